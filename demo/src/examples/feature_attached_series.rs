@@ -0,0 +1,54 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+// A second metric sampled at different X values
+struct Sample {
+    at: f64,
+    value: f64,
+}
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Pretend these arrive out of order between our data points
+    let samples = Signal::derive(move || {
+        data.with(|data| {
+            data.iter()
+                .rev()
+                .map(|d| Sample {
+                    at: d.x + 0.5,
+                    value: d.y2 + 1.0,
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    // Attach the samples with their own X values. No need to join them with our data
+    let series = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| data.y1).with_name("readings"))
+        .attach(
+            samples,
+            Series::new(|s: &Sample| s.at)
+                .line(Line::new(|s: &Sample| s.value).with_name("samples"))
+                .with_sort_by_x(true),
+        );
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=Legend::end()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                XGridLine::default().into_inner(),
+                YGridLine::default().into_inner(),
+                XGuideLine::over_data().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
@@ -0,0 +1,44 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Charts in the same group share their mouse hover and X range
+    let group = ChartGroup::new().with_sync_range_x(true);
+    let top = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| data.y1).with_name("bees"));
+    let bottom = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| data.y2).with_name("wasps"));
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 2.4)
+            debug=debug
+            series=top
+            data=data
+            group=group.clone()
+
+            left=TickLabels::aligned_floats().with_min_chars(4)
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                XGuideLine::over_data().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor().show_x_ticks(false)
+        />
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 2.4)
+            debug=debug
+            series=bottom
+            data=data
+            // Hovering over either chart shows guide lines and tooltips on both
+            group=group
+
+            left=TickLabels::aligned_floats().with_min_chars(4)
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                XGuideLine::over_data().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor().show_x_ticks(false)
+        />
+    }
+}
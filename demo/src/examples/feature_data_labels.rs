@@ -0,0 +1,38 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    let series = Series::new(|data: &MyData| data.x)
+        // Show values inside the end of each bar
+        .bar(
+            Bar::new(|data: &MyData| data.y1)
+                .with_name("sales")
+                .with_data_labels(DataLabelPlacement::Inside),
+        )
+        // Or above each point. Overlapping labels are skipped
+        .line(
+            Line::new(|data: &MyData| data.y2)
+                .with_name("returns")
+                .with_marker(MarkerShape::Circle)
+                .with_data_labels(DataLabelPlacement::Outside),
+        )
+        .with_y_range(0.0, None);
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+            ]
+            // Labels are formatted using the tooltip's tick labels
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
@@ -0,0 +1,38 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // The data table is always available to screen readers. Make it visible too
+    let table = DataTable::default().with_x_header("x");
+    let visible = table.visible;
+    let series = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| data.y1).with_name("butterflies"))
+        .line(Line::new(|data: &MyData| data.y2).with_name("dragonflies"));
+    view! {
+        <p>
+            <label>
+                <input type="checkbox" prop:checked=visible
+                    on:input=move |ev| visible.set(event_target_checked(&ev)) />
+                " Show data table"
+            </label>
+        </p>
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+            data_table=table
+
+            left=TickLabels::aligned_floats()
+            bottom=Legend::end()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                XGuideLine::over_data().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
@@ -0,0 +1,35 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Derive new lines from the same getter without touching our data
+    let series = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| data.y2).with_name("raw"))
+        .line(
+            Line::new(|data: &MyData| data.y2)
+                .moving_average(3)
+                .with_name("average"),
+        )
+        .line(Line::new(|data: &MyData| data.y2).ema(0.3).with_name("EMA"))
+        .line(Line::new(|data: &MyData| data.y2).rate().with_name("rate"));
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=Legend::end()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::horizontal_zero().into_inner(),
+                YGridLine::default().into_inner(),
+                XGuideLine::over_data().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
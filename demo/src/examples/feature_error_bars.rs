@@ -0,0 +1,39 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    let series = Series::new(|data: &MyData| data.x)
+        // A translucent band between lower and upper bounds e.g., a forecast interval
+        .line(
+            Line::new(|data: &MyData| data.y1)
+                .with_name("forecast")
+                .with_error(|data: &MyData| data.y1 * 0.8, |data: &MyData| data.y1 * 1.2),
+        )
+        // Or whiskers at each point
+        .line(
+            Line::new(|data: &MyData| data.y2)
+                .with_name("measured")
+                .with_marker(MarkerShape::Circle)
+                .with_error(|data: &MyData| data.y2 - 0.5, |data: &MyData| data.y2 + 0.5)
+                .with_error_style(ErrorStyle::Whiskers),
+        );
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=Legend::end()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                YGridLine::default().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
@@ -0,0 +1,51 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+// Pretend our sensor had an outage
+fn outage(data: &MyData, y: f64) -> f64 {
+    if (3.0..6.0).contains(&data.x) {
+        f64::NAN
+    } else {
+        y
+    }
+}
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Each line draws the same outage differently
+    let series = Series::new(|data: &MyData| data.x)
+        .line(
+            Line::new(|data: &MyData| outage(data, data.y1))
+                .with_gaps(GapPolicy::Dashed)
+                .with_name("dashed"),
+        )
+        .line(
+            Line::new(|data: &MyData| outage(data, data.y2))
+                .with_gaps(GapPolicy::Zero)
+                .with_name("zero"),
+        )
+        .line(
+            Line::new(|data: &MyData| outage(data, data.y1 + data.y2))
+                .with_gaps(GapPolicy::Connect)
+                .with_name("connect"),
+        );
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=Legend::end()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                YGridLine::default().into_inner(),
+                XGuideLine::over_data().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
@@ -0,0 +1,62 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+use leptos_use::use_interval_fn;
+
+struct Reading {
+    at: f64,
+    value: f64,
+}
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Keep a sliding window of the latest readings
+    let stream = Stream::new().with_capacity(50);
+    let readings = move |from: usize, len: usize| {
+        data.with_untracked(|data| {
+            (from..from + len)
+                .map(|i| Reading {
+                    at: i as f64,
+                    value: data.get(i % data.len().max(1)).map_or(0.0, |d| d.y1),
+                })
+                .collect::<Vec<_>>()
+        })
+    };
+    stream.extend(readings(0, 20));
+
+    // Push a new reading every second. Only the new part of the line is redrawn
+    let next = store_value(20);
+    use_interval_fn(
+        move || {
+            let at = next.get_value();
+            stream.extend(readings(at, 1));
+            next.set_value(at + 1);
+        },
+        1000,
+    );
+
+    let series = Series::new(|r: &Reading| r.at).line(
+        Line::new(|r: &Reading| r.value)
+            .with_name("reading")
+            .with_interpolation(Interpolation::Linear),
+    );
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=stream
+
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                XGridLine::default().into_inner(),
+                YGridLine::default().into_inner(),
+                XGuideLine::over_data().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
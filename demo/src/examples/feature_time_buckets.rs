@@ -0,0 +1,56 @@
+use super::MyData;
+use chrono::prelude::*;
+use leptos::*;
+use leptos_chartistry::*;
+
+// An event from an irregular log
+struct Event {
+    at: DateTime<Utc>,
+    latency: f64,
+}
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Pretend each point is a burst of events at odd minutes past the hour
+    let start = Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+    let events = Signal::derive(move || {
+        data.with(|data| {
+            data.iter()
+                .flat_map(|d| {
+                    (0..d.y1.round() as i64).map(move |k| Event {
+                        at: start + chrono::Duration::minutes((d.x * 60.0) as i64 + k * 11 + 3),
+                        latency: d.y2 + k as f64,
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    // No period set: picks the smallest that fits in 24 buckets over the X range
+    let buckets = Buckets::new(events, |e: &Event| e.at)
+        .with_max_buckets(24)
+        .aggregate(|_: &Event| 1.0, Aggregate::Count)
+        .aggregate(|e: &Event| e.latency, Aggregate::Percentile(95.0));
+    let series = buckets
+        .series()
+        .bar(Bar::new(|b: &Bucket<Utc>| b.value(0)).with_name("events"))
+        .line(Line::new(|b: &Bucket<Utc>| b.value(1)).with_name("p95 latency"))
+        .with_y_range(0.0, None);
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=buckets.data()
+
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::timestamps()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                YGridLine::default().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
@@ -0,0 +1,43 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Fit a trend to the same getter and show how well it fits in the legend
+    let series = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| data.y1).with_name("raw"))
+        .line(
+            Line::new(|data: &MyData| data.y1)
+                .trend(
+                    Trend::linear()
+                        .with_confidence(0.95)
+                        .with_equation()
+                        .with_r_squared(),
+                )
+                .with_name("linear"),
+        )
+        .line(
+            Line::new(|data: &MyData| data.y1)
+                .trend(Trend::loess(0.5))
+                .with_name("LOESS"),
+        );
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=Legend::middle()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                YGridLine::default().into_inner(),
+                XGuideLine::over_data().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
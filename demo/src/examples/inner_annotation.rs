@@ -0,0 +1,35 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    let series = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| data.y1).with_name("latency"))
+        .line(Line::new(|data: &MyData| data.y2).with_name("errors"));
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                // Annotations point at a data coordinate
+                Annotation::new(2.0, 5.0, "deploy v2.3").into_inner(),
+                // Move the label to the side and put it in a box
+                Annotation::new(6.0, 9.0, "outage")
+                    .with_offset((-8.0, 0.0))
+                    .with_callout(true)
+                    .with_colour(Colour::from_rgb(0xF5, 0x32, 0x5B))
+                    .into_inner(),
+                // Labels near the edge are nudged inside the chart
+                Annotation::new(10.0, 10.0, "new record").with_arrow(false).into_inner(),
+            ]
+        />
+    }
+}
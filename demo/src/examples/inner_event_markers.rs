@@ -0,0 +1,50 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+// Events are loaded separately from our metrics
+struct Deploy {
+    at: f64,
+    version: &'static str,
+}
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    let deploys = Signal::derive(|| {
+        vec![
+            Deploy {
+                at: 1.5,
+                version: "v2.2",
+            },
+            Deploy {
+                at: 5.5,
+                version: "v2.3",
+            },
+            Deploy {
+                at: 7.5,
+                version: "v2.3.1",
+            },
+        ]
+    });
+    let series = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| data.y1).with_name("latency"))
+        .line(Line::new(|data: &MyData| data.y2).with_name("errors"));
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                // Hover near a flag to see its label
+                EventMarkers::new(deploys, |d: &Deploy| d.at, |d: &Deploy| d.version.to_string())
+                    .into_inner(),
+            ]
+        />
+    }
+}
@@ -0,0 +1,43 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    let series = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| data.y1).with_name("requests"))
+        .line(Line::new(|data: &MyData| data.y2).with_name("errors"));
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                // Shade a window of time e.g., scheduled maintenance
+                XBand::new(4.5, 6.5).with_label("maintenance").into_inner(),
+                // Shade an acceptable range of values
+                YBand::new(2.0, 4.0)
+                    .with_colour(Colour::from_rgb(0x71, 0xc6, 0x14))
+                    .into_inner(),
+                // Mark a point in time e.g., a deploy
+                XReferenceLine::new(2.0)
+                    .with_label("deploy v2.3")
+                    .with_colour(Colour::from_rgb(0x7b, 0x4d, 0xff))
+                    .with_dash(vec![])
+                    .into_inner(),
+                // A threshold above our data. Extend the range so it's always shown
+                YReferenceLine::new(12.0)
+                    .with_label("SLA")
+                    .with_extend_range(true)
+                    .into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
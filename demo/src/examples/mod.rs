@@ -3,22 +3,45 @@ pub mod edge_layout;
 pub mod edge_legend;
 pub mod edge_rotated_label;
 pub mod edge_tick_labels;
+pub mod feature_attached_series;
+pub mod feature_chart_group;
 pub mod feature_colours;
 pub mod feature_css;
+pub mod feature_data_labels;
+pub mod feature_data_table;
+pub mod feature_derived_lines;
+pub mod feature_error_bars;
+pub mod feature_gaps;
 pub mod feature_line_gradient;
 pub mod feature_markers;
 pub mod feature_markers_2;
+pub mod feature_stream;
+pub mod feature_time_buckets;
 pub mod feature_tooltip;
+pub mod feature_trend_lines;
+pub mod inner_annotation;
 pub mod inner_axis_marker;
+pub mod inner_event_markers;
 pub mod inner_grid_line;
 pub mod inner_guide_line;
 pub mod inner_layout;
 pub mod inner_legend;
+pub mod inner_reference;
 pub mod interpolation_mixed;
 pub mod interpolation_stepped;
 pub mod series_bar;
+pub mod series_box_plot;
+pub mod series_candlestick;
+pub mod series_heatmap;
+pub mod series_histogram;
 pub mod series_line;
 pub mod series_line_stack;
+pub mod series_pie;
+pub mod series_radar;
+pub mod series_range_bar;
+pub mod series_stack_diverging;
+pub mod series_stack_percent;
+pub mod series_waterfall;
 
 use leptos::signal_prelude::*;
 
@@ -0,0 +1,36 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+// Pretend samples around our data e.g., latencies of each request
+fn samples(data: &MyData) -> Vec<f64> {
+    let mut samples = (0..20)
+        .map(|i| data.y1 + (i as f64 * 1.7).sin() * data.y2 / 4.0)
+        .collect::<Vec<_>>();
+    // One slow request
+    samples.push(data.y1 + data.y2);
+    samples
+}
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Quartiles, whiskers, and outliers are computed from the samples
+    let series = Series::new(|data: &MyData| data.x)
+        .box_plot(BoxPlot::from_samples(samples).with_name("latency"));
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                YGridLine::default().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
@@ -0,0 +1,46 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+// One heatmap cell
+struct Cell {
+    x: f64,
+    row: f64,
+    value: f64,
+}
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Spread our data over a few rows. Each cell is an X bin, a Y bin, and a value
+    let cells = Signal::derive(move || {
+        data.with(|data| {
+            data.iter()
+                .flat_map(|d| {
+                    [(0.0, d.y1), (1.0, d.y2), (2.0, d.y1 * d.y2)].map(|(row, value)| Cell {
+                        x: d.x,
+                        row,
+                        value,
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    // Colour cells by value
+    let series = Series::new(|cell: &Cell| cell.x).heatmap(
+        Heatmap::new(|cell: &Cell| cell.row, |cell: &Cell| cell.value).with_name("activity"),
+    );
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=cells
+
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            // Show the range of colours
+            right=ColourScale::new()
+        />
+    }
+}
@@ -0,0 +1,29 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Bin raw values. Bins are recomputed when the data changes
+    let histogram = Histogram::new(data, |data: &MyData| data.y1)
+        .with_binning(Binning::Sturges)
+        .with_name("readings");
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            // Bin centres against counts
+            series=histogram.series()
+            data=histogram.bins()
+
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                YGridLine::default().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
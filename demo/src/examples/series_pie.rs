@@ -0,0 +1,38 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+// One slice of the pie
+struct Share {
+    name: String,
+    value: f64,
+}
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Each of the first few points is a share of the total
+    let shares = Signal::derive(move || {
+        data.with(|data| {
+            data.iter()
+                .take(5)
+                .map(|d| Share {
+                    name: format!("x = {}", d.x),
+                    value: d.y1.abs(),
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    // An inner radius turns the pie into a donut
+    let pie = Pie::new(|s: &Share| s.name.clone(), |s: &Share| s.value).with_inner_radius(0.5);
+    view! {
+        <PieChart
+            aspect_ratio=AspectRatio::from_outer_ratio(300.0, 200.0)
+            debug=debug
+            pie=pie
+            data=shares
+            legend=Legend::middle()
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
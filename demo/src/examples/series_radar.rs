@@ -0,0 +1,44 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+// One polygon on the radar
+struct Item {
+    name: String,
+    y1: f64,
+    y2: f64,
+}
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Compare the first few points across several dimensions
+    let items = Signal::derive(move || {
+        data.with(|data| {
+            data.iter()
+                .take(3)
+                .map(|d| Item {
+                    name: format!("x = {}", d.x),
+                    y1: d.y1.abs(),
+                    y2: d.y2.abs(),
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    // Each dimension is a radial axis and each datum a polygon
+    let radar = Radar::new(|item: &Item| item.name.clone())
+        .dimension("y1", |item: &Item| item.y1)
+        .dimension("y2", |item: &Item| item.y2)
+        .dimension("sum", |item: &Item| item.y1 + item.y2)
+        .dimension("max", |item: &Item| item.y1.max(item.y2))
+        .dimension("min", |item: &Item| item.y1.min(item.y2));
+    view! {
+        <RadarChart
+            aspect_ratio=AspectRatio::from_outer_ratio(400.0, 300.0)
+            debug=debug
+            radar=radar
+            data=items
+            legend=Legend::middle()
+        />
+    }
+}
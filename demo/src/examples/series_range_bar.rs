@@ -0,0 +1,55 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+const STAGES: [&str; 3] = ["build", "test", "deploy"];
+
+// One job of a pipeline
+struct Job {
+    start: f64,
+    end: f64,
+    stage: f64,
+    name: String,
+}
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Each point starts a job on the next stage. Here we pretend y1 is how long it ran
+    let jobs = Signal::derive(move || {
+        data.with(|data| {
+            data.iter()
+                .enumerate()
+                .map(|(i, d)| Job {
+                    start: d.x,
+                    end: d.x + d.y1 / 2.0,
+                    stage: (i % STAGES.len()) as f64,
+                    name: format!("#{i}"),
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    // Spans from start to end on each stage's lane
+    let series = Series::new(|j: &Job| j.start).range_bar(
+        RangeBar::new(|j: &Job| j.end, |j: &Job| j.stage)
+            .with_label(|j: &Job| j.name.clone())
+            .with_name("jobs"),
+    );
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=jobs
+
+            // Name each lane
+            left=TickLabels::lanes(STAGES)
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                XGridLine::default().into_inner(),
+            ]
+        />
+    }
+}
@@ -0,0 +1,33 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Money in stacks upward from zero while money out stacks downward
+    let stack = Stack::new()
+        .line(Line::new(|data: &MyData| data.y1).with_name("sales"))
+        .line(Line::new(|data: &MyData| -data.y2).with_name("costs"))
+        .line(Line::new(|data: &MyData| data.y2 - data.y1).with_name("net"))
+        .with_offset(StackOffset::Diverging);
+
+    let series = Series::new(|data: &MyData| data.x).stack(stack);
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=Legend::end()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::horizontal_zero().into_inner(),
+                YGridLine::default().into_inner(),
+                XGuideLine::over_data().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor().show_x_ticks(false)
+        />
+    }
+}
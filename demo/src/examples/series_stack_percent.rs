@@ -0,0 +1,35 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Normalise each X so the stack totals 100%
+    let stack = Stack::new()
+        .line(Line::new(|data: &MyData| data.y1).with_name("fairies"))
+        .line(Line::new(|data: &MyData| data.y2).with_name("pixies"))
+        .with_offset(StackOffset::Percent);
+
+    let series = Series::new(|data: &MyData| data.x)
+        .stack(stack)
+        .with_y_range(0.0, 100.0);
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=Legend::end()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                YGridLine::default().into_inner(),
+                XGuideLine::over_data().into_inner(),
+            ]
+            // Shows each value with its share of the total
+            tooltip=Tooltip::left_cursor().show_x_ticks(false)
+        />
+    }
+}
@@ -0,0 +1,54 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+// One bar of the waterfall
+struct Step {
+    x: f64,
+    change: f64,
+    subtotal: bool,
+}
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Each point changes the total by the difference between y1 and y2. Every fifth shows a subtotal
+    let steps = Signal::derive(move || {
+        data.with(|data| {
+            data.iter()
+                .enumerate()
+                .map(|(i, d)| Step {
+                    x: d.x,
+                    change: d.y1 - d.y2,
+                    subtotal: i % 5 == 4,
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    // Bars float from the previous running total
+    let series = Series::new(|s: &Step| s.x)
+        .waterfall(
+            Waterfall::new(|s: &Step| s.change)
+                .with_subtotals(|s: &Step| s.subtotal)
+                .with_name("total"),
+        )
+        // Include zero so subtotals are drawn in full
+        .with_min_y(0.0);
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=steps
+
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::horizontal_zero().into_inner(),
+                YGridLine::default().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
@@ -12,7 +12,17 @@ use web_sys::{HtmlDialogElement, MouseEvent};
 pub enum Example {
     Line,
     StackedLine,
+    PercentStack,
+    DivergingStack,
     Bar,
+    Candlestick,
+    BoxPlot,
+    Histogram,
+    Waterfall,
+    RangeBar,
+    Heatmap,
+    Pie,
+    Radar,
     Legend,
     TickLabels,
     RotatedLabel,
@@ -20,11 +30,24 @@ pub enum Example {
     AxisMarker,
     GridLine,
     GuideLine,
+    ReferenceLines,
+    Annotations,
+    EventMarkers,
     InsetLegend,
     InnerLayout,
     MixedInterpolation,
     Stepped,
     Tooltip,
+    ChartGroup,
+    DataTable,
+    DataLabels,
+    ErrorBars,
+    DerivedLines,
+    TrendLines,
+    TimeBuckets,
+    Gaps,
+    AttachedSeries,
+    Stream,
     Colours,
     Markers,
     Markers2,
@@ -43,7 +66,17 @@ impl Example {
         match self {
             Self::Line => "Line chart",
             Self::StackedLine => "Stacked line chart",
+            Self::PercentStack => "100% stacked line chart",
+            Self::DivergingStack => "Diverging stacked line chart",
             Self::Bar => "Bar chart",
+            Self::Candlestick => "Candlestick chart",
+            Self::BoxPlot => "Box plot",
+            Self::Histogram => "Histogram",
+            Self::Waterfall => "Waterfall chart",
+            Self::RangeBar => "Range bars",
+            Self::Heatmap => "Heatmap",
+            Self::Pie => "Pie chart",
+            Self::Radar => "Radar chart",
             Self::Legend => "Legend",
             Self::TickLabels => "Tick labels",
             Self::RotatedLabel => "Rotated label",
@@ -51,11 +84,24 @@ impl Example {
             Self::AxisMarker => "Axis marker",
             Self::GridLine => "Grid line",
             Self::GuideLine => "Guide line",
+            Self::ReferenceLines => "Reference lines and bands",
+            Self::Annotations => "Annotations",
+            Self::EventMarkers => "Event markers",
             Self::InsetLegend => "Inset legend",
             Self::InnerLayout => "Combined inner layout",
             Self::MixedInterpolation => "Linear and monotone",
             Self::Stepped => "Stepped",
             Self::Tooltip => "Tooltip",
+            Self::ChartGroup => "Chart group",
+            Self::DataTable => "Data table",
+            Self::DataLabels => "Data labels",
+            Self::ErrorBars => "Error bars",
+            Self::DerivedLines => "Derived lines",
+            Self::TrendLines => "Trend lines",
+            Self::TimeBuckets => "Time buckets",
+            Self::Gaps => "Gaps",
+            Self::AttachedSeries => "Attached series",
+            Self::Stream => "Streaming data",
             Self::Colours => "Colour",
             Self::Markers => "Point markers",
             Self::Markers2 => "Point markers 2",
@@ -68,7 +114,17 @@ impl Example {
         match self {
             Self::Line => "series-line",
             Self::StackedLine => "series-line-stack",
+            Self::PercentStack => "series-stack-percent",
+            Self::DivergingStack => "series-stack-diverging",
             Self::Bar => "series-bar",
+            Self::Candlestick => "series-candlestick",
+            Self::BoxPlot => "series-box-plot",
+            Self::Histogram => "series-histogram",
+            Self::Waterfall => "series-waterfall",
+            Self::RangeBar => "series-range-bar",
+            Self::Heatmap => "series-heatmap",
+            Self::Pie => "series-pie",
+            Self::Radar => "series-radar",
             Self::Legend => "edge-legend",
             Self::TickLabels => "edge-tick-labels",
             Self::RotatedLabel => "edge-rotated-label",
@@ -76,11 +132,24 @@ impl Example {
             Self::AxisMarker => "inner-axis-marker",
             Self::GridLine => "inner-grid-line",
             Self::GuideLine => "inner-guide-line",
+            Self::ReferenceLines => "inner-reference",
+            Self::Annotations => "inner-annotation",
+            Self::EventMarkers => "inner-event-markers",
             Self::InsetLegend => "inner-legend",
             Self::InnerLayout => "inner-layout",
             Self::MixedInterpolation => "interpolation-mixed",
             Self::Stepped => "interpolation-stepped",
             Self::Tooltip => "feature-tooltip",
+            Self::ChartGroup => "feature-chart-group",
+            Self::DataTable => "feature-data-table",
+            Self::DataLabels => "feature-data-labels",
+            Self::ErrorBars => "feature-error-bars",
+            Self::DerivedLines => "feature-derived-lines",
+            Self::TrendLines => "feature-trend-lines",
+            Self::TimeBuckets => "feature-time-buckets",
+            Self::Gaps => "feature-gaps",
+            Self::AttachedSeries => "feature-attached-series",
+            Self::Stream => "feature-stream",
             Self::Colours => "feature-colours",
             Self::Markers => "feature-markers",
             Self::Markers2 => "feature-markers-2",
@@ -93,7 +162,19 @@ impl Example {
         match self {
             Self::Line => "A simple line chart.",
             Self::StackedLine => "A stacked line chart.",
+            Self::PercentStack => "A stacked line chart normalised to 100% at each X.",
+            Self::DivergingStack => "A stacked line chart with negative values stacked below zero.",
             Self::Bar => "A simple bar chart.",
+            Self::Candlestick => "Show open, high, low, and close values.",
+            Self::BoxPlot => "Show distributions with boxes, whiskers, and outliers.",
+            Self::Histogram => "Bin raw values into adjacent bars.",
+            Self::Waterfall => "Show how each value changes a running total.",
+            Self::RangeBar => {
+                "Horizontal spans from a start to an end on named lanes e.g., a pipeline timeline."
+            }
+            Self::Heatmap => "Colour a grid of cells by value.",
+            Self::Pie => "Show each datum's share of the total as a pie or donut.",
+            Self::Radar => "Compare several dimensions per datum on radial axes.",
             Self::Legend => "Add legends to your chart edges.",
             Self::TickLabels => "Add tick labels and auto-pick nice values.",
             Self::RotatedLabel => "Add rotated labels to your chart.",
@@ -101,11 +182,26 @@ impl Example {
             Self::AxisMarker => "Add axis markers to the edges of your chart area.",
             Self::GridLine => "Add grid lines aligned to your tick labels.",
             Self::GuideLine => "Add guide lines to your mouse.",
+            Self::ReferenceLines => "Mark thresholds and windows at fixed data values.",
+            Self::Annotations => "Label points of interest with text and arrows.",
+            Self::EventMarkers => "Flag events from a separate dataset.",
             Self::InsetLegend => "Add a legend inside your chart area.",
             Self::InnerLayout => "A more complete example of all inner options.",
             Self::MixedInterpolation => "Change the interpolation of your lines.",
             Self::Stepped => "Change the interpolation of your lines to stepped.",
             Self::Tooltip => "Add a mouse tooltip to your chart.",
+            Self::ChartGroup => "Synchronise the mouse hover across charts.",
+            Self::DataTable => "Mirror the chart's data in an accessible HTML table.",
+            Self::DataLabels => "Show values on bars and line points.",
+            Self::ErrorBars => "Show uncertainty with whiskers or a band.",
+            Self::DerivedLines => {
+                "Moving average, EMA, and rate lines derived from the same getter."
+            }
+            Self::TrendLines => "Fit a trend with a confidence band and its equation.",
+            Self::TimeBuckets => "Aggregate irregular events per period e.g., a count per hour.",
+            Self::Gaps => "Choose how lines are drawn across missing data.",
+            Self::AttachedSeries => "Draw series from other data with their own X values.",
+            Self::Stream => "Push live data to a chart without rebuilding it.",
             Self::Colours => "Change the colours of your chart.",
             Self::Markers => "Add point markers to your lines.",
             Self::Markers2 => "Another way to add point markers to your lines.",
@@ -118,7 +214,17 @@ impl Example {
         match self {
             Self::Line => include_example_hl!("series_line"),
             Self::StackedLine => include_example_hl!("series_line_stack"),
+            Self::PercentStack => include_example_hl!("series_stack_percent"),
+            Self::DivergingStack => include_example_hl!("series_stack_diverging"),
             Self::Bar => include_example_hl!("series_bar"),
+            Self::Candlestick => include_example_hl!("series_candlestick"),
+            Self::BoxPlot => include_example_hl!("series_box_plot"),
+            Self::Histogram => include_example_hl!("series_histogram"),
+            Self::Waterfall => include_example_hl!("series_waterfall"),
+            Self::RangeBar => include_example_hl!("series_range_bar"),
+            Self::Heatmap => include_example_hl!("series_heatmap"),
+            Self::Pie => include_example_hl!("series_pie"),
+            Self::Radar => include_example_hl!("series_radar"),
             Self::Legend => include_example_hl!("edge_legend"),
             Self::TickLabels => include_example_hl!("edge_tick_labels"),
             Self::RotatedLabel => include_example_hl!("edge_rotated_label"),
@@ -126,11 +232,24 @@ impl Example {
             Self::AxisMarker => include_example_hl!("inner_axis_marker"),
             Self::GridLine => include_example_hl!("inner_grid_line"),
             Self::GuideLine => include_example_hl!("inner_guide_line"),
+            Self::ReferenceLines => include_example_hl!("inner_reference"),
+            Self::Annotations => include_example_hl!("inner_annotation"),
+            Self::EventMarkers => include_example_hl!("inner_event_markers"),
             Self::InsetLegend => include_example_hl!("inner_legend"),
             Self::InnerLayout => include_example_hl!("inner_layout"),
             Self::MixedInterpolation => include_example_hl!("interpolation_mixed"),
             Self::Stepped => include_example_hl!("interpolation_stepped"),
             Self::Tooltip => include_example_hl!("feature_tooltip"),
+            Self::ChartGroup => include_example_hl!("feature_chart_group"),
+            Self::DataTable => include_example_hl!("feature_data_table"),
+            Self::DataLabels => include_example_hl!("feature_data_labels"),
+            Self::ErrorBars => include_example_hl!("feature_error_bars"),
+            Self::DerivedLines => include_example_hl!("feature_derived_lines"),
+            Self::TrendLines => include_example_hl!("feature_trend_lines"),
+            Self::TimeBuckets => include_example_hl!("feature_time_buckets"),
+            Self::Gaps => include_example_hl!("feature_gaps"),
+            Self::AttachedSeries => include_example_hl!("feature_attached_series"),
+            Self::Stream => include_example_hl!("feature_stream"),
             Self::Colours => include_example_hl!("feature_colours"),
             Self::Markers => include_example_hl!("feature_markers"),
             Self::Markers2 => include_example_hl!("feature_markers_2"),
@@ -153,7 +272,17 @@ impl Example {
         match self {
             Self::Line => view!(<series_line::Example debug=de data=da />),
             Self::StackedLine => view!(<series_line_stack::Example debug=de data=da />),
+            Self::PercentStack => view!(<series_stack_percent::Example debug=de data=da />),
+            Self::DivergingStack => view!(<series_stack_diverging::Example debug=de data=da />),
             Self::Bar => view!(<series_bar::Example debug=de data=da />),
+            Self::Candlestick => view!(<series_candlestick::Example debug=de data=da />),
+            Self::BoxPlot => view!(<series_box_plot::Example debug=de data=da />),
+            Self::Histogram => view!(<series_histogram::Example debug=de data=da />),
+            Self::Waterfall => view!(<series_waterfall::Example debug=de data=da />),
+            Self::RangeBar => view!(<series_range_bar::Example debug=de data=da />),
+            Self::Heatmap => view!(<series_heatmap::Example debug=de data=da />),
+            Self::Pie => view!(<series_pie::Example debug=de data=da />),
+            Self::Radar => view!(<series_radar::Example debug=de data=da />),
             Self::Legend => view!(<edge_legend::Example debug=de data=da />),
             Self::TickLabels => view!(<edge_tick_labels::Example debug=de data=da />),
             Self::RotatedLabel => view!(<edge_rotated_label::Example debug=de data=da />),
@@ -161,11 +290,24 @@ impl Example {
             Self::AxisMarker => view!(<inner_axis_marker::Example debug=de data=da />),
             Self::GridLine => view!(<inner_grid_line::Example debug=de data=da />),
             Self::GuideLine => view!(<inner_guide_line::Example debug=de data=da />),
+            Self::ReferenceLines => view!(<inner_reference::Example debug=de data=da />),
+            Self::Annotations => view!(<inner_annotation::Example debug=de data=da />),
+            Self::EventMarkers => view!(<inner_event_markers::Example debug=de data=da />),
             Self::InsetLegend => view!(<inner_legend::Example debug=de data=da />),
             Self::InnerLayout => view!(<inner_layout::Example debug=de data=da />),
             Self::MixedInterpolation => view!(<interpolation_mixed::Example debug=de data=da />),
             Self::Stepped => view!(<interpolation_stepped::Example debug=de data=da />),
             Self::Tooltip => view!(<feature_tooltip::Example debug=de data=da />),
+            Self::ChartGroup => view!(<feature_chart_group::Example debug=de data=da />),
+            Self::DataTable => view!(<feature_data_table::Example debug=de data=da />),
+            Self::DataLabels => view!(<feature_data_labels::Example debug=de data=da />),
+            Self::ErrorBars => view!(<feature_error_bars::Example debug=de data=da />),
+            Self::DerivedLines => view!(<feature_derived_lines::Example debug=de data=da />),
+            Self::TrendLines => view!(<feature_trend_lines::Example debug=de data=da />),
+            Self::TimeBuckets => view!(<feature_time_buckets::Example debug=de data=da />),
+            Self::Gaps => view!(<feature_gaps::Example debug=de data=da />),
+            Self::AttachedSeries => view!(<feature_attached_series::Example debug=de data=da />),
+            Self::Stream => view!(<feature_stream::Example debug=de data=da />),
             Self::Colours => view!(<feature_colours::Example debug=de data=da />),
             Self::Markers => view!(<feature_markers::Example debug=de data=da />),
             Self::Markers2 => view!(<feature_markers_2::Example debug=de data=da />),
@@ -268,11 +410,21 @@ pub fn Examples() -> impl IntoView {
 
                 <Card example=Example::Line />
                 <Card example=Example::StackedLine />
+                <Card example=Example::PercentStack />
+                <Card example=Example::DivergingStack />
 
                 <div class="include-right">
                     <h2 id="bar"><a href="#bar">"Bar charts"</a></h2>
                     <Card example=Example::Bar />
                 </div>
+                <Card example=Example::Candlestick />
+                <Card example=Example::BoxPlot />
+                <Card example=Example::Histogram />
+                <Card example=Example::Waterfall />
+                <Card example=Example::RangeBar />
+                <Card example=Example::Heatmap />
+                <Card example=Example::Pie />
+                <Card example=Example::Radar />
 
                 <div class="include-right">
                     <h2 id="edge"><a href="#edge">"Edge layout options"</a></h2>
@@ -288,6 +440,9 @@ pub fn Examples() -> impl IntoView {
                 </div>
                 <Card example=Example::GridLine />
                 <Card example=Example::GuideLine />
+                <Card example=Example::ReferenceLines />
+                <Card example=Example::Annotations />
+                <Card example=Example::EventMarkers />
                 <Card example=Example::InsetLegend />
                 <Card example=Example::InnerLayout />
 
@@ -301,6 +456,16 @@ pub fn Examples() -> impl IntoView {
                     <h2 id="features"><a href="#features">"Features"</a></h2>
                     <Card example=Example::Tooltip />
                 </div>
+                <Card example=Example::ChartGroup />
+                <Card example=Example::DataTable />
+                <Card example=Example::DataLabels />
+                <Card example=Example::ErrorBars />
+                <Card example=Example::DerivedLines />
+                <Card example=Example::TrendLines />
+                <Card example=Example::TimeBuckets />
+                <Card example=Example::Gaps />
+                <Card example=Example::AttachedSeries />
+                <Card example=Example::Stream />
                 <Card example=Example::Colours />
                 <Card example=Example::LineGradient />
                 <Card example=Example::Markers />
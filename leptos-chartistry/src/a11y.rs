@@ -0,0 +1,264 @@
+use crate::{state::State, Tick, TickLabels};
+use leptos::*;
+
+/// Builds an HTML table that mirrors the chart's data. Has one row per X value and one column per series. Values are formatted using the tooltip's tick labels.
+///
+/// The table is visually hidden by default but remains available to screen readers. Set [visible](Self::visible) to show it e.g., for readers who want exact numbers.
+///
+/// # Example
+/// ```rust
+/// # use leptos::*;
+/// # use leptos_chartistry::*;
+/// # #[component]
+/// # fn DataTableComponent() -> impl IntoView {
+/// let table = DataTable::default().with_x_header("Day");
+/// let visible = table.visible;
+/// view! {
+///     <button on:click=move |_| visible.update(|v| *v = !*v)>"Toggle data table"</button>
+///     <Chart
+///         data_table=table
+///         // ... fill in the rest of your props
+/// #       aspect_ratio=AspectRatio::from_outer_ratio(600.0, 300.0)
+/// #       series=Series::new(|(x, _): &(f64, f64)| *x).line(|(_, y): &(f64, f64)| *y)
+/// #       data=Signal::default()
+///     />
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DataTable {
+    /// Whether the table is visible. A hidden table is still read by screen readers. Default is false.
+    pub visible: RwSignal<bool>,
+    /// Header of the X value column. Default is "X".
+    pub x_header: RwSignal<String>,
+}
+
+impl DataTable {
+    /// Creates a new visible data table.
+    pub fn visible() -> Self {
+        Self::default().with_visible(true)
+    }
+
+    /// Sets whether the table is visible.
+    pub fn with_visible(self, visible: impl Into<bool>) -> Self {
+        self.visible.set(visible.into());
+        self
+    }
+
+    /// Sets the header of the X value column.
+    pub fn with_x_header(self, x_header: impl Into<String>) -> Self {
+        self.x_header.set(x_header.into());
+        self
+    }
+}
+
+impl Default for DataTable {
+    fn default() -> Self {
+        Self {
+            visible: create_rw_signal(false),
+            x_header: create_rw_signal("X".to_string()),
+        }
+    }
+}
+
+// Hides an element visually while keeping it available to screen readers
+pub const VISUALLY_HIDDEN: &str = "position: absolute; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; border: 0;";
+
+/// Steps the keyboard focus through data indexes. Returns None if the key is not used for navigation, otherwise the new focus index (which may be None to clear focus).
+pub fn step_focus(key: &str, index: Option<usize>, len: usize) -> Option<Option<usize>> {
+    let last = len.checked_sub(1);
+    let index = match key {
+        "ArrowRight" | "ArrowUp" => index.map_or(Some(0), |i| last.map(|last| (i + 1).min(last))),
+        "ArrowLeft" | "ArrowDown" => index.map_or(last, |i| Some(i.saturating_sub(1))),
+        "Home" => Some(0),
+        "End" => last,
+        "Escape" => None,
+        _ => return None,
+    };
+    // No data to focus on
+    Some(index.filter(|_| len > 0))
+}
+
+/// Generated SVG title and description. Summarises the series and their ranges for screen readers.
+#[component]
+pub fn Summary<X: Tick, Y: Tick>(
+    state: State<X, Y>,
+    x_ticks: TickLabels<X>,
+    y_ticks: TickLabels<Y>,
+) -> impl IntoView {
+    let data = state.pre.data.clone();
+    let format_x = x_ticks.format_x(&state);
+    let format_y = y_ticks.format_y(&state);
+
+    let title = move || {
+        let names = data
+            .series
+            .get()
+            .into_iter()
+            .map(|series| series.name.get())
+            .filter(|name| !name.is_empty())
+            .collect::<Vec<_>>();
+        if names.is_empty() {
+            "Chart".to_string()
+        } else {
+            format!("Chart of {}", names.join(", "))
+        }
+    };
+
+    let desc = move || {
+        let range_x = data.range_x.get();
+        let range_y = data.range_y.get();
+        match (range_x.range(), range_y.range()) {
+            (Some((first_x, last_x)), Some((first_y, last_y))) => format!(
+                "{} data points. X axis from {} to {}. Y axis from {} to {}. Use the arrow keys to step through data points.",
+                data.len.get(),
+                format_x(first_x),
+                format_x(last_x),
+                format_y(first_y),
+                format_y(last_y),
+            ),
+            _ => "No data.".to_string(),
+        }
+    };
+
+    view! {
+        <title>{title}</title>
+        <desc>{desc}</desc>
+    }
+}
+
+/// Announces the X and Y values of the focused data point to screen readers.
+#[component]
+pub fn LiveRegion<X: Tick, Y: Tick>(
+    state: State<X, Y>,
+    x_ticks: TickLabels<X>,
+    y_ticks: TickLabels<Y>,
+    focused: Signal<bool>,
+) -> impl IntoView {
+    let format_x = x_ticks.format_x(&state);
+    let format_y = y_ticks.format_y(&state);
+    let nearest_data_x = state.pre.data.nearest_data_x(state.hover_position_x);
+    let nearest_data_y = state.pre.data.nearest_data_y(state.hover_position_x);
+
+    let announce = move || {
+        if !focused.get() {
+            return String::new();
+        }
+        let Some(x_value) = nearest_data_x.get() else {
+            return String::new();
+        };
+        let y_values = nearest_data_y
+            .get()
+            .into_iter()
+            .map(|(series, y_value)| {
+                let y_value = y_value.map_or_else(|| "no value".to_string(), |y| format_y(&y));
+                let name = series.name.get();
+                if name.is_empty() {
+                    y_value
+                } else {
+                    format!("{name} {y_value}")
+                }
+            })
+            .collect::<Vec<_>>();
+        format!("{}: {}", format_x(&x_value), y_values.join(", "))
+    };
+
+    view! {
+        <div class="_chartistry_live_region" aria-live="polite" style=VISUALLY_HIDDEN>
+            {announce}
+        </div>
+    }
+}
+
+#[component]
+pub fn RenderDataTable<X: Tick, Y: Tick>(
+    table: DataTable,
+    state: State<X, Y>,
+    x_ticks: TickLabels<X>,
+    y_ticks: TickLabels<Y>,
+) -> impl IntoView {
+    let DataTable { visible, x_header } = table;
+    let font_height = state.pre.font_height;
+    let font_width = state.pre.font_width;
+    let series = state.pre.data.series;
+    let rows = state.pre.data.rows();
+    let format_x = x_ticks.format_x(&state);
+    let format_y = y_ticks.format_y(&state);
+
+    let cell_padding = move || format!("0 {}px", font_width.get() / 2.0);
+    let header = move || {
+        series
+            .get()
+            .into_iter()
+            .map(|series| view!(<th scope="col" style:padding=cell_padding>{series.name}</th>))
+            .collect_view()
+    };
+    let body = move || {
+        let series = series.get();
+        rows.get()
+            .into_iter()
+            .map(|(x, ys)| {
+                let cells = series
+                    .iter()
+                    .map(|series| {
+                        let y = ys
+                            .get(&series.id)
+                            .map_or_else(|| "-".to_string(), &format_y);
+                        view!(<td style:padding=cell_padding>{y}</td>)
+                    })
+                    .collect_view();
+                view! {
+                    <tr>
+                        <th scope="row" style:padding=cell_padding>{format_x(&x)}</th>
+                        {cells}
+                    </tr>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <table
+            class="_chartistry_data_table"
+            style=move || if visible.get() { "" } else { VISUALLY_HIDDEN }
+            style:border-collapse="collapse"
+            style:font-family="monospace"
+            style:text-align="right"
+            style:white-space="pre"
+            style:font-size=move || format!("{}px", font_height.get())>
+            <thead>
+                <tr>
+                    <th scope="col" style:padding=cell_padding>{x_header}</th>
+                    {header}
+                </tr>
+            </thead>
+            <tbody>{body}</tbody>
+        </table>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_focus() {
+        // Start from either end
+        assert_eq!(step_focus("ArrowRight", None, 3), Some(Some(0)));
+        assert_eq!(step_focus("ArrowLeft", None, 3), Some(Some(2)));
+        // Step and clamp
+        assert_eq!(step_focus("ArrowRight", Some(1), 3), Some(Some(2)));
+        assert_eq!(step_focus("ArrowRight", Some(2), 3), Some(Some(2)));
+        assert_eq!(step_focus("ArrowLeft", Some(1), 3), Some(Some(0)));
+        assert_eq!(step_focus("ArrowLeft", Some(0), 3), Some(Some(0)));
+        // Jumps
+        assert_eq!(step_focus("Home", Some(1), 3), Some(Some(0)));
+        assert_eq!(step_focus("End", Some(1), 3), Some(Some(2)));
+        assert_eq!(step_focus("Escape", Some(1), 3), Some(None));
+        // No data
+        assert_eq!(step_focus("ArrowRight", None, 0), Some(None));
+        assert_eq!(step_focus("End", None, 0), Some(None));
+        // Other keys are ignored
+        assert_eq!(step_focus("a", Some(1), 3), None);
+    }
+}
@@ -1,6 +1,8 @@
 use crate::{
+    a11y::{step_focus, DataTable, LiveRegion, RenderDataTable, Summary},
     aspect_ratio::KnownAspectRatio,
     debug::DebugRect,
+    group::UseChartGroup,
     inner::InnerLayout,
     layout::{EdgeLayout, Layout},
     overlay::tooltip::Tooltip,
@@ -8,9 +10,9 @@ use crate::{
     series::{RenderData, UseData},
     state::{PreState, State},
     use_watched_node::{use_watched_node, UseWatchedNode},
-    AspectRatio, Padding, Series, Tick,
+    AspectRatio, ChartData, ChartGroup, Padding, Series, Tick,
 };
-use leptos::{html::Div, *};
+use leptos::{ev::KeyboardEvent, html::Div, *};
 
 pub const FONT_HEIGHT: f64 = 16.0;
 pub const FONT_WIDTH: f64 = 10.0;
@@ -92,6 +94,14 @@ pub const FONT_WIDTH: f64 = 10.0;
 /// # } }
 /// ```
 ///
+/// ## Accessibility
+///
+/// The chart can be focused with the keyboard. Once focused, the arrow keys (and `Home` / `End`) step through data points, moving the tooltip and guide lines as if hovered by the mouse. The focused X and Y values are announced to screen readers. The SVG is given an image role with a title and description generated from the series names and ranges. Values are formatted using the tooltip's tick labels.
+///
+/// ## Touch
+///
+/// On touch screens, tapping the chart shows the tooltip and guide lines at that point. They stay after the finger is lifted until somewhere outside the chart is tapped. Dragging horizontally scrubs along the X axis while dragging vertically scrolls the page as usual. Multi-finger gestures (e.g., pinch) are left to the browser for zooming.
+///
 /// ## Next steps
 ///
 /// See the props below for more details. Copy and paste [examples](https://feral-dot-io.github.io/leptos-chartistry/examples.html) to get going quickly.
@@ -165,12 +175,20 @@ pub fn Chart<T: 'static, X: Tick, Y: Tick>(
     #[prop(into, optional)]
     tooltip: Tooltip<X, Y>,
 
+    /// Accessible HTML table mirroring the chart's data. Visually hidden unless made visible. See [DataTable](crate::DataTable) for details. Default is none.
+    #[prop(into, optional)]
+    data_table: Option<DataTable>,
+
+    /// Synchronises the mouse hover (and optionally the X range) with other charts in the same group. See [ChartGroup] for details. Default is none.
+    #[prop(into, optional)]
+    group: Option<ChartGroup<X>>,
+
     /// Series to render. Maps `T` to lines, bars, etc. See [Series] for details.
     #[prop(into)]
     series: Series<T, X, Y>,
-    /// Data to render. Must be sorted.
+    /// Data to render. Must be sorted by X unless [Series::sort_by_x] is set. Data from other sources can be [attached](Series::attach) to the series. Use a [Stream](crate::Stream) for live data that's appended to.
     #[prop(into)]
-    data: Signal<Vec<T>>,
+    data: ChartData<T>,
 ) -> impl IntoView {
     let root = create_node_ref::<Div>();
     let watch = use_watched_node(root);
@@ -203,16 +221,56 @@ pub fn Chart<T: 'static, X: Tick, Y: Tick>(
     top.reverse();
     left.reverse();
 
+    // Join chart group
+    let group = group.map(|group| group.join());
+
     // Build data
-    let data = UseData::new(series, data);
+    // Inner layouts (e.g., reference lines) can extend the range
+    let extents = {
+        let inner = inner.clone();
+        Signal::derive(move || {
+            inner
+                .iter()
+                .fold((vec![], vec![]), |(mut xs, mut ys), inner| {
+                    let (x, y) = inner.extents();
+                    xs.extend(x);
+                    ys.extend(y);
+                    (xs, ys)
+                })
+        })
+    };
+    let data = UseData::new(series, data, extents, group.as_ref());
     let pre = PreState::new(debug.into(), font_height, font_width, padding.into(), data);
 
+    // Keyboard navigation: step through data points
+    let focus_index = create_rw_signal::<Option<usize>>(None);
+    let clear_focus = move || {
+        if focus_index.get_untracked().is_some() {
+            focus_index.set(None);
+        }
+    };
+    let on_keydown = {
+        let len = pre.data.len;
+        move |ev: KeyboardEvent| {
+            if let Some(index) = step_focus(&ev.key(), focus_index.get_untracked(), len.get()) {
+                ev.prevent_default();
+                focus_index.set(index);
+            }
+        }
+    };
+
     view! {
         <div
             node_ref=root
             class="_chartistry"
+            tabindex="0"
+            on:keydown=on_keydown
+            on:mousemove=move |_| clear_focus()
+            on:touchstart=move |_| clear_focus()
+            on:blur=move |_| clear_focus()
             style:width=env_size.clone()
             style:height=env_size
+            style:touch-action="pan-y pinch-zoom"
             style="overflow: visible;">
             <DebugRect label="Chart" debug=debug />
             <Show when=move || have_dimensions.get() fallback=|| view!(<p>"Loading..."</p>)>
@@ -226,6 +284,9 @@ pub fn Chart<T: 'static, X: Tick, Y: Tick>(
                     left=left.as_slice()
                     inner=inner.clone()
                     tooltip=tooltip.clone()
+                    group=group.clone()
+                    focus_index=focus_index
+                    data_table=data_table.clone()
                 />
             </Show>
         </div>
@@ -243,6 +304,9 @@ fn RenderChart<'a, X: Tick, Y: Tick>(
     left: &'a [EdgeLayout<Y>],
     inner: Vec<InnerLayout<X, Y>>,
     tooltip: Tooltip<X, Y>,
+    group: Option<UseChartGroup<X>>,
+    focus_index: RwSignal<Option<usize>>,
+    data_table: Option<DataTable>,
 ) -> impl IntoView {
     let debug = pre_state.debug;
 
@@ -254,6 +318,8 @@ fn RenderChart<'a, X: Tick, Y: Tick>(
         let range_x = pre_state.data.range_x;
         let range_y = pre_state.data.range_y;
         let includes_bars = pre_state.data.includes_bars;
+        let heat_cell = pre_state.data.heat_cell;
+        let lane_height = pre_state.data.lane_height;
         create_memo(move |_| {
             let mut inner = layout.inner.get();
             // If we include bars, shrink the sides by half the width of X
@@ -262,11 +328,31 @@ fn RenderChart<'a, X: Tick, Y: Tick>(
                 inner = inner.shrink(0.0, half, 0.0, half);
             }
 
-            Projection::new(inner, range_x.get().positions(), range_y.get().positions())
+            // Heatmap cells are centred on their bins: extend ranges by half a cell
+            let (mut range_x, mut range_y) = (range_x.get().positions(), range_y.get().positions());
+            if let Some((width, height)) = heat_cell.get() {
+                let (half_w, half_h) = (width / 2.0, height / 2.0);
+                range_x = range_x.map(|(min, max)| (min - half_w, max + half_w));
+                range_y = range_y.map(|(min, max)| (min - half_h, max + half_h));
+            }
+            // Range bars are centred on their lanes: extend by half a lane
+            if let Some(height) = lane_height.get() {
+                let half = height / 2.0;
+                range_y = range_y.map(|(min, max)| (min - half, max + half));
+            }
+            Projection::new(inner, range_x, range_y)
         })
         .into()
     };
-    let state = State::new(pre_state, &watch, layout, projection);
+    let keyboard_x = pre_state.data.position_x_at(focus_index.into());
+    let state = State::new(
+        pre_state,
+        &watch,
+        layout,
+        projection,
+        keyboard_x,
+        group.as_ref(),
+    );
 
     // Render edges
     let edges = edges
@@ -281,18 +367,26 @@ fn RenderChart<'a, X: Tick, Y: Tick>(
         .collect_view();
 
     let outer = state.layout.outer;
+    let focused = Signal::derive(move || keyboard_x.get().is_some());
+    let (x_ticks, y_ticks) = (tooltip.x_ticks.clone(), tooltip.y_ticks.clone());
     view! {
         <svg
             width=move || format!("{}px", outer.get().width())
             height=move || format!("{}px", outer.get().height())
             viewBox=move || with!(|outer| format!("0 0 {} {}", outer.width(), outer.height()))
+            role="img"
             style="display: block; overflow: visible;">
+            <Summary state=state.clone() x_ticks=x_ticks.clone() y_ticks=y_ticks.clone() />
             <DebugRect label="RenderChart" debug=debug bounds=vec![outer.into()] />
             <CommonDefs />
             {inner}
             {edges}
-            <RenderData state=state.clone() />
+            <RenderData state=state.clone() y_ticks=y_ticks.clone() />
         </svg>
+        <LiveRegion state=state.clone() x_ticks=x_ticks.clone() y_ticks=y_ticks.clone() focused=focused />
+        {data_table.map(|table| view! {
+            <RenderDataTable table=table state=state.clone() x_ticks=x_ticks y_ticks=y_ticks />
+        })}
         <Tooltip tooltip=tooltip state=state />
     }
 }
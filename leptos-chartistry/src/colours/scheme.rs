@@ -104,6 +104,35 @@ impl ColourScheme {
 
         before.interpolate(after, ratio)
     }
+
+    /// Colour of a value within a (min, max) range e.g., a heatmap cell. Sequential schemes spread over the range while diverging schemes centre on zero.
+    pub(crate) fn by_value(&self, value: f64, (min, max): (f64, f64)) -> Colour {
+        let (below_zero, above_zero) = self.diverging_swatches();
+        if self.zero.is_none() {
+            let ratio = if max > min {
+                (value - min) / (max - min)
+            } else {
+                0.0
+            };
+            return along_swatches(above_zero, ratio);
+        }
+        // Below zero runs from the min to the centre
+        if value < 0.0 && !below_zero.is_empty() {
+            let ratio = if min < 0.0 { 1.0 - value / min } else { 1.0 };
+            along_swatches(below_zero, ratio)
+        } else {
+            let ratio = if max > 0.0 { value / max } else { 0.0 };
+            along_swatches(above_zero, ratio)
+        }
+    }
+}
+
+// Interpolates along the swatches. Ratio is clamped to 0.0 (first swatch) to 1.0 (last swatch).
+fn along_swatches(swatches: &[Colour], ratio: f64) -> Colour {
+    let at = ratio.clamp(0.0, 1.0) * swatches.len().saturating_sub(1) as f64;
+    let before = swatches[at.floor() as usize];
+    let after = swatches[at.ceil() as usize];
+    before.interpolate(after, at.fract())
 }
 
 #[component]
@@ -111,9 +140,17 @@ pub fn LinearGradientSvg(
     #[prop(into)] id: AttributeValue,
     scheme: Signal<ColourScheme>,
     range_y: Signal<Option<(f64, f64)>>,
+    // Runs left to right instead of bottom to top
+    #[prop(optional)] horizontal: bool,
 ) -> impl IntoView {
+    let (x1, y1) = if horizontal {
+        ("0%", "0%")
+    } else {
+        ("0%", "100%")
+    };
+    let x2 = if horizontal { "100%" } else { "0%" };
     view! {
-        <linearGradient id=Some(id) x1="0%" y1="100%" x2="0%" y2="0%">
+        <linearGradient id=Some(id) x1=x1 y1=y1 x2=x2 y2="0%">
             {move || scheme.get().stops(range_y.get().unwrap_or_default())}
         </linearGradient>
     }
@@ -290,6 +327,25 @@ mod tests {
         assert_eq!(scheme10.line_to_prior_swatch_index(1, 0), 0);
     }
 
+    #[test]
+    fn test_by_value() {
+        let black = Colour::from_rgb(0, 0, 0);
+        let white = Colour::from_rgb(255, 255, 255);
+        let grey = Colour::from_rgb(128, 128, 128);
+        let sequential = ColourScheme::from([black, white]);
+        assert_eq!(sequential.by_value(0.0, (0.0, 10.0)), black);
+        assert_eq!(sequential.by_value(5.0, (0.0, 10.0)), grey);
+        assert_eq!(sequential.by_value(10.0, (0.0, 10.0)), white);
+        // Clamped and empty ranges
+        assert_eq!(sequential.by_value(20.0, (0.0, 10.0)), white);
+        assert_eq!(sequential.by_value(3.0, (3.0, 3.0)), black);
+        // Diverging: white at the extremes, black at zero
+        let diverging = ColourScheme::diverging_gradient([white].into(), [black, white].into());
+        assert_eq!(diverging.by_value(-4.0, (-4.0, 10.0)), white);
+        assert_eq!(diverging.by_value(0.0, (-4.0, 10.0)), black);
+        assert_eq!(diverging.by_value(5.0, (-4.0, 10.0)), grey);
+    }
+
     #[test]
     fn test_scheme_interpolation() {
         let scheme3 = scheme3();
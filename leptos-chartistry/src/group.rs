@@ -0,0 +1,149 @@
+use crate::{series::Range, Tick};
+use leptos::*;
+use std::{cell::Cell, rc::Rc};
+
+/// Synchronises the mouse hover (and optionally the X range) across multiple charts.
+///
+/// Pass the same group to each [Chart](crate::Chart) using the `group` prop. Hovering over one chart then shows guide lines and tooltips at the same X position on every chart in the group. Intended for stacked charts that share the same X axis e.g., a dashboard of metrics over the same time window.
+///
+/// # Example
+/// ```rust
+/// # use leptos::*;
+/// # use leptos_chartistry::*;
+/// # struct MyData { x: f64, y1: f64, y2: f64 }
+/// # #[component]
+/// # fn GroupComponent(data: Signal<Vec<MyData>>) -> impl IntoView {
+/// let group = ChartGroup::new().with_sync_range_x(true);
+/// view! {
+///     <Chart
+///         aspect_ratio=AspectRatio::from_outer_ratio(600.0, 200.0)
+///         group=group.clone()
+///         inner=XGuideLine::over_data()
+///         tooltip=Tooltip::left_cursor()
+///         series=Series::new(|d: &MyData| d.x).line(|d: &MyData| d.y1)
+///         data=data />
+///     <Chart
+///         aspect_ratio=AspectRatio::from_outer_ratio(600.0, 200.0)
+///         group=group
+///         inner=XGuideLine::over_data()
+///         tooltip=Tooltip::left_cursor()
+///         series=Series::new(|d: &MyData| d.x).line(|d: &MyData| d.y2)
+///         data=data />
+/// }
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ChartGroup<X: 'static> {
+    /// Whether charts in the group share the same X range. The shared range covers the X range of every chart in the group. Default is false.
+    pub sync_range_x: RwSignal<bool>,
+    // Chart ID and hover X (in position space) of the chart being hovered
+    hover: RwSignal<Option<(usize, f64)>>,
+    // Individual X ranges of each chart in the group
+    ranges: RwSignal<Vec<(usize, Range<X>)>>,
+    next_id: Rc<Cell<usize>>,
+}
+
+/// A chart's membership of a [ChartGroup].
+#[derive(Clone)]
+pub(crate) struct UseChartGroup<X: 'static> {
+    id: usize,
+    group: ChartGroup<X>,
+}
+
+impl<X: Tick> ChartGroup<X> {
+    /// Creates a new, empty chart group.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether charts in the group share the same X range.
+    pub fn with_sync_range_x(self, sync: impl Into<bool>) -> Self {
+        self.sync_range_x.set(sync.into());
+        self
+    }
+
+    /// Registers a new chart with the group. The chart is removed from the group on cleanup.
+    pub(crate) fn join(&self) -> UseChartGroup<X> {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        let (hover, ranges) = (self.hover, self.ranges);
+        on_cleanup(move || {
+            if hover.try_get_untracked().flatten().map(|(h, _)| h) == Some(id) {
+                hover.try_set(None);
+            }
+            ranges.try_update(|ranges| ranges.retain(|(r, _)| *r != id));
+        });
+        UseChartGroup {
+            id,
+            group: self.clone(),
+        }
+    }
+}
+
+impl<X: Tick> Default for ChartGroup<X> {
+    fn default() -> Self {
+        Self {
+            sync_range_x: create_rw_signal(false),
+            hover: RwSignal::default(),
+            ranges: RwSignal::default(),
+            next_id: Rc::default(),
+        }
+    }
+}
+
+impl<X: Tick> UseChartGroup<X> {
+    /// Publishes this chart's X range to the group. Returns the X range the chart should use.
+    pub fn range_x(&self, range_x: Memo<Range<X>>) -> Memo<Range<X>> {
+        let UseChartGroup { id, group } = self.clone();
+        let ranges = group.ranges;
+        create_effect(move |_| {
+            let range_x = range_x.get();
+            ranges.update(|ranges| match ranges.iter_mut().find(|(r, _)| *r == id) {
+                Some((_, existing)) => *existing = range_x,
+                None => ranges.push((id, range_x)),
+            });
+        });
+
+        let sync = group.sync_range_x;
+        create_memo(move |_| {
+            let range_x = range_x.get();
+            if !sync.get() {
+                return range_x;
+            }
+            ranges.with(|ranges| {
+                ranges
+                    .iter()
+                    .fold(range_x, |acc, (_, other)| acc.union(other))
+            })
+        })
+    }
+
+    /// Publishes this chart's hover position to the group. Returns the group's hover position if it comes from another chart.
+    pub fn hover_position_x(
+        &self,
+        hover_inner: Signal<bool>,
+        hover_position_x: Memo<f64>,
+    ) -> Memo<Option<f64>> {
+        let UseChartGroup { id, group } = self.clone();
+        let hover = group.hover;
+        create_effect(move |_| {
+            let current = hover.get_untracked();
+            let update = if hover_inner.get() {
+                Some((id, hover_position_x.get()))
+            } else if current.map(|(h, _)| h) == Some(id) {
+                None
+            } else {
+                // Another chart is being hovered (or none at all)
+                return;
+            };
+            if current != update {
+                hover.set(update);
+            }
+        });
+
+        create_memo(move |_| match hover.get() {
+            Some((h, pos_x)) if h != id => Some(pos_x),
+            _ => None,
+        })
+    }
+}
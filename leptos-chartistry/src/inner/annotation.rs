@@ -0,0 +1,287 @@
+use super::UseInner;
+use crate::{bounds::Bounds, colours::Colour, debug::DebugRect, state::State, Tick};
+use leptos::*;
+use std::rc::Rc;
+
+/// Default colour for annotations.
+pub const ANNOTATION_COLOUR: Colour = Colour::from_rgb(0x33, 0x33, 0x33);
+
+/// Default offset (in multiples of font width and height) of an annotation label from its point. Places the label above the point.
+pub const ANNOTATION_OFFSET: (f64, f64) = (0.0, 3.0);
+
+// Length of the arrow head in stroke widths. See `marker_axis_arrow`
+const ARROW_LENGTH: f64 = 7.0;
+
+/// Builds a text annotation anchored to a data coordinate e.g., "deploy v2.3" or "outage".
+///
+/// The label is placed at an offset from the point with an optional arrow pointing to it and an optional callout box around it. Labels that would be clipped by the edge of the inner chart area are nudged inside.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// let annotation = Annotation::new(6.0, 9.0, "outage")
+///     .with_offset((-4.0, 1.0))
+///     .with_callout(true);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Annotation<X: 'static, Y: 'static> {
+    /// X value of the point.
+    pub x: RwSignal<X>,
+    /// Y value of the point.
+    pub y: RwSignal<Y>,
+    /// Text of the label.
+    pub label: RwSignal<String>,
+    /// Colour of the label, arrow, and callout border.
+    pub colour: RwSignal<Colour>,
+    /// Offset of the label's centre from the point. Given as (X, Y) multiples of the font width and height respectively with positive Y going up. Default is [ANNOTATION_OFFSET].
+    pub offset: RwSignal<(f64, f64)>,
+    /// Whether to draw an arrow from the label to the point. Default is true.
+    pub arrow: RwSignal<bool>,
+    /// Whether to draw a callout box around the label. Default is false.
+    pub callout: RwSignal<bool>,
+}
+
+impl<X: Tick, Y: Tick> Annotation<X, Y> {
+    /// Creates a new annotation at the given data coordinate.
+    pub fn new(x: X, y: Y, label: impl Into<String>) -> Self {
+        Self {
+            x: create_rw_signal(x),
+            y: create_rw_signal(y),
+            label: create_rw_signal(label.into()),
+            colour: create_rw_signal(ANNOTATION_COLOUR),
+            offset: create_rw_signal(ANNOTATION_OFFSET),
+            arrow: create_rw_signal(true),
+            callout: create_rw_signal(false),
+        }
+    }
+
+    /// Sets the colour of the annotation.
+    pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+
+    /// Sets the offset of the label from the point. See [offset](Self::offset) for details.
+    pub fn with_offset(self, offset: impl Into<(f64, f64)>) -> Self {
+        self.offset.set(offset.into());
+        self
+    }
+
+    /// Sets whether to draw an arrow from the label to the point.
+    pub fn with_arrow(self, arrow: impl Into<bool>) -> Self {
+        self.arrow.set(arrow.into());
+        self
+    }
+
+    /// Sets whether to draw a callout box around the label.
+    pub fn with_callout(self, callout: impl Into<bool>) -> Self {
+        self.callout.set(callout.into());
+        self
+    }
+}
+
+impl<X: Tick, Y: Tick> UseInner<X, Y> for Annotation<X, Y> {
+    fn render(self: Rc<Self>, state: State<X, Y>) -> View {
+        view!( <Annotation annotation=(*self).clone() state=state /> )
+    }
+}
+
+/// Moves the label bounds inside the inner bounds. Prefers showing the top left corner if the label doesn't fit.
+fn nudge_inside(label: Bounds, inner: Bounds) -> Bounds {
+    let nudge = |start: f64, end: f64, min: f64, max: f64| {
+        let delta = (max - end).min(0.0);
+        delta.max(min - start)
+    };
+    let dx = nudge(
+        label.left_x(),
+        label.right_x(),
+        inner.left_x(),
+        inner.right_x(),
+    );
+    let dy = nudge(
+        label.top_y(),
+        label.bottom_y(),
+        inner.top_y(),
+        inner.bottom_y(),
+    );
+    Bounds::from_points(
+        label.left_x() + dx,
+        label.top_y() + dy,
+        label.right_x() + dx,
+        label.bottom_y() + dy,
+    )
+}
+
+/// Finds where a line from the centre of the label to the point leaves the label. Returns None if the point is inside the label.
+fn label_edge(label: Bounds, (x, y): (f64, f64)) -> Option<(f64, f64)> {
+    if label.contains(x, y) {
+        return None;
+    }
+    let (cx, cy) = (label.centre_x(), label.centre_y());
+    let (dx, dy) = (x - cx, y - cy);
+    // Scale the direction so that it touches the nearest edge
+    let scale_x = if dx == 0.0 {
+        f64::INFINITY
+    } else {
+        (label.width() / 2.0) / dx.abs()
+    };
+    let scale_y = if dy == 0.0 {
+        f64::INFINITY
+    } else {
+        (label.height() / 2.0) / dy.abs()
+    };
+    let scale = scale_x.min(scale_y);
+    Some((cx + dx * scale, cy + dy * scale))
+}
+
+#[component]
+fn Annotation<X: Tick, Y: Tick>(annotation: Annotation<X, Y>, state: State<X, Y>) -> impl IntoView {
+    let Annotation {
+        x,
+        y,
+        label,
+        colour,
+        offset,
+        arrow,
+        callout,
+    } = annotation;
+    let debug = state.pre.debug;
+    let font_height = state.pre.font_height;
+    let font_width = state.pre.font_width;
+    let inner = state.layout.inner;
+    let proj = state.projection;
+
+    // Point in SVG coordinates. None if outside of the inner chart area
+    let point = create_memo(move |_| {
+        let (x, y) = (x.with(Tick::position), y.with(Tick::position));
+        let (x, y) = proj.get().position_to_svg(x, y);
+        inner.get().contains(x, y).then_some((x, y))
+    });
+
+    // Label bounds: centred on the offset from the point and nudged inside the inner chart area
+    let label_bounds = create_memo(move |_| {
+        let (x, y) = point.get()?;
+        let (font_width, font_height) = (font_width.get(), font_height.get());
+        let (offset_x, offset_y) = offset.get();
+        let (cx, cy) = (x + offset_x * font_width, y - offset_y * font_height);
+        // Monospace font with half a character of padding
+        let chars = label.with(|label| label.chars().count()) as f64;
+        let half_width = (chars + 1.0) * font_width / 2.0;
+        let half_height = font_height;
+        let bounds = Bounds::from_points(
+            cx - half_width,
+            cy - half_height,
+            cx + half_width,
+            cy + half_height,
+        );
+        Some(nudge_inside(bounds, inner.get()))
+    });
+
+    // Arrow from the edge of the label to the point. Stops short to leave room for the arrow head
+    let arrow_line = create_memo(move |_| {
+        if !arrow.get() {
+            return None;
+        }
+        let point @ (x, y) = point.get()?;
+        let bounds = label_bounds.get()?;
+        let (x1, y1) = label_edge(bounds, point)?;
+        let length = (x - x1).hypot(y - y1);
+        if length <= ARROW_LENGTH {
+            return None;
+        }
+        let shorten = (length - ARROW_LENGTH) / length;
+        Some((x1, y1, x1 + (x - x1) * shorten, y1 + (y - y1) * shorten))
+    });
+
+    let get = move |f: fn(&Bounds) -> f64| move || label_bounds.get().as_ref().map(f);
+    let arrow_at =
+        move |f: fn(&(f64, f64, f64, f64)) -> f64| move || arrow_line.get().as_ref().map(f);
+    let colour = move || colour.get().to_string();
+    view! {
+        <g class="_chartistry_annotation">
+            <Show when=move || label_bounds.with(Option::is_some)>
+                <DebugRect label="annotation" debug=debug bounds=vec![Signal::derive(move || label_bounds.get().unwrap_or_default())] />
+                <Show when=move || callout.get()>
+                    <rect
+                        x=get(Bounds::left_x)
+                        y=get(Bounds::top_y)
+                        width=get(Bounds::width)
+                        height=get(Bounds::height)
+                        rx=move || font_width.get() / 2.0
+                        fill="white"
+                        stroke=colour />
+                </Show>
+                <Show when=move || arrow_line.with(Option::is_some)>
+                    <line
+                        x1=arrow_at(|a| a.0)
+                        y1=arrow_at(|a| a.1)
+                        x2=arrow_at(|a| a.2)
+                        y2=arrow_at(|a| a.3)
+                        stroke=colour
+                        stroke-width=1
+                        marker-end="url(#marker_axis_arrow)" />
+                </Show>
+                <text
+                    x=get(Bounds::centre_x)
+                    y=get(Bounds::centre_y)
+                    text-anchor="middle"
+                    dominant-baseline="middle"
+                    fill=colour
+                    font-family="monospace"
+                    font-size=move || format!("{}px", font_height.get())
+                    style="white-space: pre;">
+                    {label}
+                </text>
+            </Show>
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nudge_inside() {
+        let inner = Bounds::from_points(0.0, 0.0, 100.0, 100.0);
+        let nudge = |x1, y1, x2, y2| nudge_inside(Bounds::from_points(x1, y1, x2, y2), inner);
+        // Already inside
+        assert_eq!(
+            nudge(10.0, 10.0, 30.0, 20.0),
+            Bounds::from_points(10.0, 10.0, 30.0, 20.0)
+        );
+        // Off each edge
+        assert_eq!(
+            nudge(-5.0, 10.0, 15.0, 20.0),
+            Bounds::from_points(0.0, 10.0, 20.0, 20.0)
+        );
+        assert_eq!(
+            nudge(90.0, 10.0, 110.0, 20.0),
+            Bounds::from_points(80.0, 10.0, 100.0, 20.0)
+        );
+        assert_eq!(
+            nudge(10.0, -5.0, 30.0, 5.0),
+            Bounds::from_points(10.0, 0.0, 30.0, 10.0)
+        );
+        assert_eq!(
+            nudge(10.0, 95.0, 30.0, 105.0),
+            Bounds::from_points(10.0, 90.0, 30.0, 100.0)
+        );
+        // Too big: keep the top left corner
+        assert_eq!(
+            nudge(-10.0, -10.0, 120.0, 5.0),
+            Bounds::from_points(0.0, 0.0, 130.0, 15.0)
+        );
+    }
+
+    #[test]
+    fn test_label_edge() {
+        let label = Bounds::from_points(40.0, 40.0, 60.0, 50.0);
+        // Point inside
+        assert_eq!(label_edge(label, (50.0, 45.0)), None);
+        // Directly below, above, and to the side
+        assert_eq!(label_edge(label, (50.0, 80.0)), Some((50.0, 50.0)));
+        assert_eq!(label_edge(label, (50.0, 0.0)), Some((50.0, 40.0)));
+        assert_eq!(label_edge(label, (100.0, 45.0)), Some((60.0, 45.0)));
+    }
+}
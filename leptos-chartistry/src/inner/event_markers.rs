@@ -0,0 +1,218 @@
+use super::UseInner;
+use crate::{bounds::Bounds, colours::Colour, debug::DebugRect, state::State, Tick};
+use leptos::*;
+use std::rc::Rc;
+
+/// Default colour for event markers.
+pub const EVENT_MARKER_COLOUR: Colour = Colour::from_rgb(0x7b, 0x4d, 0xff);
+
+/// Builds vertical flags at the X positions of events e.g., deploys, alerts, or config changes.
+///
+/// Events come from their own data signal rather than the chart's `data` so they can be loaded separately from metrics. Hovering near a flag shows its label.
+///
+/// # Example
+/// ```rust
+/// # use leptos::*;
+/// # use leptos_chartistry::*;
+/// struct Deploy {
+///     at: f64,
+///     version: String,
+/// }
+/// # let deploys: Signal<Vec<Deploy>> = Signal::default();
+/// let markers = EventMarkers::new(deploys, |d: &Deploy| d.at, |d: &Deploy| d.version.clone());
+/// ```
+#[derive(Clone)]
+pub struct EventMarkers<X: 'static> {
+    events: Signal<Vec<(X, String)>>,
+    /// Colour of the flags and labels.
+    pub colour: RwSignal<Colour>,
+    /// Width of the flag lines.
+    pub width: RwSignal<f64>,
+}
+
+impl<X: Tick> EventMarkers<X> {
+    /// Creates new event markers from a signal of events. `get_x` returns the X value of an event and `get_label` its label.
+    pub fn new<E: 'static>(
+        events: impl Into<Signal<Vec<E>>>,
+        get_x: impl Fn(&E) -> X + 'static,
+        get_label: impl Fn(&E) -> String + 'static,
+    ) -> Self {
+        let events = events.into();
+        let events = create_memo(move |_| {
+            events.with(|events| {
+                events
+                    .iter()
+                    .map(|event| (get_x(event), get_label(event)))
+                    .collect::<Vec<_>>()
+            })
+        });
+        Self {
+            events: events.into(),
+            colour: create_rw_signal(EVENT_MARKER_COLOUR),
+            width: create_rw_signal(1.0),
+        }
+    }
+
+    /// Sets the colour of the flags.
+    pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+
+    /// Sets the width of the flag lines.
+    pub fn with_width(self, width: impl Into<f64>) -> Self {
+        self.width.set(width.into());
+        self
+    }
+}
+
+impl<X: Tick, Y: Tick> UseInner<X, Y> for EventMarkers<X> {
+    fn render(self: Rc<Self>, state: State<X, Y>) -> View {
+        view!( <EventMarkers markers=(*self).clone() state=state /> )
+    }
+}
+
+/// Finds the index of the nearest SVG X to the mouse. Returns None if none are within `max_distance`.
+fn nearest_event(xs: &[f64], mouse_x: f64, max_distance: f64) -> Option<usize> {
+    xs.iter()
+        .enumerate()
+        .map(|(index, x)| (index, (x - mouse_x).abs()))
+        .filter(|(_, distance)| *distance <= max_distance)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, _)| index)
+}
+
+#[component]
+fn EventMarkers<X: Tick, Y: 'static>(
+    markers: EventMarkers<X>,
+    state: State<X, Y>,
+) -> impl IntoView {
+    let EventMarkers {
+        events,
+        colour,
+        width,
+    } = markers;
+    let debug = state.pre.debug;
+    let font_height = state.pre.font_height;
+    let font_width = state.pre.font_width;
+    let inner = state.layout.inner;
+    let proj = state.projection;
+    let mouse_chart = state.mouse_chart;
+    let hover_inner = state.hover_inner;
+
+    // SVG X and label of each event inside the inner chart area
+    let flags = create_memo(move |_| {
+        let inner = inner.get();
+        let proj = proj.get();
+        events.with(|events| {
+            events
+                .iter()
+                .map(|(x, label)| (proj.position_to_svg(x.position(), 0.0).0, label.clone()))
+                .filter(|(x, _)| (inner.left_x()..=inner.right_x()).contains(x))
+                .collect::<Vec<_>>()
+        })
+    });
+
+    // Label of the flag nearest to the mouse
+    let hovered = create_memo(move |_| {
+        if !hover_inner.get() {
+            return None;
+        }
+        let (mouse_x, _) = mouse_chart.get();
+        flags.with(|flags| {
+            let xs = flags.iter().map(|(x, _)| *x).collect::<Vec<_>>();
+            nearest_event(&xs, mouse_x, font_width.get()).map(|index| flags[index].clone())
+        })
+    });
+
+    let flag_lines = move || {
+        let inner = inner.get();
+        let (top, bottom) = (inner.top_y(), inner.bottom_y());
+        let size = font_height.get() / 2.0;
+        flags
+            .get()
+            .into_iter()
+            .map(|(x, label)| {
+                view! {
+                    <line x1=x y1=top x2=x y2=bottom />
+                    <path
+                        d=format!("M{x},{top} l{size},{half} l-{size},{half} z", half = size / 2.0)
+                        stroke="none">
+                        <title>{label}</title>
+                    </path>
+                }
+            })
+            .collect_view()
+    };
+
+    // Label next to the hovered flag. Flips to the left near the right edge
+    let tooltip = move || {
+        let (x, label) = hovered.get()?;
+        let inner = inner.get();
+        let (font_width, font_height) = (font_width.get(), font_height.get());
+        let width = (label.chars().count() as f64 + 1.0) * font_width;
+        let height = font_height * 1.5;
+        let left = if x + font_width + width > inner.right_x() {
+            x - font_width - width
+        } else {
+            x + font_width
+        };
+        let bounds = Bounds::from_points(
+            left,
+            inner.top_y() + font_height,
+            left + width,
+            inner.top_y() + font_height + height,
+        );
+        Some(view! {
+            <g class="_chartistry_event_marker_tooltip">
+                <rect
+                    x=bounds.left_x()
+                    y=bounds.top_y()
+                    width=bounds.width()
+                    height=bounds.height()
+                    rx=font_width / 2.0
+                    fill="white"
+                    stroke-width=1 />
+                <text
+                    x=bounds.centre_x()
+                    y=bounds.centre_y()
+                    text-anchor="middle"
+                    dominant-baseline="middle"
+                    stroke="none"
+                    font-family="monospace"
+                    font-size=format!("{font_height}px")
+                    style="white-space: pre;">
+                    {label}
+                </text>
+            </g>
+        })
+    };
+
+    view! {
+        <g
+            class="_chartistry_event_markers"
+            stroke=move || colour.get().to_string()
+            fill=move || colour.get().to_string()
+            stroke-width=width>
+            <DebugRect label="event_markers" debug=debug />
+            {flag_lines}
+            {tooltip}
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearest_event() {
+        let xs = [10.0, 20.0, 24.0];
+        assert_eq!(nearest_event(&xs, 11.0, 5.0), Some(0));
+        assert_eq!(nearest_event(&xs, 23.0, 5.0), Some(2));
+        assert_eq!(nearest_event(&xs, 21.0, 5.0), Some(1));
+        // Too far away
+        assert_eq!(nearest_event(&xs, 50.0, 5.0), None);
+        assert_eq!(nearest_event(&[], 10.0, 5.0), None);
+    }
+}
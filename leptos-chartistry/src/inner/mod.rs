@@ -1,7 +1,10 @@
+pub mod annotation;
 pub mod axis_marker;
+pub mod event_markers;
 pub mod grid_line;
 pub mod guide_line;
 pub mod legend;
+pub mod reference;
 
 use crate::{state::State, Tick};
 use leptos::*;
@@ -26,6 +29,18 @@ pub enum InnerLayout<X: Tick, Y: Tick> {
     YGuideLine(guide_line::YGuideLine),
     /// Inset legend. See [InsetLegend](legend::InsetLegend) for details.
     Legend(legend::InsetLegend),
+    /// X reference line. See [XReferenceLine](reference::XReferenceLine) for details.
+    XReferenceLine(reference::XReferenceLine<X>),
+    /// Y reference line. See [YReferenceLine](reference::YReferenceLine) for details.
+    YReferenceLine(reference::YReferenceLine<Y>),
+    /// X band. See [XBand](reference::XBand) for details.
+    XBand(reference::XBand<X>),
+    /// Y band. See [YBand](reference::YBand) for details.
+    YBand(reference::YBand<Y>),
+    /// Text annotation. See [Annotation](annotation::Annotation) for details.
+    Annotation(annotation::Annotation<X, Y>),
+    /// Event markers. See [EventMarkers](event_markers::EventMarkers) for details.
+    EventMarkers(event_markers::EventMarkers<X>),
 }
 
 /// Convert a type (e.g., a [guide line](struct@guide_line::XGuideLine)) into an inner layout for use in a [Chart](crate::Chart).
@@ -43,6 +58,23 @@ impl<X: Tick, Y: Tick> InnerLayout<X, Y> {
             Self::XGuideLine(inner) => inner.use_horizontal(),
             Self::YGuideLine(inner) => inner.use_vertical(),
             Self::Legend(inner) => Rc::new(inner),
+            Self::XReferenceLine(inner) => Rc::new(inner),
+            Self::YReferenceLine(inner) => Rc::new(inner),
+            Self::XBand(inner) => Rc::new(inner),
+            Self::YBand(inner) => Rc::new(inner),
+            Self::Annotation(inner) => Rc::new(inner),
+            Self::EventMarkers(inner) => Rc::new(inner),
+        }
+    }
+
+    /// X and Y values the layout wants included in the chart's range. Reactive.
+    pub(crate) fn extents(&self) -> (Vec<X>, Vec<Y>) {
+        match self {
+            Self::XReferenceLine(inner) => (inner.extents(), vec![]),
+            Self::YReferenceLine(inner) => (vec![], inner.extents()),
+            Self::XBand(inner) => (inner.extents(), vec![]),
+            Self::YBand(inner) => (vec![], inner.extents()),
+            _ => (vec![], vec![]),
         }
     }
 }
@@ -78,3 +110,9 @@ impl_into_inner!(grid_line::YGridLine<Y>, YGridLine);
 impl_into_inner!(guide_line::XGuideLine, XGuideLine);
 impl_into_inner!(guide_line::YGuideLine, YGuideLine);
 impl_into_inner!(legend::InsetLegend, Legend);
+impl_into_inner!(reference::XReferenceLine<X>, XReferenceLine);
+impl_into_inner!(reference::YReferenceLine<Y>, YReferenceLine);
+impl_into_inner!(reference::XBand<X>, XBand);
+impl_into_inner!(reference::YBand<Y>, YBand);
+impl_into_inner!(annotation::Annotation<X, Y>, Annotation);
+impl_into_inner!(event_markers::EventMarkers<X>, EventMarkers);
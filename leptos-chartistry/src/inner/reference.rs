@@ -0,0 +1,421 @@
+use super::UseInner;
+use crate::{bounds::Bounds, colours::Colour, debug::DebugRect, state::State, Tick};
+use leptos::*;
+use std::rc::Rc;
+
+/// Default colour for reference lines and bands.
+pub const REFERENCE_COLOUR: Colour = Colour::from_rgb(0xF5, 0x32, 0x5B);
+
+/// Default dash pattern for reference lines.
+pub const REFERENCE_DASH: [f64; 2] = [4.0, 4.0];
+
+/// Default fill opacity for reference bands.
+pub const BAND_OPACITY: f64 = 0.15;
+
+macro_rules! impl_reference_line {
+    ($name:ident, $axis:literal) => {
+        #[doc = concat!("Builds a reference line at a fixed ", $axis, " value e.g., a threshold or deploy marker. Drawn across the inner chart area.")]
+        #[derive(Clone, Debug)]
+        pub struct $name<T: 'static> {
+            #[doc = concat!($axis, " value to draw the line at.")]
+            pub value: RwSignal<T>,
+            /// Label to show next to the line. Empty for none.
+            pub label: RwSignal<String>,
+            /// Colour of the line and label.
+            pub colour: RwSignal<Colour>,
+            /// Width of the line.
+            pub width: RwSignal<f64>,
+            /// Dash pattern of the line. Alternates between dash and gap lengths. Empty for a solid line.
+            pub dash: RwSignal<Vec<f64>>,
+            #[doc = concat!("Whether to extend the ", $axis, " range to include the line. Otherwise the line is hidden when outside of the range. Default is false.")]
+            pub extend_range: RwSignal<bool>,
+        }
+
+        impl<T: Tick> $name<T> {
+            #[doc = concat!("Creates a new reference line at the given ", $axis, " value.")]
+            pub fn new(value: T) -> Self {
+                Self {
+                    value: create_rw_signal(value),
+                    label: RwSignal::default(),
+                    colour: create_rw_signal(REFERENCE_COLOUR),
+                    width: create_rw_signal(1.0),
+                    dash: create_rw_signal(REFERENCE_DASH.to_vec()),
+                    extend_range: create_rw_signal(false),
+                }
+            }
+
+            /// Sets the label of the line.
+            pub fn with_label(self, label: impl Into<String>) -> Self {
+                self.label.set(label.into());
+                self
+            }
+
+            /// Sets the colour of the line.
+            pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+                self.colour.set(colour.into());
+                self
+            }
+
+            /// Sets the width of the line.
+            pub fn with_width(self, width: impl Into<f64>) -> Self {
+                self.width.set(width.into());
+                self
+            }
+
+            /// Sets the dash pattern of the line. Empty for a solid line.
+            pub fn with_dash(self, dash: impl Into<Vec<f64>>) -> Self {
+                self.dash.set(dash.into());
+                self
+            }
+
+            #[doc = concat!("Sets whether to extend the ", $axis, " range to include the line.")]
+            pub fn with_extend_range(self, extend_range: impl Into<bool>) -> Self {
+                self.extend_range.set(extend_range.into());
+                self
+            }
+
+            // Values to include in the range (if any)
+            pub(crate) fn extents(&self) -> Vec<T> {
+                if self.extend_range.get() {
+                    vec![self.value.get()]
+                } else {
+                    vec![]
+                }
+            }
+
+            fn style(&self) -> ReferenceStyle {
+                ReferenceStyle {
+                    label: self.label,
+                    colour: self.colour,
+                    width: self.width.into(),
+                    dash: self.dash.into(),
+                    opacity: Signal::default(),
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_band {
+    ($name:ident, $axis:literal) => {
+        #[doc = concat!("Builds a shaded band between two ", $axis, " values e.g., a maintenance window or target range. Drawn across the inner chart area.")]
+        #[derive(Clone, Debug)]
+        pub struct $name<T: 'static> {
+            #[doc = concat!($axis, " value the band starts at.")]
+            pub start: RwSignal<T>,
+            #[doc = concat!($axis, " value the band ends at.")]
+            pub end: RwSignal<T>,
+            /// Label to show inside the band. Empty for none.
+            pub label: RwSignal<String>,
+            /// Colour of the band and label.
+            pub colour: RwSignal<Colour>,
+            /// Fill opacity of the band.
+            pub opacity: RwSignal<f64>,
+            #[doc = concat!("Whether to extend the ", $axis, " range to include the band. Otherwise the band is clipped to the range. Default is false.")]
+            pub extend_range: RwSignal<bool>,
+        }
+
+        impl<T: Tick> $name<T> {
+            #[doc = concat!("Creates a new band between the given ", $axis, " values.")]
+            pub fn new(start: T, end: T) -> Self {
+                Self {
+                    start: create_rw_signal(start),
+                    end: create_rw_signal(end),
+                    label: RwSignal::default(),
+                    colour: create_rw_signal(REFERENCE_COLOUR),
+                    opacity: create_rw_signal(BAND_OPACITY),
+                    extend_range: create_rw_signal(false),
+                }
+            }
+
+            /// Sets the label of the band.
+            pub fn with_label(self, label: impl Into<String>) -> Self {
+                self.label.set(label.into());
+                self
+            }
+
+            /// Sets the colour of the band.
+            pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+                self.colour.set(colour.into());
+                self
+            }
+
+            /// Sets the fill opacity of the band.
+            pub fn with_opacity(self, opacity: impl Into<f64>) -> Self {
+                self.opacity.set(opacity.into());
+                self
+            }
+
+            #[doc = concat!("Sets whether to extend the ", $axis, " range to include the band.")]
+            pub fn with_extend_range(self, extend_range: impl Into<bool>) -> Self {
+                self.extend_range.set(extend_range.into());
+                self
+            }
+
+            // Values to include in the range (if any)
+            pub(crate) fn extents(&self) -> Vec<T> {
+                if self.extend_range.get() {
+                    vec![self.start.get(), self.end.get()]
+                } else {
+                    vec![]
+                }
+            }
+
+            fn style(&self) -> ReferenceStyle {
+                ReferenceStyle {
+                    label: self.label,
+                    colour: self.colour,
+                    width: Signal::default(),
+                    dash: Signal::default(),
+                    opacity: self.opacity.into(),
+                }
+            }
+        }
+    };
+}
+
+impl_reference_line!(XReferenceLine, "X");
+impl_reference_line!(YReferenceLine, "Y");
+impl_band!(XBand, "X");
+impl_band!(YBand, "Y");
+
+impl<X: Tick, Y: Tick> UseInner<X, Y> for XReferenceLine<X> {
+    fn render(self: Rc<Self>, state: State<X, Y>) -> View {
+        let value = self.value;
+        let pos = Signal::derive(move || value.with(|x| x.position()));
+        view!( <ReferenceLine id="x" is_x=true pos=pos line=self.style() state=state /> )
+    }
+}
+
+impl<X: Tick, Y: Tick> UseInner<X, Y> for YReferenceLine<Y> {
+    fn render(self: Rc<Self>, state: State<X, Y>) -> View {
+        let value = self.value;
+        let pos = Signal::derive(move || value.with(|y| y.position()));
+        view!( <ReferenceLine id="y" is_x=false pos=pos line=self.style() state=state /> )
+    }
+}
+
+impl<X: Tick, Y: Tick> UseInner<X, Y> for XBand<X> {
+    fn render(self: Rc<Self>, state: State<X, Y>) -> View {
+        let (start, end) = (self.start, self.end);
+        let pos = Signal::derive(move || (start.with(Tick::position), end.with(Tick::position)));
+        view!( <Band id="x" is_x=true pos=pos band=self.style() state=state /> )
+    }
+}
+
+impl<X: Tick, Y: Tick> UseInner<X, Y> for YBand<Y> {
+    fn render(self: Rc<Self>, state: State<X, Y>) -> View {
+        let (start, end) = (self.start, self.end);
+        let pos = Signal::derive(move || (start.with(Tick::position), end.with(Tick::position)));
+        view!( <Band id="y" is_x=false pos=pos band=self.style() state=state /> )
+    }
+}
+
+// Non-generic style shared by reference lines and bands
+#[derive(Clone)]
+struct ReferenceStyle {
+    label: RwSignal<String>,
+    colour: RwSignal<Colour>,
+    width: Signal<f64>,
+    dash: Signal<Vec<f64>>,
+    opacity: Signal<f64>,
+}
+
+#[component]
+fn ReferenceLine<X: 'static, Y: 'static>(
+    id: &'static str,
+    is_x: bool,
+    pos: Signal<f64>,
+    line: ReferenceStyle,
+    state: State<X, Y>,
+) -> impl IntoView {
+    let debug = state.pre.debug;
+    let inner = state.layout.inner;
+    let proj = state.projection;
+
+    // Line across the inner chart area. None if outside of it
+    let bounds = create_memo(move |_| {
+        let inner = inner.get();
+        let pos = pos.get();
+        if is_x {
+            let x = proj.get().position_to_svg(pos, 0.0).0;
+            (inner.left_x()..=inner.right_x())
+                .contains(&x)
+                .then(|| Bounds::from_points(x, inner.top_y(), x, inner.bottom_y()))
+        } else {
+            let y = proj.get().position_to_svg(0.0, pos).1;
+            (inner.top_y()..=inner.bottom_y())
+                .contains(&y)
+                .then(|| Bounds::from_points(inner.left_x(), y, inner.right_x(), y))
+        }
+    });
+    let get = move |f: fn(&Bounds) -> f64| move || bounds.get().as_ref().map(f);
+
+    let colour = line.colour;
+    let dash = line.dash;
+    view! {
+        <g
+            class=format!("_chartistry_{id}_reference_line")
+            stroke=move || colour.get().to_string()
+            stroke-width=line.width
+            stroke-dasharray=move || dash_array(&dash.get())>
+            <Show when=move || bounds.with(Option::is_some)>
+                <DebugRect label=format!("{id}_reference_line") debug=debug />
+                <line
+                    x1=get(Bounds::left_x)
+                    y1=get(Bounds::top_y)
+                    x2=get(Bounds::right_x)
+                    y2=get(Bounds::bottom_y) />
+                <ReferenceLabel is_x=is_x label=line.label colour=colour bounds=bounds font_height=state.pre.font_height font_width=state.pre.font_width />
+            </Show>
+        </g>
+    }
+}
+
+#[component]
+fn Band<X: 'static, Y: 'static>(
+    id: &'static str,
+    is_x: bool,
+    pos: Signal<(f64, f64)>,
+    band: ReferenceStyle,
+    state: State<X, Y>,
+) -> impl IntoView {
+    let debug = state.pre.debug;
+    let inner = state.layout.inner;
+    let proj = state.projection;
+
+    // Band clipped to the inner chart area. None if outside of it
+    let bounds = create_memo(move |_| {
+        let inner = inner.get();
+        let (start, end) = pos.get();
+        let proj = proj.get();
+        let clip = |a: f64, b: f64, min: f64, max: f64| {
+            let (a, b) = (a.min(b).max(min), a.max(b).min(max));
+            (a < b).then_some((a, b))
+        };
+        if is_x {
+            let (start, end) = (
+                proj.position_to_svg(start, 0.0).0,
+                proj.position_to_svg(end, 0.0).0,
+            );
+            let (left, right) = clip(start, end, inner.left_x(), inner.right_x())?;
+            Some(Bounds::from_points(
+                left,
+                inner.top_y(),
+                right,
+                inner.bottom_y(),
+            ))
+        } else {
+            let (start, end) = (
+                proj.position_to_svg(0.0, start).1,
+                proj.position_to_svg(0.0, end).1,
+            );
+            let (top, bottom) = clip(start, end, inner.top_y(), inner.bottom_y())?;
+            Some(Bounds::from_points(
+                inner.left_x(),
+                top,
+                inner.right_x(),
+                bottom,
+            ))
+        }
+    });
+    let get = move |f: fn(&Bounds) -> f64| move || bounds.get().as_ref().map(f);
+
+    let colour = band.colour;
+    view! {
+        <g class=format!("_chartistry_{id}_band")>
+            <Show when=move || bounds.with(Option::is_some)>
+                <DebugRect label=format!("{id}_band") debug=debug />
+                <rect
+                    x=get(Bounds::left_x)
+                    y=get(Bounds::top_y)
+                    width=get(Bounds::width)
+                    height=get(Bounds::height)
+                    fill=move || colour.get().to_string()
+                    fill-opacity=band.opacity />
+                <ReferenceLabel is_x=is_x label=band.label colour=colour bounds=bounds font_height=state.pre.font_height font_width=state.pre.font_width />
+            </Show>
+        </g>
+    }
+}
+
+// Label placed inside the top left (X) or top right (Y) corner of the reference
+#[component]
+fn ReferenceLabel(
+    is_x: bool,
+    label: RwSignal<String>,
+    colour: RwSignal<Colour>,
+    bounds: Memo<Option<Bounds>>,
+    font_height: Memo<f64>,
+    font_width: Memo<f64>,
+) -> impl IntoView {
+    let position = move || {
+        let bounds = bounds.get().unwrap_or_default();
+        let pad = font_width.get() / 2.0;
+        if is_x {
+            (
+                bounds.left_x() + pad,
+                bounds.top_y() + pad,
+                "start",
+                "hanging",
+            )
+        } else {
+            (bounds.right_x() - pad, bounds.top_y() - pad, "end", "auto")
+        }
+    };
+    view! {
+        <Show when=move || label.with(|label| !label.is_empty())>
+            <text
+                x=move || position().0
+                y=move || position().1
+                text-anchor=move || position().2
+                dominant-baseline=move || position().3
+                stroke="none"
+                fill=move || colour.get().to_string()
+                font-family="monospace"
+                font-size=move || format!("{}px", font_height.get())
+                style="white-space: pre;">
+                {label}
+            </text>
+        </Show>
+    }
+}
+
+fn dash_array(dash: &[f64]) -> String {
+    dash.iter()
+        .map(|d| d.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{series::UseData, InnerLayout, IntoInner, Line, Series};
+
+    #[test]
+    fn test_extend_range() {
+        // Left out of the range by default
+        let line = YReferenceLine::new(50.0);
+        assert!(line.extents().is_empty());
+        line.extend_range.set(true);
+        assert_eq!(line.extents(), [50.0]);
+        // Follows the value
+        line.value.set(80.0);
+        assert_eq!(line.extents(), [80.0]);
+        let band = XBand::new(2.0, 4.0).with_extend_range(true);
+        assert_eq!(band.extents(), [2.0, 4.0]);
+
+        // Extents of each inner layout extend their own axis
+        let inner: [InnerLayout<f64, f64>; 2] = [line.into_inner(), band.into_inner()];
+        let extents = Signal::derive(move || {
+            let (x, y): (Vec<_>, Vec<_>) = inner.iter().map(InnerLayout::extents).unzip();
+            (x.concat(), y.concat())
+        });
+        let series = Series::new(|&(x, _): &(f64, f64)| x).line(Line::new(|&(_, y)| y));
+        let data = create_rw_signal(vec![(1.0, 10.0), (3.0, 20.0)]);
+        let data = UseData::new(series, data.into(), extents, None);
+        assert_eq!(data.range_x.get().positions(), Some((1.0, 4.0)));
+        assert_eq!(data.range_y.get().positions(), Some((10.0, 80.0)));
+    }
+}
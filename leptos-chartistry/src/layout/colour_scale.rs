@@ -0,0 +1,193 @@
+use super::{UseLayout, UseVerticalLayout};
+use crate::{
+    bounds::Bounds,
+    colours::LinearGradientSvg,
+    debug::DebugRect,
+    edge::Edge,
+    state::{PreState, State},
+};
+use leptos::*;
+
+const GRADIENT_ID: &str = "colour_scale_gradient";
+
+/// Builds a colour scale for a [Heatmap](crate::Heatmap): a gradient bar across the edge labelled with the minimum and maximum values. Uses the first heatmap in the series. Empty if there are no heatmaps.
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct ColourScale {}
+
+impl ColourScale {
+    /// Creates a new colour scale.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn labels<X, Y>(state: &PreState<X, Y>) -> Memo<Option<(String, String)>> {
+        let heat_range = state.data.heat_range;
+        create_memo(move |_| {
+            heat_range
+                .get()
+                .map(|(min, max)| (format_value(min), format_value(max)))
+        })
+    }
+
+    pub(super) fn fixed_height<X, Y>(&self, state: &PreState<X, Y>) -> Signal<f64> {
+        let font_height = state.font_height;
+        let padding = state.padding;
+        let labels = Self::labels(state);
+        Signal::derive(move || {
+            if labels.with(Option::is_none) {
+                return 0.0;
+            }
+            // Bar then labels
+            font_height.get() * 2.0 + padding.get().height()
+        })
+    }
+
+    pub(super) fn to_horizontal_use(&self) -> UseLayout {
+        UseLayout::ColourScale(self.clone())
+    }
+
+    pub(super) fn to_vertical_use<X, Y>(&self, state: &PreState<X, Y>) -> UseVerticalLayout {
+        let font_width = state.font_width;
+        let padding = state.padding;
+        let labels = Self::labels(state);
+        let width = Signal::derive(move || {
+            let Some((min, max)) = labels.get() else {
+                return 0.0;
+            };
+            // Bar, gap, then labels
+            let chars = min.chars().count().max(max.chars().count()) as f64;
+            let font_width = font_width.get();
+            font_width * 2.5 + chars * font_width + padding.get().width()
+        });
+        UseVerticalLayout {
+            width,
+            layout: UseLayout::ColourScale(self.clone()),
+        }
+    }
+}
+
+/// Formats a value to at most two decimals without trailing zeros e.g., 2.001 is "2" and 1.50 is "1.5". Shared by the scale, heatmap cells, and pie slices.
+pub(crate) fn format_value(value: f64) -> String {
+    let value = format!("{value:.2}");
+    let value = value.trim_end_matches('0').trim_end_matches('.');
+    // Small negatives round to zero
+    if value == "-0" {
+        "0".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+#[component]
+pub(super) fn ColourScale<X: 'static, Y: 'static>(
+    edge: Edge,
+    bounds: Memo<Bounds>,
+    state: State<X, Y>,
+) -> impl IntoView {
+    let debug = state.pre.debug;
+    let font_height = state.pre.font_height;
+    let font_width = state.pre.font_width;
+    let padding = state.pre.padding;
+    let series = state.pre.data.series;
+    let heat_range = state.pre.data.heat_range;
+    let labels = ColourScale::labels(&state.pre);
+
+    let scheme = create_memo(move |_| {
+        series.with(|series| {
+            series
+                .iter()
+                .find_map(|use_y| use_y.heatmap().map(|heatmap| heatmap.colours.get()))
+        })
+    });
+    let inner = Signal::derive(move || padding.get().apply(bounds.get()));
+
+    // Gradient bar and label positions: (bar, min label (x, y), max label (x, y))
+    let horizontal = edge.is_horizontal();
+    let layout = create_memo(move |_| {
+        let inner = inner.get();
+        let (font_height, font_width) = (font_height.get(), font_width.get());
+        if horizontal {
+            let bar = Bounds::from_points(
+                inner.left_x(),
+                inner.top_y(),
+                inner.right_x(),
+                inner.top_y() + font_height,
+            );
+            let label_y = bar.bottom_y() + font_height / 2.0;
+            (bar, (bar.left_x(), label_y), (bar.right_x(), label_y))
+        } else {
+            let bar = Bounds::from_points(
+                inner.left_x(),
+                inner.top_y(),
+                inner.left_x() + font_width * 2.0,
+                inner.bottom_y(),
+            );
+            let label_x = bar.right_x() + font_width / 2.0;
+            (bar, (label_x, bar.bottom_y()), (label_x, bar.top_y()))
+        }
+    });
+    let bar = move |f: fn(&Bounds) -> f64| move || layout.with(|(bar, _, _)| f(bar));
+
+    let body = move || {
+        let (min, max) = labels.get()?;
+        scheme.get()?;
+        let ((min_x, min_y), (max_x, max_y)) = layout.with(|(_, min, max)| (*min, *max));
+        // Labels sit along the edge: anchor at the ends of horizontal bars
+        let (min_anchor, max_anchor) = if horizontal {
+            ("start", "end")
+        } else {
+            ("start", "start")
+        };
+        let (min_baseline, max_baseline) = if horizontal {
+            ("middle", "middle")
+        } else {
+            ("auto", "hanging")
+        };
+        Some(view! {
+            <defs>
+                <LinearGradientSvg
+                    id=GRADIENT_ID
+                    scheme=Signal::derive(move || scheme.get().unwrap_or_else(|| crate::series::HEATMAP_GRADIENT.into()))
+                    range_y=heat_range.into()
+                    horizontal=horizontal />
+            </defs>
+            <rect
+                x=bar(Bounds::left_x)
+                y=bar(Bounds::top_y)
+                width=bar(Bounds::width)
+                height=bar(Bounds::height)
+                fill=format!("url(#{GRADIENT_ID})") />
+            <text x=min_x y=min_y text-anchor=min_anchor dominant-baseline=min_baseline>{min}</text>
+            <text x=max_x y=max_y text-anchor=max_anchor dominant-baseline=max_baseline>{max}</text>
+        })
+    };
+
+    view! {
+        <g
+            class="_chartistry_colour_scale"
+            font-family="monospace"
+            font-size=move || format!("{}px", font_height.get())>
+            <DebugRect label="ColourScale" debug=debug bounds=vec![bounds.into(), inner] />
+            {body}
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(3.0), "3");
+        assert_eq!(format_value(-12.0), "-12");
+        assert_eq!(format_value(0.5), "0.5");
+        assert_eq!(format_value(1.234), "1.23");
+        assert_eq!(format_value(2.001), "2");
+        assert_eq!(format_value(0.001), "0");
+        assert_eq!(format_value(-0.001), "0");
+        assert_eq!(format_value(10.0), "10");
+        assert_eq!(format_value(0.1 + 0.2), "0.3");
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
     edge::Edge,
     series::{Snippet, UseY},
     state::{PreState, State},
-    Padding,
+    Colour, Padding,
 };
 use leptos::*;
 
@@ -41,19 +41,45 @@ impl Legend {
         let font_width = state.font_width;
         let padding = state.padding;
         let series = state.data.series;
+        let summaries = state.data.summaries;
         let snippet_bounds = UseY::snippet_width(font_height, font_width);
         Signal::derive(move || {
             let font_width = font_width.get();
+            let summaries = summaries.get();
             let max_chars = series
                 .get()
                 .into_iter()
-                .map(|line| line.name.get().len() as f64 * font_width)
+                .map(|line| {
+                    // Summary is shown in brackets after the name
+                    let summary =
+                        (summaries.get(&line.id)).map_or(0, |summary| summary.chars().count() + 3);
+                    (line.name.get().len() + summary) as f64 * font_width
+                })
                 .reduce(f64::max)
                 .unwrap_or_default();
             snippet_bounds.get() + max_chars + padding.get().width()
         })
     }
 
+    /// Width of a vertical [SwatchLegend] listing named colours.
+    pub(crate) fn swatch_width(
+        entries: Memo<Vec<(String, Colour)>>,
+        font_width: Memo<f64>,
+        padding: Memo<Padding>,
+    ) -> Memo<f64> {
+        create_memo(move |_| {
+            let font_width = font_width.get();
+            let max_chars = entries.with(|entries| {
+                (entries.iter())
+                    .map(|(name, _)| name.chars().count())
+                    .max()
+                    .unwrap_or_default()
+            });
+            // Swatch, gap, then name
+            font_width * 2.5 + max_chars as f64 * font_width + padding.get().width()
+        })
+    }
+
     pub(crate) fn fixed_height<X, Y>(&self, state: &PreState<X, Y>) -> Signal<f64> {
         let font_height = state.font_height;
         let padding = state.padding;
@@ -150,7 +176,7 @@ fn VerticalBody<X: Clone + 'static, Y: Clone + 'static>(
             let:series>
             <tr>
                 <td style:padding=padding>
-                    <Snippet series=series state=state.clone() />
+                    <Snippet series=series state=state.clone() summary=true />
                 </td>
             </tr>
         </For>
@@ -174,9 +200,80 @@ fn HorizontalBody<X: Clone + 'static, Y: Clone + 'static>(
                 key=|(_, series)| series.id
                 let:series>
                 <td style:padding-left=move || padding_left(series.0)>
-                    <Snippet series=series.1 state=state.clone() />
+                    <Snippet series=series.1 state=state.clone() summary=true />
                 </td>
             </For>
         </tr>
     }
 }
+
+/// Vertical legend of named colours for charts drawn without a [Series](crate::Series) e.g., [PieChart](crate::PieChart).
+#[component]
+pub(crate) fn SwatchLegend(
+    legend: Legend,
+    entries: Memo<Vec<(String, Colour)>>,
+    bounds: Memo<Bounds>,
+    debug: Memo<bool>,
+    font_height: Memo<f64>,
+    font_width: Memo<f64>,
+    padding: Memo<Padding>,
+) -> impl IntoView {
+    let anchor = legend.anchor;
+    let rows = move || {
+        let padding = padding.get();
+        entries
+            .get()
+            .into_iter()
+            .map(|(name, colour)| {
+                view! {
+                    <tr>
+                        <td style:padding=format!("0 {}px 0 {}px", padding.right, padding.left)>
+                            <Swatch colour=colour font_height=font_height font_width=font_width />
+                            {name}
+                        </td>
+                    </tr>
+                }
+            })
+            .collect_view()
+    };
+    view! {
+        <g class="_chartistry_legend">
+            <DebugRect label="Legend" debug=debug bounds=vec![bounds.into()] />
+            <foreignObject
+                x=move || bounds.get().left_x()
+                y=move || bounds.get().top_y()
+                width=move || bounds.get().width()
+                height=move || bounds.get().height()
+                style="overflow: visible;">
+                <div
+                    style="display: flex; flex-direction: column; height: 100%; overflow: auto;"
+                    style:justify-content=move || anchor.get().css_justify_content()>
+                    <table
+                        style="border-collapse: collapse; border-spacing: 0; margin: 0;"
+                        style:font-size=move || format!("{}px", font_height.get())>
+                        <tbody>{rows}</tbody>
+                    </table>
+                </div>
+            </foreignObject>
+        </g>
+    }
+}
+
+/// Square of colour identifying an entry in a [SwatchLegend] or tooltip.
+#[component]
+pub(crate) fn Swatch(
+    colour: Colour,
+    font_height: Memo<f64>,
+    font_width: Memo<f64>,
+) -> impl IntoView {
+    view! {
+        <svg
+            width=move || font_width.get() * 2.5
+            height=move || font_height.get()
+            viewBox="0 0 10 10"
+            preserveAspectRatio="xMinYMid meet"
+            style="vertical-align: middle;">
+            <rect x=0 y=0 width=10 height=10 fill=colour />
+        </svg>
+    }
+}
@@ -1,3 +1,4 @@
+pub mod colour_scale;
 mod compose;
 pub mod legend;
 pub mod rotated_label;
@@ -20,6 +21,8 @@ use leptos::*;
 #[doc(hidden)]
 #[non_exhaustive]
 pub enum EdgeLayout<Tick: 'static> {
+    /// Colour scale. See [colour_scale](struct@colour_scale::ColourScale) for details.
+    ColourScale(colour_scale::ColourScale),
     /// Legend. See [legend](struct@legend::Legend) for details.
     Legend(legend::Legend),
     /// Rotated label. See [rotated_label](struct@rotated_label::RotatedLabel) for details.
@@ -35,6 +38,7 @@ struct UseVerticalLayout {
 
 #[derive(Clone)]
 enum UseLayout {
+    ColourScale(colour_scale::ColourScale),
     Legend(legend::Legend),
     RotatedLabel(rotated_label::RotatedLabel),
     TickLabels(tick_labels::UseTickLabels),
@@ -48,6 +52,9 @@ impl UseLayout {
         state: State<X, Y>,
     ) -> View {
         match self {
+            Self::ColourScale(_) => view! {
+                <colour_scale::ColourScale edge=edge bounds=bounds state=state />
+            },
             Self::Legend(inner) => view! {
                 <legend::Legend legend=inner edge=edge bounds=bounds state=state />
             },
@@ -64,6 +71,7 @@ impl UseLayout {
 impl<Tick: crate::Tick> EdgeLayout<Tick> {
     fn fixed_height<Y>(&self, state: &PreState<Tick, Y>) -> Signal<f64> {
         match self {
+            Self::ColourScale(inner) => inner.fixed_height(state),
             Self::Legend(inner) => inner.fixed_height(state),
             Self::RotatedLabel(inner) => inner.fixed_height(state),
             Self::TickLabels(inner) => inner.fixed_height(state),
@@ -74,6 +82,7 @@ impl<Tick: crate::Tick> EdgeLayout<Tick> {
 impl<X: Tick> EdgeLayout<X> {
     fn to_horizontal_use<Y>(&self, state: &PreState<X, Y>, avail_width: Memo<f64>) -> UseLayout {
         match self {
+            Self::ColourScale(inner) => inner.to_horizontal_use(),
             Self::Legend(inner) => inner.to_horizontal_use(),
             Self::RotatedLabel(inner) => inner.to_horizontal_use(),
             Self::TickLabels(inner) => inner.to_horizontal_use(state, avail_width),
@@ -88,6 +97,7 @@ impl<Y: Tick> EdgeLayout<Y> {
         avail_height: Memo<f64>,
     ) -> UseVerticalLayout {
         match self {
+            Self::ColourScale(inner) => inner.to_vertical_use(state),
             Self::Legend(inner) => inner.to_vertical_use(state),
             Self::RotatedLabel(inner) => inner.to_vertical_use(state),
             Self::TickLabels(inner) => inner.to_vertical_use(state, avail_height),
@@ -122,6 +132,7 @@ macro_rules! impl_into_edge {
         }
     };
 }
+impl_into_edge!(colour_scale::ColourScale, ColourScale);
 impl_into_edge!(legend::Legend, Legend);
 impl_into_edge!(rotated_label::RotatedLabel, RotatedLabel);
 impl_into_edge!(tick_labels::TickLabels<V>, TickLabels);
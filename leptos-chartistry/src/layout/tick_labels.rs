@@ -5,7 +5,7 @@ use crate::{
     edge::Edge,
     state::{PreState, State},
     ticks::{
-        AlignedFloats, GeneratedTicks, HorizontalSpan, TickFormat, TickFormatFn, TickGen,
+        AlignedFloats, GeneratedTicks, HorizontalSpan, Lanes, TickFormat, TickFormatFn, TickGen,
         Timestamps, VerticalSpan,
     },
     Tick,
@@ -54,6 +54,11 @@ impl TickLabels<f64> {
     pub fn aligned_floats() -> Self {
         Self::from_generator(AlignedFloats::default())
     }
+
+    /// Creates a new tick label generator for named lanes. See [Lanes] for details.
+    pub fn lanes(names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self::from_generator(Lanes::new(names))
+    }
 }
 
 impl<Tz> TickLabels<DateTime<Tz>>
@@ -151,6 +156,18 @@ impl<X: Tick> TickLabels<X> {
         .into()
     }
 
+    /// Formats an X value using the tick state generated for the inner chart width. Use within a reactive context.
+    pub(crate) fn format_x<Y>(&self, state: &State<X, Y>) -> impl Fn(&X) -> String + Clone {
+        let inner = state.layout.inner;
+        let avail_width = Signal::derive(move || with!(|inner| inner.width()));
+        let format = self.format;
+        let ticks = self.generate_x(&state.pre, avail_width);
+        move |x| {
+            let format = format.get();
+            ticks.with(|ticks| (format)(x, ticks.state.as_ref()))
+        }
+    }
+
     pub(super) fn fixed_height<Y>(&self, state: &PreState<X, Y>) -> Signal<f64> {
         let font_height = state.font_height;
         let padding = state.padding;
@@ -194,6 +211,18 @@ impl<Y: Tick> TickLabels<Y> {
         .into()
     }
 
+    /// Formats a Y value using the tick state generated for the inner chart height. Use within a reactive context.
+    pub(crate) fn format_y<X>(&self, state: &State<X, Y>) -> impl Fn(&Y) -> String + Clone {
+        let inner = state.layout.inner;
+        let avail_height = Signal::derive(move || with!(|inner| inner.height()));
+        let format = self.format;
+        let ticks = self.generate_y(&state.pre, avail_height);
+        move |y| {
+            let format = format.get();
+            ticks.with(|ticks| (format)(y, ticks.state.as_ref()))
+        }
+    }
+
     pub(super) fn to_vertical_use<X>(
         &self,
         state: &PreState<X, Y>,
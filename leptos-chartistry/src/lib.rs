@@ -1,7 +1,7 @@
 #![warn(missing_docs)]
 //! Welcome to Chartistry! This crate provides a flexible way to build charts in [Leptos](https://github.com/leptos-rs/leptos).
 //!
-//! All charts are built using the [Chart] fn. If you understand this function, you understand this library.
+//! All charts are built using the [Chart] fn. If you understand this function, you understand this library. Share-of-total views are drawn separately with [PieChart] and multi-dimensional comparisons with [RadarChart].
 //!
 //! ## Examples
 //!
@@ -51,34 +51,48 @@
 //! # }
 //! ```
 
+mod a11y;
 mod aspect_ratio;
 mod bounds;
 mod chart;
 mod colours;
 mod debug;
 mod edge;
+mod group;
 mod inner;
 mod layout;
 mod overlay;
 mod padding;
+mod pie;
 mod projection;
+mod radar;
 mod series;
 mod state;
+mod stream;
 mod ticks;
 mod use_watched_node;
 
+pub use a11y::DataTable;
 pub use aspect_ratio::AspectRatio;
 pub use chart::Chart;
 pub use colours::{Colour, ColourScheme, DivergingGradient, SequentialGradient};
 pub use edge::Edge;
+pub use group::ChartGroup;
 pub use inner::{
+    annotation::{Annotation, ANNOTATION_COLOUR, ANNOTATION_OFFSET},
     axis_marker::{AxisMarker, AxisPlacement, AXIS_MARKER_COLOUR},
+    event_markers::{EventMarkers, EVENT_MARKER_COLOUR},
     grid_line::{XGridLine, YGridLine, GRID_LINE_COLOUR},
     guide_line::{AlignOver, XGuideLine, YGuideLine, GUIDE_LINE_COLOUR},
     legend::InsetLegend,
+    reference::{
+        XBand, XReferenceLine, YBand, YReferenceLine, BAND_OPACITY, REFERENCE_COLOUR,
+        REFERENCE_DASH,
+    },
     InnerLayout, IntoInner,
 };
 pub use layout::{
+    colour_scale::ColourScale,
     legend::Legend,
     rotated_label::{Anchor, RotatedLabel},
     tick_labels::TickLabels,
@@ -86,8 +100,16 @@ pub use layout::{
 };
 pub use overlay::tooltip::{Tooltip, TooltipPlacement, TooltipSortBy, TOOLTIP_CURSOR_DISTANCE};
 pub use padding::Padding;
+pub use pie::{Pie, PieChart, PIE_DIM_OPACITY, PIE_HOVER_OFFSET, PIE_LABEL_THRESHOLD};
+pub use radar::{Radar, RadarChart, RADAR_FILL_OPACITY};
 pub use series::{
-    Bar, BarPlacement, Interpolation, Line, Marker, MarkerShape, Series, Stack, Step, BAR_GAP,
-    BAR_GAP_INNER, DIVERGING_GRADIENT, LINEAR_GRADIENT, SERIES_COLOUR_SCHEME, STACK_COLOUR_SCHEME,
+    Aggregate, Bar, BarPlacement, Binning, BoxPlot, BoxSummary, Bucket, Buckets, Candlestick,
+    DataLabelPlacement, ErrorStyle, GapPolicy, Heatmap, Histogram, HistogramBin, Interpolation,
+    Line, Marker, MarkerShape, RangeBar, Regression, Series, Stack, StackOffset, Step, Trend,
+    Waterfall, BAR_GAP, BAR_GAP_INNER, BOX_PLOT_OPACITY, CANDLESTICK_DOWN_COLOUR,
+    CANDLESTICK_UP_COLOUR, DIVERGING_GRADIENT, ERROR_BAND_OPACITY, GAP_DASH, HEATMAP_GRADIENT,
+    LINEAR_GRADIENT, MAX_BUCKETS, RANGE_BAR_GAP, SERIES_COLOUR_SCHEME, STACK_COLOUR_SCHEME,
+    WATERFALL_DECREASE_COLOUR, WATERFALL_INCREASE_COLOUR, WATERFALL_SUBTOTAL_COLOUR,
 };
-pub use ticks::{AlignedFloats, Period, Tick, Timestamps};
+pub use stream::{ChartData, Stream};
+pub use ticks::{AlignedFloats, Lanes, Period, Tick, Timestamps};
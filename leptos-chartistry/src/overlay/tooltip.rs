@@ -1,6 +1,6 @@
 use crate::{
     debug::DebugRect,
-    series::{Snippet, UseY},
+    series::{Extra, Snippet, UseY},
     state::State,
     Tick, TickLabels, AXIS_MARKER_COLOUR,
 };
@@ -215,40 +215,25 @@ pub(crate) fn Tooltip<X: Tick, Y: Tick>(
     let font_height = state.pre.font_height;
     let font_width = state.pre.font_width;
     let padding = state.pre.padding;
-    let inner = state.layout.inner;
 
     let x_body = {
         let nearest_data_x = state.pre.data.nearest_data_x(state.hover_position_x);
-        let x_format = x_ticks.format;
-        let avail_width = Signal::derive(move || with!(|inner| inner.width()));
-        let x_ticks = x_ticks.generate_x(&state.pre, avail_width);
+        let format_x = x_ticks.format_x(&state);
         move || {
             // Hide ticks?
             if !show_x_ticks.get() {
                 return "".to_string();
             }
-            let x_format = x_format.get();
-            with!(|nearest_data_x, x_ticks| {
-                nearest_data_x.as_ref().map_or_else(
-                    || "no data".to_string(),
-                    |x_value| (x_format)(x_value, x_ticks.state.as_ref()),
-                )
-            })
+            nearest_data_x
+                .get()
+                .map_or_else(|| "no data".to_string(), |x_value| format_x(&x_value))
         }
     };
 
     let format_y_value = {
-        let avail_height = Signal::derive(move || with!(|inner| inner.height()));
-        let y_format = y_ticks.format;
-        let y_ticks = y_ticks.generate_y(&state.pre, avail_height);
+        let format_y = y_ticks.format_y(&state);
         move |y_value: Option<Y>| {
-            let y_format = y_format.get();
-            y_ticks.with(|y_ticks| {
-                y_value.as_ref().map_or_else(
-                    || "-".to_string(),
-                    |y_value| (y_format)(y_value, y_ticks.state.as_ref()),
-                )
-            })
+            y_value.map_or_else(|| "-".to_string(), |y_value| format_y(&y_value))
         }
     };
 
@@ -269,12 +254,26 @@ pub(crate) fn Tooltip<X: Tick, Y: Tick>(
         })
     };
 
+    // Candlesticks show all four values and percent stacks show their share alongside
+    let format_ohlc = y_ticks.format_y(&state);
+    let nearest_extras = state.pre.data.nearest_extras(state.hover_position_x);
+
     let nearest_data_y = move || {
+        let extras = nearest_extras.get();
         nearest_y_values
             .get()
             .into_iter()
             .map(|(line, y_value)| {
-                let y_value = format_y_value(y_value);
+                let y_value = match extras.get(&line.id) {
+                    Some(Extra::Ohlc(ohlc)) => {
+                        let [open, high, low, close] = ohlc.each_ref().map(&format_ohlc);
+                        format!("O {open} H {high} L {low} C {close}")
+                    }
+                    Some(Extra::Share(share)) if share.is_finite() => {
+                        format!("{} ({:.0}%)", format_y_value(y_value), share * 100.0)
+                    }
+                    _ => format_y_value(y_value),
+                };
                 (line, y_value)
             })
             .collect::<Vec<_>>()
@@ -310,14 +309,14 @@ pub(crate) fn Tooltip<X: Tick, Y: Tick>(
                 <h2
                     style="margin: 0; text-align: center;"
                     style:font-size=move || format!("{}px", font_height.get())>
-                    {x_body}
+                    {x_body.clone()}
                 </h2>
                 <table
                     style="border-collapse: collapse; border-spacing: 0; margin: 0 0 0 auto; padding: 0;"
                     style:font-size=move || format!("{}px", font_height.get())>
                     <tbody>
                         <For
-                            each=nearest_data_y
+                            each=nearest_data_y.clone()
                             key=|(series, y_value)| (series.id, y_value.to_owned())
                             children=series_tr.clone()
                         />
@@ -0,0 +1,545 @@
+use crate::{
+    aspect_ratio::KnownAspectRatio,
+    bounds::Bounds,
+    chart::{FONT_HEIGHT, FONT_WIDTH},
+    colours::{Colour, ColourScheme},
+    debug::DebugRect,
+    layout::{
+        colour_scale::format_value,
+        legend::{Swatch, SwatchLegend},
+    },
+    overlay::tooltip::TooltipPlacement,
+    projection::polar_to_svg,
+    series::SERIES_COLOUR_SCHEME,
+    use_watched_node::use_watched_node,
+    AspectRatio, Legend, Padding, Tooltip, AXIS_MARKER_COLOUR,
+};
+use leptos::{html::Div, *};
+use std::{f64::consts::TAU, rc::Rc};
+
+/// Distance (px) a hovered slice is pulled out from the centre.
+pub const PIE_HOVER_OFFSET: f64 = 6.0;
+/// Opacity of the other slices while a slice is hovered.
+pub const PIE_DIM_OPACITY: f64 = 0.5;
+/// Default minimum share of the total a slice needs to be labelled. Avoids overlapping labels on thin slices.
+pub const PIE_LABEL_THRESHOLD: f64 = 0.05;
+
+/// Describes the slices of a [PieChart].
+///
+/// Each datum (`T`) is one slice. Its name comes from `get_name` and its size from `get_value`. Slices are drawn clockwise from 12 o'clock in data order. Values that are not positive (or are `NaN`) are not drawn.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Share { browser: String, visits: f64 }
+/// let pie = Pie::new(|s: &Share| s.browser.clone(), |s: &Share| s.visits)
+///     // Turns the pie into a donut
+///     .with_inner_radius(0.5);
+/// ```
+#[non_exhaustive]
+pub struct Pie<T> {
+    get_name: Rc<dyn Fn(&T) -> String>,
+    get_value: Rc<dyn Fn(&T) -> f64>,
+    /// Colour scheme of the slices. If there are more slices than colours, the colours will repeat. Default is [SERIES_COLOUR_SCHEME].
+    pub colours: RwSignal<ColourScheme>,
+    /// Radius of the hole in the middle as a ratio of the outer radius. Zero draws a pie and anything above draws a donut. Clamped to 0.0 and 0.95. Default is 0.0.
+    pub inner_radius: RwSignal<f64>,
+    /// Whether to label slices with their percentage of the total. Default is true.
+    pub show_labels: RwSignal<bool>,
+    /// Minimum share of the total (0.0 to 1.0) a slice needs to be labelled. Default is [PIE_LABEL_THRESHOLD].
+    pub label_threshold: RwSignal<f64>,
+}
+
+impl<T> Pie<T> {
+    /// Create a new pie. `get_name` returns the slice's name (used in the legend and tooltip) and `get_value` its size.
+    pub fn new(
+        get_name: impl Fn(&T) -> String + 'static,
+        get_value: impl Fn(&T) -> f64 + 'static,
+    ) -> Self {
+        Self {
+            get_name: Rc::new(get_name),
+            get_value: Rc::new(get_value),
+            colours: create_rw_signal(SERIES_COLOUR_SCHEME.into()),
+            inner_radius: RwSignal::default(),
+            show_labels: create_rw_signal(true),
+            label_threshold: create_rw_signal(PIE_LABEL_THRESHOLD),
+        }
+    }
+
+    /// Set the colour scheme of the slices.
+    pub fn with_colours(self, colours: impl Into<ColourScheme>) -> Self {
+        self.colours.set(colours.into());
+        self
+    }
+
+    /// Set the inner radius as a ratio of the outer radius. Anything above zero draws a donut.
+    pub fn with_inner_radius(self, inner_radius: f64) -> Self {
+        self.inner_radius.set(inner_radius);
+        self
+    }
+
+    /// Set whether to label slices with their percentage.
+    pub fn with_labels(self, show_labels: bool) -> Self {
+        self.show_labels.set(show_labels);
+        self
+    }
+
+    /// Set the minimum share of the total a slice needs to be labelled.
+    pub fn with_label_threshold(self, threshold: f64) -> Self {
+        self.label_threshold.set(threshold);
+        self
+    }
+}
+
+impl<T> Clone for Pie<T> {
+    fn clone(&self) -> Self {
+        Self {
+            get_name: self.get_name.clone(),
+            get_value: self.get_value.clone(),
+            colours: self.colours,
+            inner_radius: self.inner_radius,
+            show_labels: self.show_labels,
+            label_threshold: self.label_threshold,
+        }
+    }
+}
+
+/// A slice of the pie. Angles are in radians clockwise from 12 o'clock.
+#[derive(Clone, Debug, PartialEq)]
+struct Slice {
+    name: String,
+    value: f64,
+    colour: Colour,
+    start: f64,
+    end: f64,
+    /// Share of the total: 0.0 to 1.0
+    share: f64,
+}
+
+impl Slice {
+    fn mid(&self) -> f64 {
+        (self.start + self.end) / 2.0
+    }
+
+    /// Describes the value and its share of the total e.g., "2 (25%)".
+    fn value_label(&self) -> String {
+        format!(
+            "{} ({})",
+            format_value(self.value),
+            format_percent(self.share)
+        )
+    }
+}
+
+/// Returns the (start, end) angle and share of the total of each value. Values that are not positive take no space.
+fn slice_angles(values: &[f64]) -> Vec<(f64, f64, f64)> {
+    let size = |value: f64| if value > 0.0 { value } else { 0.0 };
+    let total = values.iter().copied().map(size).sum::<f64>();
+    let mut start = 0.0;
+    values
+        .iter()
+        .map(|&value| {
+            let share = if total > 0.0 {
+                size(value) / total
+            } else {
+                0.0
+            };
+            let end = start + share * TAU;
+            let angles = (start, end, share);
+            start = end;
+            angles
+        })
+        .collect()
+}
+
+/// SVG path of a slice between two angles. A zero inner radius draws a wedge to the centre. Arcs are split at their midpoint so that no half exceeds 180 degrees which lets a lone slice draw a full circle.
+fn slice_path(centre: (f64, f64), outer: f64, inner: f64, start: f64, end: f64) -> String {
+    let mid = (start + end) / 2.0;
+    let arc = |radius: f64, to: f64, sweep: u8| {
+        let (mx, my) = polar_to_svg(centre, radius, mid);
+        let (tx, ty) = polar_to_svg(centre, radius, to);
+        format!(
+            "A {radius},{radius} 0 0 {sweep} {mx},{my} A {radius},{radius} 0 0 {sweep} {tx},{ty}"
+        )
+    };
+    let (sx, sy) = polar_to_svg(centre, outer, start);
+    let mut path = format!("M {sx},{sy} {}", arc(outer, end, 1));
+    if inner > 0.0 {
+        let (ex, ey) = polar_to_svg(centre, inner, end);
+        path.push_str(&format!(" L {ex},{ey} {}", arc(inner, start, 0)));
+    } else {
+        path.push_str(&format!(" L {},{}", centre.0, centre.1));
+    }
+    path.push_str(" Z");
+    path
+}
+
+/// Returns the index of the slice under the point relative to the centre. None if outside the ring.
+fn slice_at(angles: &[(f64, f64)], (dx, dy): (f64, f64), outer: f64, inner: f64) -> Option<usize> {
+    let radius = dx.hypot(dy);
+    if radius > outer || radius < inner {
+        return None;
+    }
+    let angle = dx.atan2(-dy).rem_euclid(TAU);
+    angles
+        .iter()
+        .position(|&(start, end)| start < end && start <= angle && angle < end)
+}
+
+/// Formats a share of the total as a whole percentage.
+fn format_percent(share: f64) -> String {
+    format!("{:.0}%", share * 100.0)
+}
+
+/// Renders a pie or donut chart showing each datum's share of the total.
+///
+/// Shares [AspectRatio], [Legend], [Tooltip] styling, [ColourScheme], and [Padding] with [Chart](crate::Chart). Slices are described by [Pie]. Hovering a slice pulls it out from the centre and dims the others. A legend, if given, is placed to the right of the pie with its anchor used to position it vertically. The tooltip shows the hovered slice's value and percentage using the tooltip's placement and cursor distance.
+///
+/// ## Example
+/// ```rust
+/// use leptos::*;
+/// use leptos_chartistry::*;
+///
+/// # struct Share { browser: String, visits: f64 }
+/// # fn load_data() -> Signal<Vec<Share>> { Signal::default() }
+/// # #[component]
+/// # fn PieComponent() -> impl IntoView {
+/// let data: Signal<Vec<Share>> = load_data();
+/// view! {
+///     <PieChart
+///         aspect_ratio=AspectRatio::from_outer_ratio(400.0, 300.0)
+///         pie=Pie::new(|s: &Share| s.browser.clone(), |s: &Share| s.visits)
+///             .with_inner_radius(0.5)
+///         legend=Legend::middle()
+///         tooltip=Tooltip::left_cursor()
+///         data=data
+///     />
+/// }
+/// # }
+/// ```
+#[component]
+pub fn PieChart<T: 'static>(
+    /// Determines the width and height of the chart. Inner ratios size the pie while outer ratios include the legend. See [AspectRatio] for details.
+    #[prop(into)]
+    aspect_ratio: MaybeSignal<AspectRatio>,
+
+    /// The height of the font used in the chart. Default is 16.
+    #[prop(into, optional)]
+    font_height: Option<MaybeSignal<f64>>,
+
+    /// The width of a monospaced character in the font used. Default is 10.
+    #[prop(into, optional)]
+    font_width: Option<MaybeSignal<f64>>,
+
+    /// Debug mode. If enabled shows lines around components. Default is false.
+    #[prop(into, optional)]
+    debug: MaybeSignal<bool>,
+
+    /// Padding around the pie and legend. Default is the font width.
+    #[prop(into, optional)]
+    padding: Option<MaybeSignal<Padding>>,
+
+    /// Legend listing each slice. Placed to the right of the pie. Default is none.
+    #[prop(into, optional)]
+    legend: Option<Legend>,
+
+    /// Tooltip to show on mouse hover. Only the placement and cursor distance are used. Default is hidden.
+    #[prop(into, optional)]
+    tooltip: Tooltip<f64, f64>,
+
+    /// Describes the slices. See [Pie] for details.
+    #[prop(into)]
+    pie: Pie<T>,
+    /// Data to render. Each datum is a slice.
+    #[prop(into)]
+    data: Signal<Vec<T>>,
+) -> impl IntoView {
+    let root = create_node_ref::<Div>();
+    let watch = use_watched_node(root);
+
+    // Aspect ratio signal
+    let have_dimensions = create_memo(move |_| watch.bounds.get().is_some());
+    let width = create_memo(move |_| watch.bounds.get().unwrap_or_default().width());
+    let height = create_memo(move |_| watch.bounds.get().unwrap_or_default().height());
+    let calc = AspectRatio::known_signal(aspect_ratio.clone(), width, height);
+    let env_size = move || {
+        if aspect_ratio.get().is_env() {
+            "100%"
+        } else {
+            "fit-content"
+        }
+    };
+
+    let debug = create_memo(move |_| debug.get());
+    let font_height = create_memo(move |_| font_height.map(|f| f.get()).unwrap_or(FONT_HEIGHT));
+    let font_width = create_memo(move |_| font_width.map(|f| f.get()).unwrap_or(FONT_WIDTH));
+    let padding = create_memo(move |_| {
+        padding
+            .map(|p| p.get())
+            .unwrap_or_else(move || Padding::from(font_width.get()))
+    });
+
+    // Build slices
+    let Pie {
+        get_name,
+        get_value,
+        colours,
+        inner_radius,
+        show_labels,
+        label_threshold,
+    } = pie;
+    let slices = create_memo(move |_| {
+        let colours = colours.get();
+        data.with(|data| {
+            let values = data.iter().map(|t| get_value(t)).collect::<Vec<_>>();
+            (data.iter().zip(values.iter()))
+                .zip(slice_angles(&values))
+                .enumerate()
+                .map(|(index, ((t, &value), (start, end, share)))| Slice {
+                    name: get_name(t),
+                    value,
+                    colour: colours.by_index(index),
+                    start,
+                    end,
+                    share,
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    // Layout: the pie on the left with the legend on the right
+    let entries = create_memo(move |_| {
+        slices.with(|slices| {
+            (slices.iter())
+                .map(|slice| (slice.name.clone(), slice.colour))
+                .collect::<Vec<_>>()
+        })
+    });
+    let legend_width = match legend {
+        Some(_) => Legend::swatch_width(entries, font_width, padding),
+        None => create_memo(|_| 0.0),
+    };
+    let zero = create_memo(|_| 0.0);
+    let inner_width = KnownAspectRatio::inner_width_signal(calc, zero, legend_width);
+    let inner_height = KnownAspectRatio::inner_height_signal(calc, zero, zero);
+    let outer = create_memo(move |_| {
+        Bounds::new(inner_width.get() + legend_width.get(), inner_height.get())
+    });
+    let pie_bounds = create_memo(move |_| {
+        padding
+            .get()
+            .apply(Bounds::new(inner_width.get(), inner_height.get()))
+    });
+    let legend_bounds = create_memo(move |_| {
+        let outer = outer.get();
+        Bounds::from_points(inner_width.get(), 0.0, outer.right_x(), outer.bottom_y())
+    });
+
+    // Pie geometry: (centre, outer radius, inner radius)
+    let geometry = create_memo(move |_| {
+        let bounds = pie_bounds.get();
+        let centre = (bounds.centre_x(), bounds.centre_y());
+        // Leave room for the hovered slice to move out
+        let outer = (bounds.width().min(bounds.height()) / 2.0 - PIE_HOVER_OFFSET).max(0.0);
+        let inner = outer * inner_radius.get().clamp(0.0, 0.95);
+        (centre, outer, inner)
+    });
+
+    // Hover emphasis
+    let hovered = create_memo(move |_| {
+        if !watch.mouse_chart_hover.get() {
+            return None;
+        }
+        let ((cx, cy), outer, inner) = geometry.get();
+        let (x, y) = watch.mouse_chart.get();
+        slices.with(|slices| {
+            let angles = (slices.iter())
+                .map(|slice| (slice.start, slice.end))
+                .collect::<Vec<_>>();
+            slice_at(&angles, (x - cx, y - cy), outer, inner)
+        })
+    });
+
+    let render_slices = move || {
+        let (centre, outer, inner) = geometry.get();
+        let hovered = hovered.get();
+        slices.with(|slices| {
+            (slices.iter().enumerate())
+                .filter(|(_, slice)| slice.start < slice.end)
+                .map(|(index, slice)| {
+                    let d = slice_path(centre, outer, inner, slice.start, slice.end);
+                    // Pull the hovered slice out and dim the others
+                    let (transform, opacity) = match hovered {
+                        Some(hovered) if hovered == index => {
+                            let (dx, dy) = polar_to_svg((0.0, 0.0), PIE_HOVER_OFFSET, slice.mid());
+                            (format!("translate({dx}, {dy})"), 1.0)
+                        }
+                        Some(_) => (String::new(), PIE_DIM_OPACITY),
+                        None => (String::new(), 1.0),
+                    };
+                    let title = format!("{}: {}", slice.name, slice.value_label());
+                    view! {
+                        <path d=d fill=slice.colour transform=transform fill-opacity=opacity>
+                            <title>{title}</title>
+                        </path>
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    let render_labels = move || {
+        if !show_labels.get() {
+            return None;
+        }
+        let (centre, outer, inner) = geometry.get();
+        let threshold = label_threshold.get();
+        // Centre of the ring or two thirds out on a pie
+        let radius = if inner > 0.0 {
+            (outer + inner) / 2.0
+        } else {
+            outer * 2.0 / 3.0
+        };
+        let hovered = hovered.get();
+        let labels = slices.with(|slices| {
+            (slices.iter().enumerate())
+                .filter(|(_, slice)| slice.share > 0.0 && slice.share >= threshold)
+                .map(|(index, slice)| {
+                    let offset = if hovered == Some(index) {
+                        PIE_HOVER_OFFSET
+                    } else {
+                        0.0
+                    };
+                    let (x, y) = polar_to_svg(centre, radius + offset, slice.mid());
+                    view! {
+                        <text x=x y=y text-anchor="middle" dominant-baseline="middle">
+                            {format_percent(slice.share)}
+                        </text>
+                    }
+                })
+                .collect_view()
+        });
+        Some(view! {
+            <g
+                class="_chartistry_pie_labels"
+                fill="#FFFFFF"
+                stroke="none"
+                font-family="monospace"
+                font-size=move || format!("{}px", font_height.get())
+                pointer-events="none">
+                {labels}
+            </g>
+        })
+    };
+
+    let legend = legend.map(|legend| {
+        view! {
+            <SwatchLegend
+                legend=legend
+                entries=entries
+                bounds=legend_bounds
+                debug=debug
+                font_height=font_height
+                font_width=font_width
+                padding=padding />
+        }
+    });
+
+    // Tooltip shares the chart tooltip's styling
+    let placement = tooltip.placement;
+    let cursor_distance = tooltip.cursor_distance;
+    let tooltip = move || {
+        if placement.get() == TooltipPlacement::Hide {
+            return None;
+        }
+        let slice = slices.with(|slices| slices.get(hovered.get()?).cloned())?;
+        Some(view! {
+            <aside
+                class="_chartistry_tooltip"
+                style="position: absolute; z-index: 1; width: max-content; height: max-content; transform: translateY(-50%); background-color: #fff; white-space: pre; font-family: monospace;"
+                style:border=format!("1px solid {}", AXIS_MARKER_COLOUR)
+                style:top=move || format!("calc({}px)", watch.mouse_page.get().1)
+                style:right=move || format!("calc(100% - {}px + {}px)", watch.mouse_page.get().0, cursor_distance.get())
+                style:padding=move || padding.get().to_css_style()
+                style:font-size=move || format!("{}px", font_height.get())>
+                <Swatch colour=slice.colour font_height=font_height font_width=font_width />
+                {format!("{}  {}", slice.name, slice.value_label())}
+            </aside>
+        })
+    };
+
+    view! {
+        <div
+            node_ref=root
+            class="_chartistry _chartistry_pie"
+            style:width=env_size.clone()
+            style:height=env_size
+            style:touch-action="pan-y pinch-zoom"
+            style="overflow: visible;">
+            <DebugRect label="PieChart" debug=debug />
+            <Show when=move || have_dimensions.get() fallback=|| view!(<p>"Loading..."</p>)>
+                <svg
+                    width=move || format!("{}px", outer.get().width())
+                    height=move || format!("{}px", outer.get().height())
+                    viewBox=move || with!(|outer| format!("0 0 {} {}", outer.width(), outer.height()))
+                    role="img"
+                    style="display: block; overflow: visible;">
+                    <DebugRect label="Pie" debug=debug bounds=vec![outer.into(), pie_bounds.into()] />
+                    <g class="_chartistry_pie_slices" stroke="#FFFFFF">
+                        {render_slices}
+                    </g>
+                    {render_labels}
+                    {legend.clone()}
+                </svg>
+                {tooltip}
+            </Show>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slice_angles() {
+        let angles = slice_angles(&[1.0, 3.0, -2.0, f64::NAN]);
+        assert_eq!(angles[0], (0.0, TAU / 4.0, 0.25));
+        assert_eq!(angles[1], (TAU / 4.0, TAU, 0.75));
+        // Negative and missing values take no space
+        assert_eq!(angles[2], (TAU, TAU, 0.0));
+        assert_eq!(angles[3], (TAU, TAU, 0.0));
+        assert!(slice_angles(&[0.0]).iter().all(|&(s, e, _)| s == e));
+    }
+
+    #[test]
+    fn test_slice_at() {
+        let angles = [(0.0, TAU / 4.0), (TAU / 4.0, TAU)];
+        // Right of centre is 3 o'clock: the second slice
+        assert_eq!(slice_at(&angles, (5.0, 0.0), 10.0, 0.0), Some(1));
+        // Up and right is the first
+        assert_eq!(slice_at(&angles, (3.0, -3.0), 10.0, 0.0), Some(0));
+        // Outside the pie or inside the donut's hole
+        assert_eq!(slice_at(&angles, (11.0, 0.0), 10.0, 0.0), None);
+        assert_eq!(slice_at(&angles, (3.0, -3.0), 10.0, 5.0), None);
+    }
+
+    #[test]
+    fn test_format_percent() {
+        assert_eq!(format_percent(0.25), "25%");
+        assert_eq!(format_percent(1.0 / 3.0), "33%");
+    }
+
+    #[test]
+    fn test_value_label() {
+        let slice = Slice {
+            name: "apples".to_string(),
+            value: 2.001,
+            colour: Colour::from_rgb(0, 0, 0),
+            start: 0.0,
+            end: TAU / 4.0,
+            share: 0.25,
+        };
+        assert_eq!(slice.value_label(), "2 (25%)");
+    }
+}
@@ -1,4 +1,5 @@
 use crate::bounds::Bounds;
+use std::f64::consts::TAU;
 
 /// A projection converts between data and SVG coordinates. SVG has zero in the top left corner. Data coordinates have zero in the bottom left.
 #[derive(Clone, Debug, PartialEq)]
@@ -38,6 +39,11 @@ impl Projection {
         (x, y)
     }
 
+    /// Returns the SVG distance of one data position along the X and Y axes. Y is negative as SVG runs top to bottom.
+    pub fn scale(&self) -> (f64, f64) {
+        (self.x_mult, -self.y_mult)
+    }
+
     /// Converts an SVG point to data coordinates. View coordinates are in SVG space with zero at top left. Data coordinates are in chart space with zero at bottom left.
     pub fn svg_to_position(&self, x: f64, y: f64) -> (f64, f64) {
         let x = self.left_x + (x - self.bounds.left_x()) / self.x_mult;
@@ -46,6 +52,52 @@ impl Projection {
     }
 }
 
+/// A polar projection converts data values on evenly spaced radial axes to SVG coordinates. The first axis points up (12 o'clock) with the rest following clockwise. The minimum value sits at the centre and the maximum at the radius.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolarProjection {
+    centre: (f64, f64),
+    radius: f64,
+    axes: usize,
+    min: f64,
+    mult: f64,
+}
+
+impl PolarProjection {
+    pub fn new(centre: (f64, f64), radius: f64, axes: usize, (min, max): (f64, f64)) -> Self {
+        // If the range is zero, skip projection
+        let range = max - min;
+        let mult = radius / if range == 0.0 { 0.5 } else { range };
+        PolarProjection {
+            centre,
+            radius,
+            axes,
+            min,
+            mult,
+        }
+    }
+
+    /// Angle of an axis in radians clockwise from 12 o'clock.
+    pub fn axis_angle(&self, axis: usize) -> f64 {
+        TAU * axis as f64 / self.axes.max(1) as f64
+    }
+
+    /// Converts a value on an axis to SVG view coordinates. Values below the minimum are drawn at the centre.
+    pub fn position_to_svg(&self, axis: usize, value: f64) -> (f64, f64) {
+        let radius = ((value - self.min) * self.mult).max(0.0);
+        polar_to_svg(self.centre, radius, self.axis_angle(axis))
+    }
+
+    /// Converts a distance from the centre on an axis to SVG view coordinates e.g., for labels beyond the radius.
+    pub fn offset_to_svg(&self, axis: usize, offset: f64) -> (f64, f64) {
+        polar_to_svg(self.centre, self.radius + offset, self.axis_angle(axis))
+    }
+}
+
+/// Converts a point at `angle` (radians clockwise from 12 o'clock) and `radius` from the centre to SVG view coordinates.
+pub fn polar_to_svg((cx, cy): (f64, f64), radius: f64, angle: f64) -> (f64, f64) {
+    (cx + radius * angle.sin(), cy - radius * angle.cos())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -96,4 +148,19 @@ mod tests {
         );
         assert_eq!(p, p.clone());
     }
+
+    #[test]
+    fn test_polar_projection() {
+        let round = |(x, y): (f64, f64)| ((x * 1e9).round() / 1e9, (y * 1e9).round() / 1e9);
+        let p = PolarProjection::new((50.0, 50.0), 40.0, 4, (0.0, 100.0));
+        // Min at the centre, max at the radius. Axes run clockwise from the top
+        assert_eq!(round(p.position_to_svg(0, 0.0)), (50.0, 50.0));
+        assert_eq!(round(p.position_to_svg(0, 100.0)), (50.0, 10.0));
+        assert_eq!(round(p.position_to_svg(1, 50.0)), (70.0, 50.0));
+        assert_eq!(round(p.position_to_svg(2, 100.0)), (50.0, 90.0));
+        assert_eq!(round(p.position_to_svg(3, 100.0)), (10.0, 50.0));
+        // Below the minimum clamps to the centre
+        assert_eq!(round(p.position_to_svg(1, -10.0)), (50.0, 50.0));
+        assert_eq!(round(p.offset_to_svg(1, 5.0)), (95.0, 50.0));
+    }
 }
@@ -0,0 +1,500 @@
+use crate::{
+    aspect_ratio::KnownAspectRatio,
+    bounds::Bounds,
+    chart::{FONT_HEIGHT, FONT_WIDTH},
+    colours::ColourScheme,
+    debug::DebugRect,
+    layout::legend::SwatchLegend,
+    projection::PolarProjection,
+    series::SERIES_COLOUR_SCHEME,
+    ticks::{GeneratedTicks, TickGen, VerticalSpan},
+    use_watched_node::use_watched_node,
+    AlignedFloats, AspectRatio, Legend, Padding, GRID_LINE_COLOUR,
+};
+use leptos::{html::Div, *};
+use std::rc::Rc;
+
+/// Default opacity of the area inside each radar polygon.
+pub const RADAR_FILL_OPACITY: f64 = 0.2;
+
+type GetDimension<T> = Rc<dyn Fn(&T) -> f64>;
+
+/// Describes the axes and polygons of a [RadarChart].
+///
+/// Each dimension is a radial axis added with [dimension](Self::dimension). Each datum (`T`) is drawn as a polygon joining its value on every axis. Like [Series](crate::Series), polygons are coloured by the colour scheme in data order and named in the legend by `get_name`.
+///
+/// All axes share the same range. By default this runs from zero (or the smallest value if negative) to the largest value. Missing values (`NaN`) are drawn at the centre.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Team { name: String, speed: f64, quality: f64, cost: f64 }
+/// let radar = Radar::new(|t: &Team| t.name.clone())
+///     .dimension("Speed", |t: &Team| t.speed)
+///     .dimension("Quality", |t: &Team| t.quality)
+///     .dimension("Cost", |t: &Team| t.cost)
+///     .with_range(0.0, 10.0);
+/// ```
+#[non_exhaustive]
+pub struct Radar<T> {
+    get_name: Rc<dyn Fn(&T) -> String>,
+    dimensions: Vec<(String, GetDimension<T>)>,
+    /// Colour scheme of the polygons. If there are more polygons than colours, the colours will repeat. Default is [SERIES_COLOUR_SCHEME].
+    pub colours: RwSignal<ColourScheme>,
+    /// Optional minimum value shared by all axes. Drawn at the centre.
+    pub min: RwSignal<Option<f64>>,
+    /// Optional maximum value shared by all axes. Drawn at the outer ring.
+    pub max: RwSignal<Option<f64>>,
+    /// Opacity of the area inside each polygon. Default is [RADAR_FILL_OPACITY].
+    pub fill_opacity: RwSignal<f64>,
+    /// Width of the polygon outlines.
+    pub width: RwSignal<f64>,
+}
+
+impl<T> Radar<T> {
+    /// Create a new radar with no axes. `get_name` returns the name of a datum's polygon (used in the legend).
+    pub fn new(get_name: impl Fn(&T) -> String + 'static) -> Self {
+        Self {
+            get_name: Rc::new(get_name),
+            dimensions: Vec::new(),
+            colours: create_rw_signal(SERIES_COLOUR_SCHEME.into()),
+            min: RwSignal::default(),
+            max: RwSignal::default(),
+            fill_opacity: create_rw_signal(RADAR_FILL_OPACITY),
+            width: create_rw_signal(2.0),
+        }
+    }
+
+    /// Adds a radial axis. Axes are placed clockwise from 12 o'clock in the order added.
+    pub fn dimension(
+        mut self,
+        name: impl Into<String>,
+        get_value: impl Fn(&T) -> f64 + 'static,
+    ) -> Self {
+        self.dimensions.push((name.into(), Rc::new(get_value)));
+        self
+    }
+
+    /// Set the colour scheme of the polygons.
+    pub fn with_colours(self, colours: impl Into<ColourScheme>) -> Self {
+        self.colours.set(colours.into());
+        self
+    }
+
+    /// Set the minimum value of the axes. If not set, defaults to zero or the smallest value if negative.
+    pub fn with_min(self, min: impl Into<Option<f64>>) -> Self {
+        self.min.set(min.into());
+        self
+    }
+
+    /// Set the maximum value of the axes. If not set, defaults to the largest value.
+    pub fn with_max(self, max: impl Into<Option<f64>>) -> Self {
+        self.max.set(max.into());
+        self
+    }
+
+    /// Set the range of the axes. Equivalent to calling [with_min](Self::with_min) and [with_max](Self::with_max).
+    pub fn with_range(self, min: impl Into<Option<f64>>, max: impl Into<Option<f64>>) -> Self {
+        self.with_min(min).with_max(max)
+    }
+
+    /// Set the opacity of the area inside each polygon.
+    pub fn with_fill_opacity(self, opacity: f64) -> Self {
+        self.fill_opacity.set(opacity);
+        self
+    }
+}
+
+impl<T> Clone for Radar<T> {
+    fn clone(&self) -> Self {
+        Self {
+            get_name: self.get_name.clone(),
+            dimensions: self.dimensions.clone(),
+            colours: self.colours,
+            min: self.min,
+            max: self.max,
+            fill_opacity: self.fill_opacity,
+            width: self.width,
+        }
+    }
+}
+
+/// Range shared by all axes. Defaults to zero (or the smallest value if negative) to the largest value.
+fn value_range(values: &[f64], min: Option<f64>, max: Option<f64>) -> (f64, f64) {
+    let values = values.iter().copied().filter(|value| !value.is_nan());
+    let (low, high) = values.fold((0.0_f64, f64::NEG_INFINITY), |(low, high), value| {
+        (low.min(value), high.max(value))
+    });
+    let min = min.unwrap_or(low);
+    let max = max.unwrap_or(if high.is_finite() { high } else { min });
+    (min, max)
+}
+
+/// Text anchor and baseline of an axis label placed beyond the outer ring at `angle`. Labels sit outside of the chart: to the left of left-hand axes, above the top axis, etc.
+fn label_alignment(angle: f64) -> (&'static str, &'static str) {
+    const CENTRED: f64 = 0.1;
+    let (sin, cos) = angle.sin_cos();
+    let anchor = if sin > CENTRED {
+        "start"
+    } else if sin < -CENTRED {
+        "end"
+    } else {
+        "middle"
+    };
+    let baseline = if cos > CENTRED {
+        "auto"
+    } else if cos < -CENTRED {
+        "hanging"
+    } else {
+        "middle"
+    };
+    (anchor, baseline)
+}
+
+/// Renders a radar (spider) chart comparing several numeric dimensions per datum on radial axes.
+///
+/// Shares [AspectRatio], [Legend], [ColourScheme], and [Padding] with [Chart](crate::Chart). Axes and polygons are described by [Radar]. Concentric grid rings are placed on values generated by [AlignedFloats] and labelled along the first axis. A legend, if given, is placed to the right of the radar with its anchor used to position it vertically.
+///
+/// ## Example
+/// ```rust
+/// use leptos::*;
+/// use leptos_chartistry::*;
+///
+/// # struct Team { name: String, speed: f64, quality: f64, cost: f64 }
+/// # fn load_data() -> Signal<Vec<Team>> { Signal::default() }
+/// # #[component]
+/// # fn RadarComponent() -> impl IntoView {
+/// let data: Signal<Vec<Team>> = load_data();
+/// view! {
+///     <RadarChart
+///         aspect_ratio=AspectRatio::from_outer_ratio(500.0, 300.0)
+///         radar=Radar::new(|t: &Team| t.name.clone())
+///             .dimension("Speed", |t: &Team| t.speed)
+///             .dimension("Quality", |t: &Team| t.quality)
+///             .dimension("Cost", |t: &Team| t.cost)
+///         legend=Legend::middle()
+///         data=data
+///     />
+/// }
+/// # }
+/// ```
+#[component]
+pub fn RadarChart<T: 'static>(
+    /// Determines the width and height of the chart. Inner ratios size the radar while outer ratios include the legend. See [AspectRatio] for details.
+    #[prop(into)]
+    aspect_ratio: MaybeSignal<AspectRatio>,
+
+    /// The height of the font used in the chart. Default is 16.
+    #[prop(into, optional)]
+    font_height: Option<MaybeSignal<f64>>,
+
+    /// The width of a monospaced character in the font used. Default is 10.
+    #[prop(into, optional)]
+    font_width: Option<MaybeSignal<f64>>,
+
+    /// Debug mode. If enabled shows lines around components. Default is false.
+    #[prop(into, optional)]
+    debug: MaybeSignal<bool>,
+
+    /// Padding around the radar and legend. Default is the font width.
+    #[prop(into, optional)]
+    padding: Option<MaybeSignal<Padding>>,
+
+    /// Legend listing each polygon. Placed to the right of the radar. Default is none.
+    #[prop(into, optional)]
+    legend: Option<Legend>,
+
+    /// Describes the axes and polygons. See [Radar] for details.
+    #[prop(into)]
+    radar: Radar<T>,
+    /// Data to render. Each datum is a polygon.
+    #[prop(into)]
+    data: Signal<Vec<T>>,
+) -> impl IntoView {
+    let root = create_node_ref::<Div>();
+    let watch = use_watched_node(root);
+
+    // Aspect ratio signal
+    let have_dimensions = create_memo(move |_| watch.bounds.get().is_some());
+    let width = create_memo(move |_| watch.bounds.get().unwrap_or_default().width());
+    let height = create_memo(move |_| watch.bounds.get().unwrap_or_default().height());
+    let calc = AspectRatio::known_signal(aspect_ratio.clone(), width, height);
+    let env_size = move || {
+        if aspect_ratio.get().is_env() {
+            "100%"
+        } else {
+            "fit-content"
+        }
+    };
+
+    let debug = create_memo(move |_| debug.get());
+    let font_height = create_memo(move |_| font_height.map(|f| f.get()).unwrap_or(FONT_HEIGHT));
+    let font_width = create_memo(move |_| font_width.map(|f| f.get()).unwrap_or(FONT_WIDTH));
+    let padding = create_memo(move |_| {
+        padding
+            .map(|p| p.get())
+            .unwrap_or_else(move || Padding::from(font_width.get()))
+    });
+
+    // Build polygons: (name, colour, value per axis)
+    let Radar {
+        get_name,
+        dimensions,
+        colours,
+        min,
+        max,
+        fill_opacity,
+        width: stroke_width,
+    } = radar;
+    let axis_names = dimensions
+        .iter()
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    let polygons = create_memo(move |_| {
+        let colours = colours.get();
+        data.with(|data| {
+            (data.iter().enumerate())
+                .map(|(index, t)| {
+                    let values = (dimensions.iter())
+                        .map(|(_, get_value)| get_value(t))
+                        .collect::<Vec<_>>();
+                    (get_name(t), colours.by_index(index), values)
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+    let range = create_memo(move |_| {
+        let values = polygons.with(|polygons| {
+            (polygons.iter())
+                .flat_map(|(_, _, values)| values.iter().copied())
+                .collect::<Vec<_>>()
+        });
+        value_range(&values, min.get(), max.get())
+    });
+
+    // Layout: the radar on the left with the legend on the right
+    let entries = create_memo(move |_| {
+        polygons.with(|polygons| {
+            (polygons.iter())
+                .map(|(name, colour, _)| (name.clone(), *colour))
+                .collect::<Vec<_>>()
+        })
+    });
+    let legend_width = match legend {
+        Some(_) => Legend::swatch_width(entries, font_width, padding),
+        None => create_memo(|_| 0.0),
+    };
+    let zero = create_memo(|_| 0.0);
+    let inner_width = KnownAspectRatio::inner_width_signal(calc, zero, legend_width);
+    let inner_height = KnownAspectRatio::inner_height_signal(calc, zero, zero);
+    let outer = create_memo(move |_| {
+        Bounds::new(inner_width.get() + legend_width.get(), inner_height.get())
+    });
+    let radar_bounds = create_memo(move |_| {
+        padding
+            .get()
+            .apply(Bounds::new(inner_width.get(), inner_height.get()))
+    });
+    let legend_bounds = create_memo(move |_| {
+        let outer = outer.get();
+        Bounds::from_points(inner_width.get(), 0.0, outer.right_x(), outer.bottom_y())
+    });
+
+    // Leave room around the outer ring for axis labels
+    let axes = axis_names.len();
+    let label_chars = (axis_names.iter())
+        .map(|name| name.chars().count())
+        .max()
+        .unwrap_or_default() as f64;
+    let radius = create_memo(move |_| {
+        let bounds = radar_bounds.get();
+        let (font_height, font_width) = (font_height.get(), font_width.get());
+        let horizontal = bounds.width() / 2.0 - (label_chars + 1.0) * font_width;
+        let vertical = bounds.height() / 2.0 - font_height * 1.5;
+        horizontal.min(vertical).max(0.0)
+    });
+    let projection = create_memo(move |_| {
+        let bounds = radar_bounds.get();
+        let centre = (bounds.centre_x(), bounds.centre_y());
+        PolarProjection::new(centre, radius.get(), axes, range.get())
+    });
+
+    // Grid rings
+    let rings = create_memo(move |_| {
+        let (min, max) = range.get();
+        if min >= max {
+            return GeneratedTicks::none();
+        }
+        // Space rings apart by two lines of text
+        let span = VerticalSpan::new(font_height.get() * 2.0, radius.get());
+        AlignedFloats::default().generate(&min, &max, &span)
+    });
+    let ring_points = move |value: f64| {
+        projection.with(|proj| {
+            (0..axes)
+                .map(|axis| {
+                    let (x, y) = proj.position_to_svg(axis, value);
+                    format!("{x},{y}")
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+    };
+    let render_grid = move || {
+        let min = range.get().0;
+        let rings = rings.get();
+        let spokes = (0..axes)
+            .map(|axis| {
+                let ((x1, y1), (x2, y2)) = projection.with(|proj| {
+                    (
+                        proj.position_to_svg(axis, min),
+                        proj.offset_to_svg(axis, 0.0),
+                    )
+                });
+                view!(<line x1=x1 y1=y1 x2=x2 y2=y2 />)
+            })
+            .collect_view();
+        let ring_views = (rings.ticks.iter())
+            .filter(|&&value| value > min)
+            .map(|&value| view!(<polygon points=ring_points(value) fill="none" />))
+            .collect_view();
+        // Ring values along the first axis
+        let labels = (rings.ticks.iter())
+            .map(|value| {
+                let (x, y) = projection.with(|proj| proj.position_to_svg(0, *value));
+                view! {
+                    <text x=x + font_width.get() / 2.0 y=y dominant-baseline="middle">
+                        {rings.state.format(value)}
+                    </text>
+                }
+            })
+            .collect_view();
+        view! {
+            <g class="_chartistry_radar_grid" stroke=GRID_LINE_COLOUR>
+                {spokes}
+                {ring_views}
+            </g>
+            <g class="_chartistry_radar_ring_labels" stroke="none" fill=GRID_LINE_COLOUR>
+                {labels}
+            </g>
+        }
+    };
+
+    let render_axis_labels = move || {
+        let offset = font_width.get() / 2.0;
+        (axis_names.iter().enumerate())
+            .map(|(axis, name)| {
+                let (x, y, angle) = projection.with(|proj| {
+                    let (x, y) = proj.offset_to_svg(axis, offset);
+                    (x, y, proj.axis_angle(axis))
+                });
+                let (anchor, baseline) = label_alignment(angle);
+                view! {
+                    <text x=x y=y text-anchor=anchor dominant-baseline=baseline>
+                        {name.clone()}
+                    </text>
+                }
+            })
+            .collect_view()
+    };
+
+    let render_polygons = move || {
+        polygons.with(|polygons| {
+            (polygons.iter())
+                .map(|(name, colour, values)| {
+                    let points = projection.with(|proj| {
+                        (values.iter().enumerate())
+                            .map(|(axis, &value)| {
+                                let value = if value.is_nan() {
+                                    f64::NEG_INFINITY
+                                } else {
+                                    value
+                                };
+                                let (x, y) = proj.position_to_svg(axis, value);
+                                format!("{x},{y}")
+                            })
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                    });
+                    view! {
+                        <polygon points=points fill=*colour stroke=*colour>
+                            <title>{name.clone()}</title>
+                        </polygon>
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    let legend = legend.map(|legend| {
+        view! {
+            <SwatchLegend
+                legend=legend
+                entries=entries
+                bounds=legend_bounds
+                debug=debug
+                font_height=font_height
+                font_width=font_width
+                padding=padding />
+        }
+    });
+
+    view! {
+        <div
+            node_ref=root
+            class="_chartistry _chartistry_radar"
+            style:width=env_size.clone()
+            style:height=env_size
+            style="overflow: visible;">
+            <DebugRect label="RadarChart" debug=debug />
+            <Show when=move || have_dimensions.get() fallback=|| view!(<p>"Loading..."</p>)>
+                <svg
+                    width=move || format!("{}px", outer.get().width())
+                    height=move || format!("{}px", outer.get().height())
+                    viewBox=move || with!(|outer| format!("0 0 {} {}", outer.width(), outer.height()))
+                    role="img"
+                    style="display: block; overflow: visible;">
+                    <DebugRect label="Radar" debug=debug bounds=vec![outer.into(), radar_bounds.into()] />
+                    <g
+                        font-family="monospace"
+                        font-size=move || format!("{}px", font_height.get())>
+                        {render_grid}
+                        <g class="_chartistry_radar_axis_labels">
+                            {render_axis_labels.clone()}
+                        </g>
+                    </g>
+                    <g
+                        class="_chartistry_radar_polygons"
+                        fill-opacity=fill_opacity
+                        stroke-width=stroke_width>
+                        {render_polygons}
+                    </g>
+                    {legend.clone()}
+                </svg>
+            </Show>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_range() {
+        assert_eq!(value_range(&[3.0, 7.0, f64::NAN], None, None), (0.0, 7.0));
+        // Negative values extend below zero
+        assert_eq!(value_range(&[-2.0, 5.0], None, None), (-2.0, 5.0));
+        assert_eq!(value_range(&[3.0, 7.0], Some(1.0), Some(10.0)), (1.0, 10.0));
+        assert_eq!(value_range(&[], None, None), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_label_alignment() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+        assert_eq!(label_alignment(0.0), ("middle", "auto"));
+        assert_eq!(label_alignment(FRAC_PI_2), ("start", "middle"));
+        assert_eq!(label_alignment(PI), ("middle", "hanging"));
+        assert_eq!(label_alignment(PI + FRAC_PI_2), ("end", "middle"));
+    }
+}
@@ -1,4 +1,4 @@
-use super::{ApplyUseSeries, GetYValue, IntoUseBar, SeriesAcc, UseY};
+use super::{ApplyUseSeries, DataLabelPlacement, GetYValue, IntoUseBar, SeriesAcc, UseY};
 use crate::{state::State, Colour, Tick};
 use leptos::*;
 use std::rc::Rc;
@@ -37,6 +37,8 @@ pub struct Bar<T, Y> {
     ///
     /// The group gap is the ratio of the available width for a single bar in a group of bars (for a single X value). Carrying on the example from [gap](Self::gap) a group gap of 0.05 (5%) and two bars would result in 1px (40 / 2 * 0.05). This group gap becomes the space inbetween each bar.
     pub group_gap: RwSignal<f64>,
+    /// Placement of data labels showing the Y value of each bar. Default is none.
+    pub data_labels: RwSignal<DataLabelPlacement>,
 }
 
 /// The location of where the bar extends from.
@@ -53,10 +55,11 @@ pub enum BarPlacement {
 #[derive(Clone, Debug, PartialEq)]
 pub struct UseBar {
     group_id: usize,
-    colour: Signal<Colour>,
+    pub(super) colour: Signal<Colour>,
     placement: RwSignal<BarPlacement>,
     gap: RwSignal<f64>,
     group_gap: RwSignal<f64>,
+    pub(super) data_labels: RwSignal<DataLabelPlacement>,
 }
 
 impl<T, Y> Bar<T, Y> {
@@ -74,6 +77,7 @@ impl<T, Y> Bar<T, Y> {
             placement: RwSignal::default(),
             gap: create_rw_signal(BAR_GAP),
             group_gap: create_rw_signal(BAR_GAP_INNER),
+            data_labels: RwSignal::default(),
         }
     }
 
@@ -106,6 +110,12 @@ impl<T, Y> Bar<T, Y> {
         self.group_gap.set(group_gap);
         self
     }
+
+    /// Set the placement of data labels showing the Y value of each bar.
+    pub fn with_data_labels(self, placement: impl Into<DataLabelPlacement>) -> Self {
+        self.data_labels.set(placement.into());
+        self
+    }
 }
 
 impl<T, Y> Clone for Bar<T, Y> {
@@ -117,6 +127,7 @@ impl<T, Y> Clone for Bar<T, Y> {
             group_gap: self.group_gap,
             name: self.name,
             colour: self.colour,
+            data_labels: self.data_labels,
         }
     }
 }
@@ -152,45 +163,67 @@ impl<T, Y> IntoUseBar<T, Y> for Bar<T, Y> {
                 placement: self.placement,
                 gap: self.gap,
                 group_gap: self.group_gap,
+                data_labels: self.data_labels,
             },
         );
         (bar, self.get_y.clone())
     }
 }
 
-#[component]
-pub fn RenderBar<X: 'static, Y: 'static>(
-    bar: UseBar,
-    state: State<X, Y>,
+impl UseBar {
+    /// Each bar's (left X, end Y, width, base Y) in SVG coordinates. The end Y is the data value. Shared by the bars and their data labels.
+    pub(super) fn rects<X, Y>(
+        &self,
+        state: &State<X, Y>,
+        positions: Signal<Vec<(f64, f64)>>,
+    ) -> Signal<Vec<(f64, f64, f64, f64)>> {
+        let placement = self.placement;
+        let svg_zero = state.svg_zero;
+        let inner = state.layout.inner;
+        let rects = group_rects(state, self.group_id, self.gap, self.group_gap, positions);
+        let rects = create_memo(move |_| {
+            // Find the bottom Y position of each bar
+            let bottom_y = match placement.get() {
+                BarPlacement::Zero => svg_zero.get().1,
+                BarPlacement::Edge => inner.get().bottom_y(),
+            };
+            rects.with(|rects| {
+                rects
+                    .iter()
+                    .map(|&(left, y, width)| (left, y, width, bottom_y))
+                    .collect()
+            })
+        });
+        rects.into()
+    }
+}
+
+/// Each (left X, Y, width) in SVG coordinates of a member of a group of bars e.g., bars or box plots side by side.
+pub(super) fn group_rects<X, Y>(
+    state: &State<X, Y>,
+    group_id: usize,
+    gap: RwSignal<f64>,
+    group_gap: RwSignal<f64>,
     positions: Signal<Vec<(f64, f64)>>,
-) -> impl IntoView {
-    let bars = create_memo(move |_| {
-        state
-            .pre
-            .data
-            .series
+) -> Signal<Vec<(f64, f64, f64)>> {
+    let series = state.pre.data.series;
+    let groups = create_memo(move |_| {
+        series
             .get()
             .iter()
-            .filter_map(|series| series.bar().map(|bar| (series.clone(), bar.clone())))
-            .collect::<Vec<_>>()
-            .len()
+            .filter(|series| series.is_grouped())
+            .count()
     });
-
-    let rects = move || {
+    let x_width = state.layout.x_width;
+    Signal::derive(move || {
         positions.with(|positions| {
-            // Find the bottom Y position of each bar
-            let bottom_y = match bar.placement.get() {
-                BarPlacement::Zero => state.svg_zero.get().1,
-                BarPlacement::Edge => state.layout.inner.get().bottom_y(),
-            };
-
             // Find width of each X position
             // Note: this should possibly be on Layout
-            let gap = bar.gap.get().clamp(0.0, 1.0);
-            let width = state.layout.x_width.get() * (1.0 - gap);
+            let gap = gap.get().clamp(0.0, 1.0);
+            let width = x_width.get() * (1.0 - gap);
             // Find width of each group in an X position
-            let group_gap = bar.group_gap.get().clamp(0.0, 1.0);
-            let group_width = width / bars.get() as f64;
+            let group_gap = group_gap.get().clamp(0.0, 1.0);
+            let group_width = width / groups.get() as f64;
             let group_width_inner = group_width * (1.0 - group_gap);
             let group_gap = group_width * group_gap;
 
@@ -198,11 +231,26 @@ pub fn RenderBar<X: 'static, Y: 'static>(
             positions
                 .iter()
                 .map(|&(x, y)| {
+                    let left = x + group_width * group_id as f64 + offset;
+                    (left, y, group_width_inner)
+                })
+                .collect::<Vec<_>>()
+        })
+    })
+}
+
+#[component]
+pub fn RenderBar(bar: UseBar, rects: Signal<Vec<(f64, f64, f64, f64)>>) -> impl IntoView {
+    let rects = move || {
+        rects.with(|rects| {
+            rects
+                .iter()
+                .map(|&(x, y, width, bottom_y)| {
                     view! {
                         <rect
-                            x=x + group_width * bar.group_id as f64 + offset
+                            x=x
                             y=y
-                            width=group_width_inner
+                            width=width
                             height=bottom_y - y />
                     }
                 })
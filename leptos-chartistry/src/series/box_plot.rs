@@ -0,0 +1,407 @@
+use super::{
+    line::MarkerShape, ApplyUseSeries, Extra, Extras, GetYValue, IntoUseBar, SeriesAcc, UseY,
+};
+use crate::{state::State, Colour, Tick};
+use leptos::*;
+use std::rc::Rc;
+
+/// Default fill opacity of a box plot's box.
+pub const BOX_PLOT_OPACITY: f64 = 0.3;
+
+// Whiskers extend to the furthest sample within this many IQRs of the box
+const WHISKER_IQR: f64 = 1.5;
+// Whisker cap width relative to the box width
+const CAP_RATIO: f64 = 0.5;
+// Scales outlier markers (drawn -1 to 1) to a 1.0 line width
+const WIDTH_TO_MARKER: f64 = 6.0;
+
+/// Summary of a distribution drawn by a [BoxPlot].
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoxSummary<Y> {
+    /// End of the lower whisker.
+    pub lower: Y,
+    /// First quartile: bottom of the box.
+    pub q1: Y,
+    /// Median: line across the box.
+    pub median: Y,
+    /// Third quartile: top of the box.
+    pub q3: Y,
+    /// End of the upper whisker.
+    pub upper: Y,
+    /// Values drawn as markers beyond the whiskers.
+    pub outliers: Vec<Y>,
+}
+
+type GetSummary<T, Y> = Rc<dyn Fn(&T) -> BoxSummary<Y>>;
+
+/// X with (lower, Q1, median, Q3, upper) and outlier positions of a box.
+pub(crate) type BoxPositions = (f64, [f64; 5], Vec<f64>);
+
+/// Draws a box plot (box and whisker) series on the chart e.g., latency distributions.
+///
+/// Each X value has a box from the first to third quartile with a line at the median, whiskers, and outlier markers. The summary can be pre-computed with [BoxPlot::new] or computed from raw samples with [BoxPlot::from_samples]. Boxes are sized and grouped like bars: see [Bar::gap](crate::Bar::gap).
+///
+/// The median is used as the Y value e.g., in the tooltip. The Y range includes the whiskers and outliers.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Endpoint { id: f64, latencies: Vec<f64> }
+/// let series = Series::new(|e: &Endpoint| e.id)
+///     .box_plot(BoxPlot::from_samples(|e: &Endpoint| e.latencies.clone()).with_name("latency"));
+/// ```
+#[non_exhaustive]
+pub struct BoxPlot<T, Y> {
+    get_summary: GetSummary<T, Y>,
+    /// Name of the series. Used in the legend and tooltip.
+    pub name: RwSignal<String>,
+    /// Colour of the boxes. If not set, the next colour in the series will be used. Default is `None`.
+    pub colour: RwSignal<Option<Colour>>,
+    /// Gap between groups of boxes. Clamped to 0.0 and 1.0. Defaults to [BAR_GAP](crate::BAR_GAP).
+    pub gap: RwSignal<f64>,
+    /// Gap inside a group of boxes and bars. Clamped to 0.0 and 1.0. Defaults to [BAR_GAP_INNER](crate::BAR_GAP_INNER).
+    pub group_gap: RwSignal<f64>,
+    /// Width of the box outline, median, and whiskers.
+    pub width: RwSignal<f64>,
+    /// Shape of the outlier markers. Default is [MarkerShape::Circle].
+    pub outlier_marker: RwSignal<MarkerShape>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseBoxPlot {
+    group_id: usize,
+    colour: Signal<Colour>,
+    gap: RwSignal<f64>,
+    group_gap: RwSignal<f64>,
+    width: RwSignal<f64>,
+    outlier_marker: RwSignal<MarkerShape>,
+}
+
+impl<T, Y> BoxPlot<T, Y> {
+    /// Create a new box plot from pre-computed whisker and quartile getters. Use [with_outliers](Self::with_outliers) to add outliers.
+    pub fn new(
+        get_lower: impl Fn(&T) -> Y + 'static,
+        get_q1: impl Fn(&T) -> Y + 'static,
+        get_median: impl Fn(&T) -> Y + 'static,
+        get_q3: impl Fn(&T) -> Y + 'static,
+        get_upper: impl Fn(&T) -> Y + 'static,
+    ) -> Self
+    where
+        Y: Tick,
+    {
+        Self::from_summary(move |t| BoxSummary {
+            lower: get_lower(t),
+            q1: get_q1(t),
+            median: get_median(t),
+            q3: get_q3(t),
+            upper: get_upper(t),
+            outliers: Vec::new(),
+        })
+    }
+
+    fn from_summary(get_summary: impl Fn(&T) -> BoxSummary<Y> + 'static) -> Self {
+        Self {
+            get_summary: Rc::new(get_summary),
+            name: RwSignal::default(),
+            colour: RwSignal::default(),
+            gap: create_rw_signal(super::BAR_GAP),
+            group_gap: create_rw_signal(super::BAR_GAP_INNER),
+            width: create_rw_signal(1.0),
+            outlier_marker: create_rw_signal(MarkerShape::Circle),
+        }
+    }
+
+    /// Set the outliers drawn as markers. Replaces any existing outliers.
+    pub fn with_outliers(mut self, get_outliers: impl Fn(&T) -> Vec<Y> + 'static) -> Self
+    where
+        T: 'static,
+        Y: 'static,
+    {
+        let get_summary = self.get_summary;
+        self.get_summary = Rc::new(move |t| BoxSummary {
+            outliers: get_outliers(t),
+            ..get_summary(t)
+        });
+        self
+    }
+
+    /// Set the name of the series. Used in the legend.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.name.set(name.into());
+        self
+    }
+
+    /// Set the colour of the boxes. If not set, the next colour in the series will be used.
+    pub fn with_colour(self, colour: impl Into<Option<Colour>>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+
+    /// Set the gap between groups of boxes. Clamped to 0.0 and 1.0.
+    pub fn with_gap(self, gap: f64) -> Self {
+        self.gap.set(gap);
+        self
+    }
+
+    /// Set the shape of the outlier markers.
+    pub fn with_outlier_marker(self, shape: impl Into<MarkerShape>) -> Self {
+        self.outlier_marker.set(shape.into());
+        self
+    }
+}
+
+impl<T> BoxPlot<T, f64> {
+    /// Create a new box plot that computes its summary from raw samples. Quartiles are linearly interpolated. Whiskers extend to the furthest sample within 1.5 IQR of the box with the remaining samples as outliers. `NaN` samples are ignored.
+    pub fn from_samples(get_samples: impl Fn(&T) -> Vec<f64> + 'static) -> Self {
+        Self::from_summary(move |t| summarise(get_samples(t)))
+    }
+}
+
+impl<T, Y> Clone for BoxPlot<T, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            get_summary: self.get_summary.clone(),
+            name: self.name,
+            colour: self.colour,
+            gap: self.gap,
+            group_gap: self.group_gap,
+            width: self.width,
+            outlier_marker: self.outlier_marker,
+        }
+    }
+}
+
+/// Linearly interpolated quantile of sorted, non-empty samples.
+pub(super) fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let at = (sorted.len() - 1) as f64 * q;
+    let (lower, upper) = (sorted[at.floor() as usize], sorted[at.ceil() as usize]);
+    lower + (upper - lower) * at.fract()
+}
+
+/// Computes a box plot summary from samples. Returns NaNs if there are no samples.
+fn summarise(mut samples: Vec<f64>) -> BoxSummary<f64> {
+    samples.retain(|sample| !sample.is_nan());
+    samples.sort_by(f64::total_cmp);
+    if samples.is_empty() {
+        return BoxSummary {
+            lower: f64::NAN,
+            q1: f64::NAN,
+            median: f64::NAN,
+            q3: f64::NAN,
+            upper: f64::NAN,
+            outliers: Vec::new(),
+        };
+    }
+
+    let quantile = |q| quantile(&samples, q);
+    let (q1, median, q3) = (quantile(0.25), quantile(0.5), quantile(0.75));
+    let iqr = q3 - q1;
+    let (low_fence, high_fence) = (q1 - iqr * WHISKER_IQR, q3 + iqr * WHISKER_IQR);
+    let inside = |sample: &&f64| (low_fence..=high_fence).contains(*sample);
+    BoxSummary {
+        lower: *samples.iter().find(inside).unwrap_or(&q1),
+        q1,
+        median,
+        q3,
+        upper: *samples.iter().rev().find(inside).unwrap_or(&q3),
+        outliers: samples
+            .iter()
+            .filter(|sample| !inside(sample))
+            .copied()
+            .collect(),
+    }
+}
+
+/// Y getter using the median with the whole summary alongside.
+struct SummaryY<T, Y> {
+    get_summary: GetSummary<T, Y>,
+}
+
+impl<T, Y> GetYValue<T, Y> for SummaryY<T, Y> {
+    fn value(&self, t: &T) -> Y {
+        (self.get_summary)(t).median
+    }
+
+    fn cumulative_value(&self, t: &T) -> Y {
+        self.value(t)
+    }
+
+    fn extra(&self, t: &T) -> Option<Extra<Y>> {
+        Some(Extra::BoxPlot((self.get_summary)(t)))
+    }
+}
+
+impl<T: 'static, Y: 'static> ApplyUseSeries<T, Y> for BoxPlot<T, Y> {
+    fn apply_use_series(self: Rc<Self>, series: &mut SeriesAcc<T, Y>) {
+        let colour = series.next_colour();
+        _ = series.push_bar(colour, (*self).clone());
+    }
+}
+
+impl<T: 'static, Y: 'static> IntoUseBar<T, Y> for BoxPlot<T, Y> {
+    fn into_use_bar(
+        self,
+        id: usize,
+        group_id: usize,
+        colour: Memo<Colour>,
+    ) -> (UseY, Rc<dyn GetYValue<T, Y>>) {
+        let override_colour = self.colour;
+        let colour = Signal::derive(move || override_colour.get().unwrap_or(colour.get()));
+        let box_plot = UseY::new_box_plot(
+            id,
+            self.name,
+            UseBoxPlot {
+                group_id,
+                colour,
+                gap: self.gap,
+                group_gap: self.group_gap,
+                width: self.width,
+                outlier_marker: self.outlier_marker,
+            },
+        );
+        let get_y = Rc::new(SummaryY {
+            get_summary: self.get_summary,
+        });
+        (box_plot, get_y)
+    }
+}
+
+/// SVG positions of each box.
+pub(super) fn svg_summaries<X, Y>(
+    state: &State<X, Y>,
+    extras: Signal<Extras>,
+) -> Signal<Vec<BoxPositions>> {
+    let projection = state.projection;
+    Signal::derive(move || {
+        let proj = projection.get();
+        let to_svg_y = |&y: &f64| proj.position_to_svg(0.0, y).1;
+        extras.with(|extras| {
+            (extras.iter())
+                .filter_map(|((x, _), extra)| match extra {
+                    Extra::BoxPlot(s) => {
+                        let svg_x = proj.position_to_svg(*x, 0.0).0;
+                        let values = [&s.lower, &s.q1, &s.median, &s.q3, &s.upper].map(to_svg_y);
+                        let outliers = s.outliers.iter().map(to_svg_y).collect();
+                        Some((svg_x, values, outliers))
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        })
+    })
+}
+
+#[component]
+pub fn RenderBoxPlot<X: 'static, Y: 'static>(
+    box_plot: UseBoxPlot,
+    state: State<X, Y>,
+    // SVG positions of each box
+    summaries: Signal<Vec<BoxPositions>>,
+) -> impl IntoView {
+    let colour = box_plot.colour;
+    let width = box_plot.width;
+    let outlier_marker = box_plot.outlier_marker;
+    // Reuse the bar layout to share X widths and groups with bars
+    let positions = Signal::derive(move || {
+        summaries.with(|summaries| summaries.iter().map(|(x, _, _)| (*x, 0.0)).collect())
+    });
+    let rects = super::bar::group_rects(
+        &state,
+        box_plot.group_id,
+        box_plot.gap,
+        box_plot.group_gap,
+        positions,
+    );
+
+    let boxes = move || {
+        let line_width = width.get();
+        let marker = outlier_marker.get();
+        let diameter = line_width * WIDTH_TO_MARKER;
+        rects.with(|rects| {
+            summaries.with(|summaries| {
+                rects
+                    .iter()
+                    .zip(summaries)
+                    .filter(|(_, (_, values, _))| !values.iter().any(|v| v.is_nan()))
+                    .map(|(&(left, _, box_width), (_, values, outliers))| {
+                        let [lower, q1, median, q3, upper] = *values;
+                        let centre = left + box_width / 2.0;
+                        let cap = box_width * CAP_RATIO / 2.0;
+                        let (top, bottom) = (q3.min(q1), q3.max(q1));
+                        let whiskers = format!(
+                            "M {centre} {top} V {upper} M {} {upper} h {} M {centre} {bottom} V {lower} M {} {lower} h {}",
+                            centre - cap,
+                            cap * 2.0,
+                            centre - cap,
+                            cap * 2.0,
+                        );
+                        let outliers = outliers
+                            .iter()
+                            .map(|&y| {
+                                view! {
+                                    <super::line::MarkerShape
+                                        shape=marker
+                                        x=centre
+                                        y=y
+                                        diameter=diameter
+                                        line_width=line_width />
+                                }
+                            })
+                            .collect_view();
+                        view! {
+                            <path d=whiskers fill="none" />
+                            <rect
+                                x=left
+                                y=top
+                                width=box_width
+                                height=bottom - top
+                                fill-opacity=BOX_PLOT_OPACITY />
+                            <line x1=left y1=median x2=left + box_width y2=median />
+                            <g stroke="none">{outliers}</g>
+                        }
+                    })
+                    .collect_view()
+            })
+        })
+    };
+
+    view! {
+        <g
+            class="_chartistry_box_plot"
+            fill=move || colour.get().to_string()
+            stroke=move || colour.get().to_string()
+            stroke-width=width>
+            {boxes}
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarise() {
+        let summary = summarise(vec![5.0, 1.0, 3.0, 2.0, 4.0]);
+        assert_eq!(
+            summary,
+            BoxSummary {
+                lower: 1.0,
+                q1: 2.0,
+                median: 3.0,
+                q3: 4.0,
+                upper: 5.0,
+                outliers: vec![],
+            }
+        );
+        // Interpolated quartiles with an outlier and NaN ignored
+        let summary = summarise(vec![1.0, 2.0, 3.0, 4.0, f64::NAN, 100.0]);
+        assert_eq!(summary.q1, 2.0);
+        assert_eq!(summary.median, 3.0);
+        assert_eq!(summary.q3, 4.0);
+        assert_eq!(summary.upper, 4.0);
+        assert_eq!(summary.outliers, vec![100.0]);
+        // Empty
+        assert!(summarise(vec![]).median.is_nan());
+    }
+}
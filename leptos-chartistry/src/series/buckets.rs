@@ -0,0 +1,382 @@
+use super::{box_plot::quantile, GetX, Series};
+use crate::Period;
+use chrono::prelude::*;
+use leptos::*;
+use std::{fmt::Display, rc::Rc};
+
+/// Default upper limit on the number of buckets when the period is chosen automatically.
+pub const MAX_BUCKETS: usize = 100;
+// Upper limit on the number of buckets to avoid runaway rendering e.g., seconds over a year
+const BUCKET_LIMIT: usize = 10_000;
+// Periods tried (smallest first) when choosing automatically
+const AUTO_PERIODS: [Period; 6] = [
+    Period::Second,
+    Period::Minute,
+    Period::Hour,
+    Period::Day,
+    Period::Month,
+    Period::Year,
+];
+
+type GetValue<T> = Rc<dyn Fn(&T) -> f64>;
+
+/// How values in a [bucket](Buckets) are combined into one. `NaN` values are ignored.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum Aggregate {
+    /// Sum of the values. Zero for an empty bucket.
+    #[default]
+    Sum,
+    /// Mean of the values.
+    Mean,
+    /// Smallest value.
+    Min,
+    /// Largest value.
+    Max,
+    /// Number of values. Zero for an empty bucket.
+    Count,
+    /// Linearly interpolated percentile from 0.0 to 100.0 e.g., 95.0 for p95.
+    Percentile(f64),
+}
+
+/// A single bucket covering `start` (inclusive) to `end` (exclusive).
+#[derive(Clone, Debug)]
+pub struct Bucket<Tz: TimeZone> {
+    /// Start of the bucket. Aligned to the period. Used as the X value.
+    pub start: DateTime<Tz>,
+    /// End of the bucket.
+    pub end: DateTime<Tz>,
+    /// Period of the bucket.
+    pub period: Period,
+    /// Aggregated values in the order they were [added](Buckets::aggregate). Missing values (e.g., the mean of an empty bucket) are `NaN`.
+    pub values: Vec<f64>,
+}
+
+// Note: derive would require `Tz: PartialEq` which chrono's time zones don't all implement
+impl<Tz: TimeZone> PartialEq for Bucket<Tz> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start
+            && self.end == other.end
+            && self.period == other.period
+            && self.values == other.values
+    }
+}
+
+impl<Tz: TimeZone> Bucket<Tz> {
+    /// Returns the aggregated value at `index` in the order they were [added](Buckets::aggregate). Returns `NaN` if there is no such value.
+    pub fn value(&self, index: usize) -> f64 {
+        self.values.get(index).copied().unwrap_or(f64::NAN)
+    }
+}
+
+/// Groups raw data with irregular timestamps into buckets of a [Period] e.g., events per hour. Pass [data](Self::data) as the chart's data and build on [series](Self::series) for its series.
+///
+/// Each [aggregate](Self::aggregate) combines the values in a bucket into one e.g., a sum or p95. Buckets are aligned the same way as [Timestamps](crate::Timestamps) ticks and cover the whole X range so empty buckets are kept.
+///
+/// Set a fixed [period](Self::period) or leave it as `None` to pick the smallest period that fits in [max_buckets](Self::max_buckets) over the X range. Buckets are recomputed whenever the data, period, or X range (e.g., the series' [min_x](Series::min_x)) change.
+///
+/// # Example
+/// ```rust
+/// # use chrono::prelude::*;
+/// # use leptos::*;
+/// # use leptos_chartistry::*;
+/// # struct Event { at: DateTime<Utc>, latency: f64 }
+/// # let events: Signal<Vec<Event>> = Signal::default();
+/// let buckets = Buckets::new(events, |e: &Event| e.at)
+///     .with_period(Period::Hour)
+///     .aggregate(|_: &Event| 1.0, Aggregate::Count)
+///     .aggregate(|e: &Event| e.latency, Aggregate::Percentile(95.0));
+/// let series = buckets
+///     .series()
+///     .bar(Bar::new(|b: &Bucket<Utc>| b.value(0)).with_name("events"))
+///     .line(Line::new(|b: &Bucket<Utc>| b.value(1)).with_name("p95 latency"));
+/// let data = buckets.data();
+/// ```
+pub struct Buckets<T: 'static, Tz: TimeZone + 'static> {
+    data: Signal<Vec<T>>,
+    get_x: GetX<T, DateTime<Tz>>,
+    aggregates: Vec<(GetValue<T>, Aggregate)>,
+    /// Period of each bucket. If not set, the smallest period with at most [max_buckets](Self::max_buckets) buckets is used. Default is `None`.
+    pub period: RwSignal<Option<Period>>,
+    /// Maximum number of buckets when choosing the period automatically. Defaults to [MAX_BUCKETS].
+    pub max_buckets: RwSignal<usize>,
+    /// Start of the X range. Data before is ignored. If not set, the earliest timestamp is used. Shared with the [series](Self::series).
+    pub min_x: RwSignal<Option<DateTime<Tz>>>,
+    /// End of the X range. Data after is ignored. If not set, the latest timestamp is used. Shared with the [series](Self::series).
+    pub max_x: RwSignal<Option<DateTime<Tz>>>,
+}
+
+impl<T: 'static, Tz: TimeZone + 'static> Buckets<T, Tz> {
+    /// Creates new buckets from a signal of data. `get_x` returns the timestamp of each datum. Data does not need to be in order.
+    pub fn new(
+        data: impl Into<Signal<Vec<T>>>,
+        get_x: impl Fn(&T) -> DateTime<Tz> + 'static,
+    ) -> Self {
+        Self {
+            data: data.into(),
+            get_x: Rc::new(get_x),
+            aggregates: Vec::new(),
+            period: RwSignal::default(),
+            max_buckets: create_rw_signal(MAX_BUCKETS),
+            min_x: RwSignal::default(),
+            max_x: RwSignal::default(),
+        }
+    }
+
+    /// Adds an aggregate of `get_value` across each bucket. Values are stored in the order added. See [Bucket::value].
+    pub fn aggregate(
+        mut self,
+        get_value: impl Fn(&T) -> f64 + 'static,
+        aggregate: Aggregate,
+    ) -> Self {
+        self.aggregates.push((Rc::new(get_value), aggregate));
+        self
+    }
+
+    /// Sets a fixed period for each bucket.
+    pub fn with_period(self, period: impl Into<Option<Period>>) -> Self {
+        self.period.set(period.into());
+        self
+    }
+
+    /// Sets the maximum number of buckets when choosing the period automatically.
+    pub fn with_max_buckets(self, max_buckets: usize) -> Self {
+        self.max_buckets.set(max_buckets);
+        self
+    }
+
+    /// Sets the X range. Data outside is ignored.
+    pub fn with_x_range(
+        self,
+        min_x: impl Into<Option<DateTime<Tz>>>,
+        max_x: impl Into<Option<DateTime<Tz>>>,
+    ) -> Self {
+        self.min_x.set(min_x.into());
+        self.max_x.set(max_x.into());
+        self
+    }
+
+    /// Returns the buckets. Use as the chart's data.
+    pub fn data(&self) -> Signal<Vec<Bucket<Tz>>> {
+        let (data, get_x) = (self.data, self.get_x.clone());
+        let aggregates = self.aggregates.clone();
+        let (period, max_buckets) = (self.period, self.max_buckets);
+        let (min_x, max_x) = (self.min_x, self.max_x);
+        create_memo(move |_| {
+            let points = data.with(|data| {
+                (data.iter())
+                    .map(|datum| {
+                        let values = (aggregates.iter())
+                            .map(|(get_value, _)| get_value(datum))
+                            .collect();
+                        (get_x(datum), values)
+                    })
+                    .collect::<Vec<_>>()
+            });
+            let range = (min_x.get(), max_x.get());
+            let period = period.get().map_or_else(|| Err(max_buckets.get()), Ok);
+            let aggregates = aggregates.iter().map(|&(_, agg)| agg).collect::<Vec<_>>();
+            bucket_points(points, &aggregates, period, range)
+        })
+        .into()
+    }
+
+    /// Returns a series of buckets by start timestamp with an X range shared with the buckets. Add lines or bars using [Bucket::value].
+    pub fn series(&self) -> Series<Bucket<Tz>, DateTime<Tz>, f64>
+    where
+        Tz::Offset: Display,
+    {
+        let mut series = Series::new(|bucket: &Bucket<Tz>| bucket.start.clone());
+        series.min_x = self.min_x;
+        series.max_x = self.max_x;
+        series
+    }
+}
+
+impl<T, Tz: TimeZone> Clone for Buckets<T, Tz> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data,
+            get_x: self.get_x.clone(),
+            aggregates: self.aggregates.clone(),
+            period: self.period,
+            max_buckets: self.max_buckets,
+            min_x: self.min_x,
+            max_x: self.max_x,
+        }
+    }
+}
+
+impl Aggregate {
+    /// Combines values into one. `NaN` values are ignored.
+    fn apply(self, mut values: Vec<f64>) -> f64 {
+        values.retain(|value| !value.is_nan());
+        let empty = values.is_empty();
+        match self {
+            Aggregate::Sum => values.iter().sum(),
+            Aggregate::Count => values.len() as f64,
+            _ if empty => f64::NAN,
+            Aggregate::Mean => values.iter().sum::<f64>() / values.len() as f64,
+            Aggregate::Min => values.into_iter().reduce(f64::min).unwrap_or(f64::NAN),
+            Aggregate::Max => values.into_iter().reduce(f64::max).unwrap_or(f64::NAN),
+            Aggregate::Percentile(percentile) => {
+                values.sort_by(f64::total_cmp);
+                quantile(&values, percentile.clamp(0.0, 100.0) / 100.0)
+            }
+        }
+    }
+}
+
+/// Picks the smallest period with at most `max_buckets` buckets from first to last. Falls back to the largest period.
+fn auto_period<Tz: TimeZone>(
+    first: &DateTime<Tz>,
+    last: &DateTime<Tz>,
+    max_buckets: usize,
+) -> Period {
+    let fits = |period: Period| {
+        let Some(mut start) = period.truncate_at(first.clone()) else {
+            return false;
+        };
+        let mut count = 0;
+        while start <= *last {
+            count += 1;
+            if count > max_buckets {
+                return false;
+            }
+            start = start + period;
+        }
+        true
+    };
+    (AUTO_PERIODS.into_iter())
+        .find(|&period| fits(period))
+        .unwrap_or(Period::Year)
+}
+
+/// Buckets timestamped values by a period (or `Err` with the maximum number of buckets to choose automatically) across a range. Each value is combined by its aggregate.
+fn bucket_points<Tz: TimeZone>(
+    mut points: Vec<(DateTime<Tz>, Vec<f64>)>,
+    aggregates: &[Aggregate],
+    period: Result<Period, usize>,
+    (min_x, max_x): (Option<DateTime<Tz>>, Option<DateTime<Tz>>),
+) -> Vec<Bucket<Tz>> {
+    // Only values in range
+    points.retain(|(x, _)| {
+        min_x.as_ref().is_none_or(|min_x| x >= min_x)
+            && max_x.as_ref().is_none_or(|max_x| x <= max_x)
+    });
+    points.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let first = min_x.or_else(|| points.first().map(|(x, _)| x.clone()));
+    let last = max_x.or_else(|| points.last().map(|(x, _)| x.clone()));
+    let (Some(first), Some(last)) = (first, last) else {
+        return Vec::new();
+    };
+    let period = period.unwrap_or_else(|max_buckets| auto_period(&first, &last, max_buckets));
+    let Some(mut start) = period.truncate_at(first) else {
+        return Vec::new();
+    };
+
+    // Walk buckets and sorted values together
+    let mut points = points.into_iter().peekable();
+    let mut buckets = Vec::new();
+    while start <= last && buckets.len() < BUCKET_LIMIT {
+        let end = start.clone() + period;
+        let mut values = vec![Vec::new(); aggregates.len()];
+        while let Some((_, point)) = points.next_if(|(x, _)| *x < end) {
+            for (values, value) in values.iter_mut().zip(point) {
+                values.push(value);
+            }
+        }
+        let values = (aggregates.iter().zip(values))
+            .map(|(aggregate, values)| aggregate.apply(values))
+            .collect();
+        buckets.push(Bucket {
+            start,
+            end: end.clone(),
+            period,
+            values,
+        });
+        start = end;
+    }
+    buckets
+}
+
+impl Display for Aggregate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Aggregate::Sum => write!(f, "Sum"),
+            Aggregate::Mean => write!(f, "Mean"),
+            Aggregate::Min => write!(f, "Min"),
+            Aggregate::Max => write!(f, "Max"),
+            Aggregate::Count => write!(f, "Count"),
+            Aggregate::Percentile(percentile) => write!(f, "p{percentile}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(hour: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 1, hour, min, 0).unwrap()
+    }
+
+    fn points() -> Vec<(DateTime<Utc>, Vec<f64>)> {
+        // Out of order with an empty hour
+        vec![
+            (at(12, 40), vec![3.0]),
+            (at(10, 5), vec![1.0]),
+            (at(10, 55), vec![5.0]),
+            (at(12, 0), vec![f64::NAN]),
+        ]
+    }
+
+    fn values(aggregate: Aggregate) -> Vec<f64> {
+        bucket_points(points(), &[aggregate], Ok(Period::Hour), (None, None))
+            .into_iter()
+            .map(|bucket| bucket.value(0))
+            .collect()
+    }
+
+    #[test]
+    fn test_bucket_points() {
+        let buckets = bucket_points(points(), &[Aggregate::Sum], Ok(Period::Hour), (None, None));
+        let starts = buckets.iter().map(|b| b.start).collect::<Vec<_>>();
+        assert_eq!(starts, [at(10, 0), at(11, 0), at(12, 0)]);
+        assert_eq!(buckets[0].end, at(11, 0));
+        assert_eq!(values(Aggregate::Sum), [6.0, 0.0, 3.0]);
+        assert_eq!(values(Aggregate::Count), [2.0, 0.0, 1.0]);
+        let mean = values(Aggregate::Mean);
+        assert_eq!(mean[0], 3.0);
+        assert!(mean[1].is_nan());
+        assert_eq!(values(Aggregate::Max)[0], 5.0);
+        assert_eq!(values(Aggregate::Min)[0], 1.0);
+        assert_eq!(values(Aggregate::Percentile(50.0))[0], 3.0);
+        assert_eq!(values(Aggregate::Percentile(150.0))[0], 5.0);
+    }
+
+    #[test]
+    fn test_bucket_range() {
+        // Range drops values outside and keeps empty buckets up to its end
+        let range = (Some(at(10, 30)), Some(at(13, 10)));
+        let buckets = bucket_points(points(), &[Aggregate::Count], Ok(Period::Hour), range);
+        let counts = buckets.iter().map(|b| b.value(0)).collect::<Vec<_>>();
+        assert_eq!(counts, [1.0, 0.0, 1.0, 0.0]);
+        assert!(bucket_points::<Utc>(vec![], &[], Ok(Period::Hour), (None, None)).is_empty());
+    }
+
+    #[test]
+    fn test_auto_period() {
+        let (first, last) = (at(10, 5), at(12, 40));
+        assert_eq!(auto_period(&first, &last, 200), Period::Minute);
+        assert_eq!(auto_period(&first, &last, 3), Period::Hour);
+        assert_eq!(auto_period(&first, &last, 1), Period::Day);
+        assert_eq!(auto_period(&first, &last, 0), Period::Year);
+        // Re-buckets as the range changes
+        let buckets = bucket_points(points(), &[], Err(10), (None, None));
+        assert_eq!(buckets[0].period, Period::Hour);
+        let range = (Some(at(10, 0)), Some(at(10, 6)));
+        let buckets = bucket_points(points(), &[], Err(10), range);
+        assert_eq!(buckets[0].period, Period::Minute);
+    }
+}
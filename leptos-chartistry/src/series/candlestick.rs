@@ -0,0 +1,259 @@
+use super::{ApplyUseSeries, Extra, Extras, GetYValue, IntoUseCandlestick, SeriesAcc, UseY};
+use crate::{state::State, Colour, Tick};
+use leptos::*;
+use std::rc::Rc;
+
+/// Default colour of a candle where the close is at or above the open.
+pub const CANDLESTICK_UP_COLOUR: Colour = Colour::from_rgb(0x26, 0xa6, 0x9a);
+/// Default colour of a candle where the close is below the open.
+pub const CANDLESTICK_DOWN_COLOUR: Colour = Colour::from_rgb(0xef, 0x53, 0x50);
+
+type GetOhlc<T, Y> = Rc<dyn Fn(&T) -> Y>;
+
+/// Draws a candlestick (OHLC) series on the chart e.g., for financial data.
+///
+/// Each X value has an open, high, low, and close. A wick is drawn from the low to the high and a body from the open to the close. Candles are coloured by direction: up when the close is at or above the open and down otherwise. Candles are sized like bars: see [Bar::gap](crate::Bar::gap).
+///
+/// The close is used as the Y value e.g., for the tooltip's sorting and guide lines. The tooltip shows all four values.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Price { day: f64, open: f64, high: f64, low: f64, close: f64 }
+/// let series = Series::new(|p: &Price| p.day).candlestick(
+///     Candlestick::new(|p: &Price| p.open, |p: &Price| p.high, |p: &Price| p.low, |p: &Price| p.close)
+///         .with_name("ACME"),
+/// );
+/// ```
+#[non_exhaustive]
+pub struct Candlestick<T, Y> {
+    get_open: GetOhlc<T, Y>,
+    get_high: GetOhlc<T, Y>,
+    get_low: GetOhlc<T, Y>,
+    get_close: GetOhlc<T, Y>,
+    /// Name of the series. Used in the legend and tooltip.
+    pub name: RwSignal<String>,
+    /// Colour of candles that closed at or above their open. Default is [CANDLESTICK_UP_COLOUR].
+    pub up_colour: RwSignal<Colour>,
+    /// Colour of candles that closed below their open. Default is [CANDLESTICK_DOWN_COLOUR].
+    pub down_colour: RwSignal<Colour>,
+    /// Gap between candles. Clamped to 0.0 and 1.0. Defaults to [BAR_GAP](crate::BAR_GAP).
+    pub gap: RwSignal<f64>,
+    /// Width of the wicks.
+    pub width: RwSignal<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseCandlestick {
+    up_colour: RwSignal<Colour>,
+    down_colour: RwSignal<Colour>,
+    gap: RwSignal<f64>,
+    width: RwSignal<f64>,
+}
+
+impl<T, Y> Candlestick<T, Y> {
+    /// Create a new candlestick series from open, high, low, and close getters.
+    pub fn new(
+        get_open: impl Fn(&T) -> Y + 'static,
+        get_high: impl Fn(&T) -> Y + 'static,
+        get_low: impl Fn(&T) -> Y + 'static,
+        get_close: impl Fn(&T) -> Y + 'static,
+    ) -> Self
+    where
+        Y: Tick,
+    {
+        Self {
+            get_open: Rc::new(get_open),
+            get_high: Rc::new(get_high),
+            get_low: Rc::new(get_low),
+            get_close: Rc::new(get_close),
+            name: RwSignal::default(),
+            up_colour: create_rw_signal(CANDLESTICK_UP_COLOUR),
+            down_colour: create_rw_signal(CANDLESTICK_DOWN_COLOUR),
+            gap: create_rw_signal(super::BAR_GAP),
+            width: create_rw_signal(1.0),
+        }
+    }
+
+    /// Set the name of the series. Used in the legend.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.name.set(name.into());
+        self
+    }
+
+    /// Set the up and down colours of the candles.
+    pub fn with_colours(self, up: impl Into<Colour>, down: impl Into<Colour>) -> Self {
+        self.up_colour.set(up.into());
+        self.down_colour.set(down.into());
+        self
+    }
+
+    /// Set the gap between candles. Clamped to 0.0 and 1.0.
+    pub fn with_gap(self, gap: f64) -> Self {
+        self.gap.set(gap);
+        self
+    }
+}
+
+impl<T, Y> Clone for Candlestick<T, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            get_open: self.get_open.clone(),
+            get_high: self.get_high.clone(),
+            get_low: self.get_low.clone(),
+            get_close: self.get_close.clone(),
+            name: self.name,
+            up_colour: self.up_colour,
+            down_colour: self.down_colour,
+            gap: self.gap,
+            width: self.width,
+        }
+    }
+}
+
+/// Y getter using the close with the open, high, and low alongside.
+struct OhlcY<T, Y> {
+    open: GetOhlc<T, Y>,
+    high: GetOhlc<T, Y>,
+    low: GetOhlc<T, Y>,
+    close: GetOhlc<T, Y>,
+}
+
+impl<T, Y> GetYValue<T, Y> for OhlcY<T, Y> {
+    fn value(&self, t: &T) -> Y {
+        (self.close)(t)
+    }
+
+    fn cumulative_value(&self, t: &T) -> Y {
+        (self.close)(t)
+    }
+
+    fn extra(&self, t: &T) -> Option<Extra<Y>> {
+        Some(Extra::Ohlc([
+            (self.open)(t),
+            (self.high)(t),
+            (self.low)(t),
+            (self.close)(t),
+        ]))
+    }
+}
+
+impl<T: 'static, Y: 'static> ApplyUseSeries<T, Y> for Candlestick<T, Y> {
+    fn apply_use_series(self: Rc<Self>, series: &mut SeriesAcc<T, Y>) {
+        _ = series.push_candlestick((*self).clone());
+    }
+}
+
+impl<T: 'static, Y: 'static> IntoUseCandlestick<T, Y> for Candlestick<T, Y> {
+    fn into_use_candlestick(self, id: usize) -> (UseY, Rc<dyn GetYValue<T, Y>>) {
+        let candlestick = UseY::new_candlestick(
+            id,
+            self.name,
+            UseCandlestick {
+                up_colour: self.up_colour,
+                down_colour: self.down_colour,
+                gap: self.gap,
+                width: self.width,
+            },
+        );
+        let get_y = Rc::new(OhlcY {
+            open: self.get_open,
+            high: self.get_high,
+            low: self.get_low,
+            close: self.get_close,
+        });
+        (candlestick, get_y)
+    }
+}
+
+/// Returns the body's top and bottom (SVG Y) and whether the candle is up. SVG Y increases downwards.
+fn candle_body(open: f64, close: f64) -> (f64, f64, bool) {
+    if close <= open {
+        (close, open, true)
+    } else {
+        (open, close, false)
+    }
+}
+
+/// SVG (X, open, high, low, close) of each candle.
+pub(super) fn svg_ohlc<X, Y>(
+    state: &State<X, Y>,
+    extras: Signal<Extras>,
+) -> Signal<Vec<(f64, [f64; 4])>> {
+    let projection = state.projection;
+    Signal::derive(move || {
+        let proj = projection.get();
+        extras.with(|extras| {
+            (extras.iter())
+                .filter_map(|&((x, _), ref extra)| match extra {
+                    Extra::Ohlc(ohlc) => {
+                        let svg_x = proj.position_to_svg(x, 0.0).0;
+                        Some((svg_x, ohlc.map(|y| proj.position_to_svg(x, y).1)))
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        })
+    })
+}
+
+#[component]
+pub fn RenderCandlestick<X: 'static, Y: 'static>(
+    candlestick: UseCandlestick,
+    state: State<X, Y>,
+    // SVG (X, open, high, low, close) of each candle
+    ohlc: Signal<Vec<(f64, [f64; 4])>>,
+) -> impl IntoView {
+    let UseCandlestick {
+        up_colour,
+        down_colour,
+        gap,
+        width,
+    } = candlestick;
+    let x_width = state.layout.x_width;
+    let candles = move || {
+        let gap = gap.get().clamp(0.0, 1.0);
+        let body_width = x_width.get() * (1.0 - gap);
+        let (up_colour, down_colour) = (up_colour.get(), down_colour.get());
+        ohlc.with(|ohlc| {
+            ohlc.iter()
+                .filter(|(x, values)| !(x.is_nan() || values.iter().any(|v| v.is_nan())))
+                .map(|&(x, [open, high, low, close])| {
+                    let (top, bottom, up) = candle_body(open, close);
+                    let colour = if up { up_colour } else { down_colour }.to_string();
+                    view! {
+                        <g fill=colour.clone() stroke=colour>
+                            <line x1=x y1=high x2=x y2=low />
+                            <rect
+                                x=x - body_width / 2.0
+                                y=top
+                                width=body_width
+                                // Keep flat candles visible
+                                height=(bottom - top).max(1.0) />
+                        </g>
+                    }
+                })
+                .collect_view()
+        })
+    };
+    view! {
+        <g class="_chartistry_candlestick" stroke-width=width>
+            {candles}
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candle_body() {
+        // Up: close is above the open (smaller SVG Y)
+        assert_eq!(candle_body(10.0, 4.0), (4.0, 10.0, true));
+        // Down
+        assert_eq!(candle_body(4.0, 10.0), (4.0, 10.0, false));
+        // Flat counts as up
+        assert_eq!(candle_body(5.0, 5.0), (5.0, 5.0, true));
+    }
+}
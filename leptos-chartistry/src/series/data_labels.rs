@@ -0,0 +1,191 @@
+use crate::colours::Colour;
+use leptos::*;
+
+/// Placement of data labels: text showing the Y value at each point on a line or bar.
+///
+/// Labels are formatted using the tooltip's Y tick labels. Labels that would overlap a previous label are skipped.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum DataLabelPlacement {
+    /// No data labels.
+    #[default]
+    None,
+    /// Above each line point or beyond the end of each bar.
+    Outside,
+    /// Centred on each line point or inside the end of each bar.
+    Inside,
+}
+
+/// Where a data label is anchored in SVG coordinates.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct DataLabelAnchor {
+    pub x: f64,
+    /// Y of the point or end of the bar.
+    pub y: f64,
+    /// Y of the bar's base. Same as `y` for lines.
+    pub base_y: f64,
+}
+
+// Label bounds (left, top, right, bottom)
+type LabelBounds = (f64, f64, f64, f64);
+
+/// Returns which labels to show. A label is skipped if it overlaps the last shown label.
+fn skip_overlapping(labels: &[LabelBounds]) -> Vec<bool> {
+    let mut last: Option<LabelBounds> = None;
+    labels
+        .iter()
+        .map(|&label @ (left, top, right, bottom)| {
+            let overlaps =
+                last.is_some_and(|(l, t, r, b)| left < r && l < right && top < b && t < bottom);
+            if !overlaps {
+                last = Some(label);
+            }
+            !overlaps
+        })
+        .collect()
+}
+
+#[component]
+pub(super) fn DataLabels(
+    placement: RwSignal<DataLabelPlacement>,
+    anchors: Signal<Vec<DataLabelAnchor>>,
+    labels: Signal<Vec<String>>,
+    colour: Signal<Colour>,
+    // Text colour when placed inside e.g., on top of a bar's fill
+    inside_colour: Signal<Colour>,
+    font_height: Memo<f64>,
+    font_width: Memo<f64>,
+) -> impl IntoView {
+    let texts = move || {
+        let placement = placement.get();
+        if placement == DataLabelPlacement::None {
+            return ().into_view();
+        }
+        let (font_height, font_width) = (font_height.get(), font_width.get());
+        let pad = font_width / 2.0;
+
+        // Position each label: (x, centre y, text)
+        let labels = labels.get();
+        let positioned = anchors.with(|anchors| {
+            anchors
+                .iter()
+                .zip(labels)
+                .filter(|(anchor, _)| !(anchor.x.is_nan() || anchor.y.is_nan()))
+                .map(|(anchor, label)| {
+                    // Direction from the base to the end. Lines and upward bars go up (-1.0)
+                    let dir = if anchor.y <= anchor.base_y { -1.0 } else { 1.0 };
+                    let half = font_height / 2.0;
+                    let y = match placement {
+                        DataLabelPlacement::Outside => anchor.y + dir * (half + pad),
+                        DataLabelPlacement::Inside if anchor.y == anchor.base_y => anchor.y,
+                        DataLabelPlacement::Inside | DataLabelPlacement::None => {
+                            anchor.y - dir * (half + pad)
+                        }
+                    };
+                    (anchor.x, y, label)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        let bounds = positioned
+            .iter()
+            .map(|(x, y, label)| {
+                let half_width = label.chars().count() as f64 * font_width / 2.0;
+                let half_height = font_height / 2.0;
+                (
+                    x - half_width,
+                    y - half_height,
+                    x + half_width,
+                    y + half_height,
+                )
+            })
+            .collect::<Vec<_>>();
+        positioned
+            .into_iter()
+            .zip(skip_overlapping(&bounds))
+            .filter(|(_, show)| *show)
+            .map(|((x, y, label), _)| {
+                view! {
+                    <text
+                        x=x
+                        y=y
+                        text-anchor="middle"
+                        dominant-baseline="middle">
+                        {label}
+                    </text>
+                }
+            })
+            .collect_view()
+    };
+
+    let fill = move || {
+        let colour = match placement.get() {
+            DataLabelPlacement::Inside => inside_colour.get(),
+            _ => colour.get(),
+        };
+        colour.to_string()
+    };
+    view! {
+        <g
+            class="_chartistry_data_labels"
+            fill=fill
+            stroke="none"
+            font-family="monospace"
+            font-size=move || format!("{}px", font_height.get())
+            style="white-space: pre;">
+            {texts}
+        </g>
+    }
+}
+
+impl std::str::FromStr for DataLabelPlacement {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(DataLabelPlacement::None),
+            "outside" => Ok(DataLabelPlacement::Outside),
+            "inside" => Ok(DataLabelPlacement::Inside),
+            _ => Err("unknown data label placement"),
+        }
+    }
+}
+
+impl std::fmt::Display for DataLabelPlacement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataLabelPlacement::None => write!(f, "None"),
+            DataLabelPlacement::Outside => write!(f, "Outside"),
+            DataLabelPlacement::Inside => write!(f, "Inside"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skip_overlapping() {
+        assert_eq!(skip_overlapping(&[]), Vec::<bool>::new());
+        // Spaced out
+        assert_eq!(
+            skip_overlapping(&[(0.0, 0.0, 10.0, 5.0), (20.0, 0.0, 30.0, 5.0)]),
+            vec![true, true]
+        );
+        // Second overlaps first, third is compared against the first
+        assert_eq!(
+            skip_overlapping(&[
+                (0.0, 0.0, 10.0, 5.0),
+                (8.0, 0.0, 18.0, 5.0),
+                (12.0, 0.0, 22.0, 5.0),
+            ]),
+            vec![true, false, true]
+        );
+        // Horizontal overlap at a different height is fine
+        assert_eq!(
+            skip_overlapping(&[(0.0, 0.0, 10.0, 5.0), (5.0, 10.0, 15.0, 15.0)]),
+            vec![true, true]
+        );
+    }
+}
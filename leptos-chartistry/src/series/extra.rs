@@ -0,0 +1,62 @@
+use super::{use_data::Range, BoxSummary};
+use crate::Tick;
+
+/// Position of each data point of a series with its extra values in positions.
+pub type Extras = Vec<((f64, f64), Extra<f64>)>;
+
+/// Values of a data point beyond its Y value e.g., a candlestick's open, high, low, and close. Each kind of series produces its own variant and draws it from positions (`Extra<f64>`).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Extra<Y> {
+    /// Lower and upper error bounds of a line.
+    Error(Y, Y),
+    /// Open, high, low, and close of a candlestick.
+    Ohlc([Y; 4]),
+    /// Summary of a box plot.
+    BoxPlot(BoxSummary<Y>),
+    /// Cell value of a heatmap.
+    Heat(f64),
+    /// Start (None for zero) of a waterfall bar and whether it's a subtotal.
+    Step(Option<Y>, bool),
+    /// Share of a percent stack's total.
+    Share(f64),
+    /// End X position and label of a range bar.
+    Span(f64, String),
+}
+
+impl<Y: Tick> Extra<Y> {
+    /// Extends a Y range to include the Y values drawn.
+    pub fn extend_range(&self, range: &mut Range<Y>) {
+        match self {
+            Self::Error(lower, upper) => [lower, upper].into_iter().for_each(|y| range.update(y)),
+            Self::Ohlc(ohlc) => ohlc.iter().for_each(|y| range.update(y)),
+            Self::BoxPlot(summary) => [&summary.lower, &summary.upper]
+                .into_iter()
+                .chain(&summary.outliers)
+                .for_each(|y| range.update(y)),
+            Self::Step(Some(start), _) => range.update(start),
+            Self::Step(None, _) | Self::Heat(_) | Self::Share(_) | Self::Span(..) => {}
+        }
+    }
+
+    /// Converts Y values to positions.
+    pub fn positions(&self) -> Extra<f64> {
+        match self {
+            Self::Error(lower, upper) => Extra::Error(lower.position(), upper.position()),
+            Self::Ohlc(ohlc) => Extra::Ohlc(ohlc.each_ref().map(Tick::position)),
+            Self::BoxPlot(summary) => Extra::BoxPlot(BoxSummary {
+                lower: summary.lower.position(),
+                q1: summary.q1.position(),
+                median: summary.median.position(),
+                q3: summary.q3.position(),
+                upper: summary.upper.position(),
+                outliers: summary.outliers.iter().map(Tick::position).collect(),
+            }),
+            Self::Heat(value) => Extra::Heat(*value),
+            Self::Step(start, subtotal) => {
+                Extra::Step(start.as_ref().map(Tick::position), *subtotal)
+            }
+            Self::Share(share) => Extra::Share(*share),
+            Self::Span(end, label) => Extra::Span(*end, label.clone()),
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use super::{ApplyUseSeries, Extra, Extras, GetYValue, IntoUseHeatmap, SeriesAcc, UseY};
+use crate::{
+    bounds::Bounds,
+    colours::{ColourScheme, SequentialGradient, LIPARI},
+    layout::colour_scale::format_value,
+    state::State,
+    Tick,
+};
+use leptos::*;
+use std::rc::Rc;
+
+/// Default colour scheme for a heatmap. Assumes a light background with darker colours for lower values.
+pub const HEATMAP_GRADIENT: SequentialGradient = LIPARI;
+
+/// Draws a heatmap: a grid of cells coloured by value.
+///
+/// Each datum (`T`) is one cell. Its X bin comes from the series' X getter, its Y bin from `get_y`, and its colour from `get_value` mapped through a [ColourScheme]. Sequential schemes spread over the range of values while diverging schemes centre on zero. Cells are sized to the smallest gap between bins so leave missing cells out of the data rather than using gaps in bins.
+///
+/// Show the colour scale on an edge with [ColourScale](crate::ColourScale).
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Activity { hour: f64, weekday: f64, count: f64 }
+/// let series = Series::new(|a: &Activity| a.hour)
+///     .heatmap(Heatmap::new(|a: &Activity| a.weekday, |a: &Activity| a.count).with_name("activity"));
+/// ```
+#[non_exhaustive]
+pub struct Heatmap<T, Y> {
+    get_y: Rc<dyn Fn(&T) -> Y>,
+    get_value: Rc<dyn Fn(&T) -> f64>,
+    /// Name of the heatmap. Used in the legend.
+    pub name: RwSignal<String>,
+    /// Colour scheme mapping values to cell colours. Default is [HEATMAP_GRADIENT].
+    pub colours: RwSignal<ColourScheme>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseHeatmap {
+    pub(crate) colours: RwSignal<ColourScheme>,
+}
+
+impl<T, Y> Heatmap<T, Y> {
+    /// Create a new heatmap. `get_y` returns the Y bin of a cell and `get_value` its value. `NaN` values are not drawn.
+    pub fn new(get_y: impl Fn(&T) -> Y + 'static, get_value: impl Fn(&T) -> f64 + 'static) -> Self
+    where
+        Y: Tick,
+    {
+        Self {
+            get_y: Rc::new(get_y),
+            get_value: Rc::new(get_value),
+            name: RwSignal::default(),
+            colours: create_rw_signal(HEATMAP_GRADIENT.into()),
+        }
+    }
+
+    /// Set the name of the heatmap. Used in the legend.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.name.set(name.into());
+        self
+    }
+
+    /// Set the colour scheme e.g., a [SequentialGradient] or [DivergingGradient](crate::colours::DivergingGradient).
+    pub fn with_colours(self, colours: impl Into<ColourScheme>) -> Self {
+        self.colours.set(colours.into());
+        self
+    }
+}
+
+impl<T, Y> Clone for Heatmap<T, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            get_y: self.get_y.clone(),
+            get_value: self.get_value.clone(),
+            name: self.name,
+            colours: self.colours,
+        }
+    }
+}
+
+/// Y getter using the Y bin with the cell's value alongside.
+struct HeatY<T, Y> {
+    get_y: Rc<dyn Fn(&T) -> Y>,
+    get_value: Rc<dyn Fn(&T) -> f64>,
+}
+
+impl<T, Y> GetYValue<T, Y> for HeatY<T, Y> {
+    fn value(&self, t: &T) -> Y {
+        (self.get_y)(t)
+    }
+
+    fn cumulative_value(&self, t: &T) -> Y {
+        (self.get_y)(t)
+    }
+
+    fn extra(&self, t: &T) -> Option<Extra<Y>> {
+        Some(Extra::Heat((self.get_value)(t)))
+    }
+}
+
+impl<T: 'static, Y: 'static> ApplyUseSeries<T, Y> for Heatmap<T, Y> {
+    fn apply_use_series(self: Rc<Self>, series: &mut SeriesAcc<T, Y>) {
+        _ = series.push_heatmap((*self).clone());
+    }
+}
+
+impl<T: 'static, Y: 'static> IntoUseHeatmap<T, Y> for Heatmap<T, Y> {
+    fn into_use_heatmap(self, id: usize) -> (UseY, Rc<dyn GetYValue<T, Y>>) {
+        let heatmap = UseY::new_heatmap(
+            id,
+            self.name,
+            UseHeatmap {
+                colours: self.colours,
+            },
+        );
+        let get_y = Rc::new(HeatY {
+            get_y: self.get_y,
+            get_value: self.get_value,
+        });
+        (heatmap, get_y)
+    }
+}
+
+/// SVG bounds and value of each cell.
+pub(super) fn svg_cells<X, Y>(
+    state: &State<X, Y>,
+    extras: Signal<Extras>,
+) -> Signal<Vec<(Bounds, f64)>> {
+    let (projection, heat_cell) = (state.projection, state.pre.data.heat_cell);
+    Signal::derive(move || {
+        let proj = projection.get();
+        let (width, height) = heat_cell.get().unwrap_or_default();
+        let (half_w, half_h) = (width / 2.0, height / 2.0);
+        extras.with(|extras| {
+            (extras.iter())
+                .filter_map(|&((x, y), ref extra)| match *extra {
+                    Extra::Heat(value) => {
+                        let (left, top) = proj.position_to_svg(x - half_w, y + half_h);
+                        let (right, bottom) = proj.position_to_svg(x + half_w, y - half_h);
+                        Some((Bounds::from_points(left, top, right, bottom), value))
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        })
+    })
+}
+
+#[component]
+pub fn RenderHeatmap<X: 'static, Y: 'static>(
+    heatmap: UseHeatmap,
+    state: State<X, Y>,
+    // SVG bounds and value of each cell
+    cells: Signal<Vec<(Bounds, f64)>>,
+    // Formatted Y bin of each cell
+    labels: Signal<Vec<String>>,
+) -> impl IntoView {
+    let colours = heatmap.colours;
+    let heat_range = state.pre.data.heat_range;
+    let cells = move || {
+        let colours = colours.get();
+        let range = heat_range.get().unwrap_or_default();
+        let labels = labels.get();
+        cells.with(|cells| {
+            cells
+                .iter()
+                .zip(labels.into_iter().chain(std::iter::repeat(String::new())))
+                .filter(|((_, value), _)| !value.is_nan())
+                .map(|(&(bounds, value), label)| {
+                    view! {
+                        <rect
+                            x=bounds.left_x()
+                            y=bounds.top_y()
+                            width=bounds.width()
+                            height=bounds.height()
+                            fill=colours.by_value(value, range).to_string()>
+                            <title>{format!("{label}: {}", format_value(value))}</title>
+                        </rect>
+                    }
+                })
+                .collect_view()
+        })
+    };
+    view! {
+        <g class="_chartistry_heatmap" stroke="none">
+            {cells}
+        </g>
+    }
+}
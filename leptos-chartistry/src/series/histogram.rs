@@ -0,0 +1,258 @@
+use super::{box_plot::quantile, Bar, Series};
+use crate::Colour;
+use leptos::*;
+
+// Upper limit on the number of bins to avoid runaway rendering e.g., from a tiny fixed width. Bins are widened to stay within it
+const MAX_BINS: usize = 10_000;
+// Tolerance when fitting a whole number of bins to the range of values
+const RANGE_EPSILON: f64 = 1e-9;
+
+/// How a [Histogram] chooses its bins.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum Binning {
+    /// Bins of a fixed width. Bin edges are aligned to multiples of the width.
+    FixedWidth(f64),
+    /// A fixed number of equal width bins across the range of values.
+    Count(usize),
+    /// Sturges' rule: `log2(n) + 1` bins. Suits small, roughly normal data.
+    #[default]
+    Sturges,
+    /// Freedman–Diaconis rule: bins of width `2 * IQR / cbrt(n)`. Robust to outliers. Falls back to Sturges if the IQR is zero.
+    FreedmanDiaconis,
+}
+
+/// A single histogram bin covering `start` (inclusive) to `end` (exclusive). The last bin includes its end.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistogramBin {
+    /// Start of the bin.
+    pub start: f64,
+    /// End of the bin.
+    pub end: f64,
+    /// Number of values in the bin.
+    pub count: usize,
+}
+
+impl HistogramBin {
+    /// Centre of the bin. Used as the X value.
+    pub fn centre(&self) -> f64 {
+        (self.start + self.end) / 2.0
+    }
+}
+
+/// Bins raw values into a histogram. Pass [bins](Self::bins) as the chart's data and [series](Self::series) as its series.
+///
+/// Bins are recomputed whenever the values or [binning](Self::binning) change. Each bin is drawn as a bar with no gap so that they sit next to each other. Empty bins are kept so that bins have the same width.
+///
+/// # Example
+/// ```rust
+/// # use leptos::*;
+/// # use leptos_chartistry::*;
+/// # struct Request { latency: f64 }
+/// # let requests: Signal<Vec<Request>> = Signal::default();
+/// let histogram = Histogram::new(requests, |r: &Request| r.latency)
+///     .with_binning(Binning::FreedmanDiaconis)
+///     .with_name("requests");
+/// let (series, data) = (histogram.series(), histogram.bins());
+/// ```
+#[derive(Clone)]
+pub struct Histogram {
+    values: Memo<Vec<f64>>,
+    /// How bins are chosen. Default is [Binning::Sturges].
+    pub binning: RwSignal<Binning>,
+    /// Name of the bars. Used in the legend and tooltip.
+    pub name: RwSignal<String>,
+    /// Colour of the bars. If not set, the first colour in the series will be used.
+    pub colour: RwSignal<Option<Colour>>,
+}
+
+impl Histogram {
+    /// Creates a new histogram from a signal of data. `get_value` returns the value of each datum. `NaN` values are ignored.
+    pub fn new<T: 'static>(
+        data: impl Into<Signal<Vec<T>>>,
+        get_value: impl Fn(&T) -> f64 + 'static,
+    ) -> Self {
+        let data = data.into();
+        let values = create_memo(move |_| data.with(|data| data.iter().map(&get_value).collect()));
+        Self {
+            values,
+            binning: RwSignal::default(),
+            name: RwSignal::default(),
+            colour: RwSignal::default(),
+        }
+    }
+
+    /// Sets how bins are chosen.
+    pub fn with_binning(self, binning: impl Into<Binning>) -> Self {
+        self.binning.set(binning.into());
+        self
+    }
+
+    /// Sets the name of the bars.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.name.set(name.into());
+        self
+    }
+
+    /// Sets the colour of the bars.
+    pub fn with_colour(self, colour: impl Into<Option<Colour>>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+
+    /// Returns the bins. Use as the chart's data.
+    pub fn bins(&self) -> Signal<Vec<HistogramBin>> {
+        let (values, binning) = (self.values, self.binning);
+        create_memo(move |_| values.with(|values| bin_values(values, binning.get()))).into()
+    }
+
+    /// Returns a series of adjacent bars: bin centres against counts. Use as the chart's series.
+    pub fn series(&self) -> Series<HistogramBin, f64, f64> {
+        let mut bar = Bar::new(|bin: &HistogramBin| bin.count as f64).with_gap(0.0);
+        // Share signals with the histogram
+        bar.name = self.name;
+        bar.colour = self.colour;
+        Series::new(HistogramBin::centre)
+            .bar(bar)
+            .with_y_range(0.0, None)
+    }
+}
+
+/// Bins values according to the binning strategy.
+fn bin_values(values: &[f64], binning: Binning) -> Vec<HistogramBin> {
+    let mut sorted = values
+        .iter()
+        .copied()
+        .filter(|value| !value.is_nan())
+        .collect::<Vec<_>>();
+    sorted.sort_by(f64::total_cmp);
+    let (Some(&min), Some(&max)) = (sorted.first(), sorted.last()) else {
+        return Vec::new();
+    };
+    let n = sorted.len() as f64;
+    let range = max - min;
+    let sturges = || range / (n.log2().ceil() + 1.0);
+
+    // Bin width
+    let width = match binning {
+        Binning::FixedWidth(width) if width > 0.0 => width,
+        Binning::Count(count) if count > 0 => range / count as f64,
+        Binning::FreedmanDiaconis => {
+            let iqr = quantile(&sorted, 0.75) - quantile(&sorted, 0.25);
+            if iqr > 0.0 {
+                2.0 * iqr / n.cbrt()
+            } else {
+                sturges()
+            }
+        }
+        Binning::FixedWidth(_) | Binning::Count(_) | Binning::Sturges => sturges(),
+    };
+    // All values are the same: one bin around them
+    if width <= 0.0 {
+        return vec![HistogramBin {
+            start: min - 0.5,
+            end: max + 0.5,
+            count: sorted.len(),
+        }];
+    }
+    // Fixed widths are aligned to multiples of the width. Others span the range with the max in the last bin
+    let (start, bins) = match binning {
+        Binning::FixedWidth(fixed) if fixed > 0.0 => {
+            let start = (min / width).floor() * width;
+            (start, ((max - start) / width).floor() + 1.0)
+        }
+        _ => (min, (range / width - RANGE_EPSILON).ceil().max(1.0)),
+    };
+    // Too many bins: widen them to span the range so values still land in their own bin
+    let (start, width, bins) = if bins > MAX_BINS as f64 {
+        (min, range / MAX_BINS as f64, MAX_BINS)
+    } else {
+        (start, width, bins as usize)
+    };
+    let mut counts = vec![0; bins];
+    for value in sorted {
+        let index = ((value - start) / width).floor() as usize;
+        counts[index.min(bins - 1)] += 1;
+    }
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(index, count)| HistogramBin {
+            start: start + width * index as f64,
+            end: start + width * (index + 1) as f64,
+            count,
+        })
+        .collect()
+}
+
+impl std::fmt::Display for Binning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Binning::FixedWidth(width) => write!(f, "Fixed width ({width})"),
+            Binning::Count(count) => write!(f, "Count ({count})"),
+            Binning::Sturges => write!(f, "Sturges"),
+            Binning::FreedmanDiaconis => write!(f, "Freedman–Diaconis"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(values: &[f64], binning: Binning) -> Vec<(f64, f64, usize)> {
+        bin_values(values, binning)
+            .into_iter()
+            .map(|bin| (bin.start, bin.end, bin.count))
+            .collect()
+    }
+
+    #[test]
+    fn test_bin_values() {
+        let values = [1.0, 2.0, 2.5, 3.0, 7.0, f64::NAN];
+        // Aligned to the width with the max in its own bin
+        assert_eq!(
+            counts(&values, Binning::FixedWidth(2.0)),
+            vec![(0.0, 2.0, 1), (2.0, 4.0, 3), (4.0, 6.0, 0), (6.0, 8.0, 1)]
+        );
+        // Spans the range exactly with the max in the last bin
+        assert_eq!(
+            counts(&values, Binning::Count(3)),
+            vec![(1.0, 3.0, 3), (3.0, 5.0, 1), (5.0, 7.0, 1)]
+        );
+        // Sturges: 5 values -> 4 bins
+        assert_eq!(bin_values(&values, Binning::Sturges).len(), 4);
+        // Total count is preserved
+        let total = |binning| {
+            (bin_values(&values, binning).iter())
+                .map(|bin| bin.count)
+                .sum::<usize>()
+        };
+        assert_eq!(total(Binning::FreedmanDiaconis), 5);
+        assert_eq!(total(Binning::Sturges), 5);
+    }
+
+    #[test]
+    fn test_bin_values_edge_cases() {
+        assert!(bin_values(&[], Binning::Sturges).is_empty());
+        assert_eq!(counts(&[4.0, 4.0], Binning::Sturges), vec![(3.5, 4.5, 2)]);
+        // Invalid widths fall back to Sturges
+        assert_eq!(
+            bin_values(&[1.0, 2.0], Binning::FixedWidth(0.0)),
+            bin_values(&[1.0, 2.0], Binning::Sturges)
+        );
+    }
+
+    #[test]
+    fn test_bin_values_max_bins() {
+        let values = (0..=10).map(f64::from).collect::<Vec<_>>();
+        let bins = bin_values(&values, Binning::FixedWidth(1e-9));
+        assert_eq!(bins.len(), MAX_BINS);
+        assert_eq!((bins[0].start, bins[MAX_BINS - 1].end), (0.0, 10.0));
+        // Widened bins: each value lands in its own bin with only the max in the last
+        assert_eq!(bins.iter().map(|bin| bin.count).sum::<usize>(), 11);
+        assert_eq!(bins.iter().filter(|bin| bin.count > 0).count(), 11);
+        assert_eq!(bins[MAX_BINS - 1].count, 1);
+        assert_eq!(bins[MAX_BINS / 2].count, 1);
+    }
+}
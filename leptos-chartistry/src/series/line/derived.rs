@@ -0,0 +1,125 @@
+use crate::series::{GetY, GetYValue};
+use std::collections::VecDeque;
+
+/// How a line's Y values are derived from the values before it. See [Line::moving_average](super::Line::moving_average).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(super) enum Derive {
+    MovingAverage(usize),
+    Ema(f64),
+    Cumulative,
+    Rate,
+}
+
+/// History of a derived line. Kept by each series while building data.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct DeriveState {
+    // Trailing window of values
+    window: VecDeque<f64>,
+    // Previous (X, value) e.g., the last EMA or running total
+    previous: Option<(f64, f64)>,
+}
+
+/// Y getter that derives its value from the data before it.
+pub(super) struct DerivedY<T> {
+    pub get_y: GetY<T, f64>,
+    pub derive: Derive,
+}
+
+impl<T> GetYValue<T, f64> for DerivedY<T> {
+    fn value(&self, t: &T) -> f64 {
+        self.get_y.value(t)
+    }
+
+    fn cumulative_value(&self, t: &T) -> f64 {
+        self.get_y.cumulative_value(t)
+    }
+
+    fn derive(&self, t: &T, x: f64, state: &mut DeriveState) -> Option<f64> {
+        Some(self.derive.next(x, self.get_y.value(t), state))
+    }
+}
+
+impl Derive {
+    /// Derives the next value at X from the history. Missing values (`NaN`) stay missing and are left out of the history.
+    fn next(self, x: f64, value: f64, state: &mut DeriveState) -> f64 {
+        if value.is_nan() {
+            return f64::NAN;
+        }
+        match self {
+            Derive::MovingAverage(window) => {
+                state.window.push_back(value);
+                if state.window.len() > window.max(1) {
+                    state.window.pop_front();
+                }
+                state.window.iter().sum::<f64>() / state.window.len() as f64
+            }
+            Derive::Ema(alpha) => {
+                let alpha = alpha.clamp(0.0, 1.0);
+                let ema = state
+                    .previous
+                    .map_or(value, |(_, ema)| alpha * value + (1.0 - alpha) * ema);
+                state.previous = Some((x, ema));
+                ema
+            }
+            Derive::Cumulative => {
+                let total = state.previous.map_or(0.0, |(_, total)| total) + value;
+                state.previous = Some((x, total));
+                total
+            }
+            Derive::Rate => {
+                let rate = state.previous.map_or(f64::NAN, |(prev_x, prev)| {
+                    let dx = x - prev_x;
+                    if dx == 0.0 {
+                        f64::NAN
+                    } else {
+                        (value - prev) / dx
+                    }
+                });
+                state.previous = Some((x, value));
+                rate
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn derive(derive: Derive, points: &[(f64, f64)]) -> Vec<f64> {
+        let mut state = DeriveState::default();
+        (points.iter())
+            .map(|&(x, value)| derive.next(x, value, &mut state))
+            .collect()
+    }
+
+    const POINTS: &[(f64, f64)] = &[(0.0, 2.0), (1.0, 4.0), (2.0, 9.0), (4.0, 1.0)];
+
+    #[test]
+    fn test_derive() {
+        assert_eq!(
+            derive(Derive::MovingAverage(2), POINTS),
+            [2.0, 3.0, 6.5, 5.0]
+        );
+        assert_eq!(derive(Derive::Ema(0.5), POINTS), [2.0, 3.0, 6.0, 3.5]);
+        assert_eq!(derive(Derive::Cumulative, POINTS), [2.0, 6.0, 15.0, 16.0]);
+        let rate = derive(Derive::Rate, POINTS);
+        assert!(rate[0].is_nan());
+        assert_eq!(rate[1..], [2.0, 5.0, -4.0]);
+    }
+
+    #[test]
+    fn test_derive_missing() {
+        let points = [(0.0, 2.0), (1.0, f64::NAN), (2.0, 4.0)];
+        let average = derive(Derive::MovingAverage(2), &points);
+        assert!(average[1].is_nan());
+        assert_eq!(average[2], 3.0);
+        // Rate spans the gap
+        assert_eq!(derive(Derive::Rate, &points)[2], 1.0);
+        // Zero window is treated as one
+        assert_eq!(
+            derive(Derive::MovingAverage(0), POINTS),
+            [2.0, 4.0, 9.0, 1.0]
+        );
+    }
+}
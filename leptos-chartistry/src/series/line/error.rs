@@ -0,0 +1,192 @@
+use super::{DeriveState, Fit, UseLine};
+use crate::{
+    series::{Extra, Extras, GetY, GetYValue},
+    state::State,
+};
+use leptos::*;
+use std::rc::Rc;
+
+/// Default fill opacity of an error band.
+pub const ERROR_BAND_OPACITY: f64 = 0.2;
+
+// Whisker cap width relative to the line width
+const WIDTH_TO_CAP: f64 = 6.0;
+
+/// How a line's error bounds are drawn. See [Line::with_error](super::Line::with_error).
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorStyle {
+    /// Translucent band between the lower and upper bounds. Follows the line's interpolation.
+    #[default]
+    Band,
+    /// Whiskers (error bars) at each point.
+    Whiskers,
+}
+
+pub(super) type GetBound<T, Y> = Rc<dyn Fn(&T) -> Y>;
+
+/// Y getter with lower and upper error bounds.
+pub(super) struct ErrorY<T, Y> {
+    pub get_y: GetY<T, Y>,
+    pub lower: GetBound<T, Y>,
+    pub upper: GetBound<T, Y>,
+}
+
+impl<T, Y> GetYValue<T, Y> for ErrorY<T, Y> {
+    fn value(&self, t: &T) -> Y {
+        self.get_y.value(t)
+    }
+
+    fn cumulative_value(&self, t: &T) -> Y {
+        self.get_y.cumulative_value(t)
+    }
+
+    fn extra(&self, t: &T) -> Option<Extra<Y>> {
+        Some(Extra::Error((self.lower)(t), (self.upper)(t)))
+    }
+
+    fn derive(&self, t: &T, x: f64, state: &mut DeriveState) -> Option<Y> {
+        self.get_y.derive(t, x, state)
+    }
+
+    fn fit(&self, data: &[T], xs: &[f64]) -> Option<Fit<Y>> {
+        self.get_y.fit(data, xs)
+    }
+}
+
+/// Splits points into runs without missing (NaN) values.
+fn segments(errors: &[(f64, f64, f64)]) -> Vec<&[(f64, f64, f64)]> {
+    errors
+        .split(|(x, lower, upper)| x.is_nan() || lower.is_nan() || upper.is_nan())
+        .filter(|segment| !segment.is_empty())
+        .collect()
+}
+
+/// SVG (X, lower Y, upper Y) of each point.
+pub(crate) fn svg_errors<X, Y>(
+    state: &State<X, Y>,
+    extras: Signal<Extras>,
+) -> Signal<Vec<(f64, f64, f64)>> {
+    let projection = state.projection;
+    Signal::derive(move || {
+        let proj = projection.get();
+        extras.with(|extras| {
+            (extras.iter())
+                .filter_map(|&((x, _), ref extra)| match *extra {
+                    Extra::Error(lower, upper) => {
+                        let (x, lower) = proj.position_to_svg(x, lower);
+                        let (_, upper) = proj.position_to_svg(0.0, upper);
+                        Some((x, lower, upper))
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        })
+    })
+}
+
+#[component]
+pub fn RenderLineError(
+    line: UseLine,
+    // SVG (X, lower Y, upper Y) of each point
+    errors: Signal<Vec<(f64, f64, f64)>>,
+) -> impl IntoView {
+    let style = line.error_style;
+    let interpolation = line.interpolation;
+
+    let band = move || {
+        errors.with(|errors| {
+            let interpolation = interpolation.get();
+            segments(errors)
+                .into_iter()
+                .map(|segment| {
+                    // Upper bound left to right then lower bound back again
+                    let upper = segment.iter().map(|&(x, _, upper)| (x, upper));
+                    let lower = segment.iter().rev().map(|&(x, lower, _)| (x, lower));
+                    let upper = interpolation.path(&upper.collect::<Vec<_>>());
+                    let lower = interpolation.path(&lower.collect::<Vec<_>>());
+                    let d = format!("{upper}L{} Z", lower.trim_start_matches('M'));
+                    view!(<path d=d />)
+                })
+                .collect_view()
+        })
+    };
+
+    let whiskers = move || {
+        let cap = line.width.get() * WIDTH_TO_CAP / 2.0;
+        errors.with(|errors| {
+            segments(errors)
+                .into_iter()
+                .flatten()
+                .map(|&(x, lower, upper)| {
+                    let d = format!(
+                        "M {x} {lower} V {upper} M {} {upper} h {} M {} {lower} h {}",
+                        x - cap,
+                        cap * 2.0,
+                        x - cap,
+                        cap * 2.0
+                    );
+                    view!(<path d=d />)
+                })
+                .collect_view()
+        })
+    };
+
+    let colour = line.colour;
+    let colour = move || colour.get().to_string();
+    view! {
+        <g class="_chartistry_line_error">
+            {move || match style.get() {
+                ErrorStyle::Band => view! {
+                    <g fill=colour fill-opacity=ERROR_BAND_OPACITY stroke="none">{band}</g>
+                }.into_view(),
+                ErrorStyle::Whiskers => view! {
+                    <g fill="none" stroke=colour stroke-width=line.width>{whiskers}</g>
+                }.into_view(),
+            }}
+        </g>
+    }
+}
+
+impl std::str::FromStr for ErrorStyle {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "band" => Ok(ErrorStyle::Band),
+            "whiskers" => Ok(ErrorStyle::Whiskers),
+            _ => Err("unknown error style"),
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorStyle::Band => write!(f, "Band"),
+            ErrorStyle::Whiskers => write!(f, "Whiskers"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segments() {
+        let nan = f64::NAN;
+        let errors = [
+            (1.0, 0.0, 2.0),
+            (2.0, 1.0, 3.0),
+            (3.0, nan, 4.0),
+            (4.0, 2.0, 5.0),
+        ];
+        assert_eq!(
+            segments(&errors),
+            vec![&errors[0..2], &errors[3..4]] as Vec<&[_]>
+        );
+        assert!(segments(&[]).is_empty());
+        assert!(segments(&[(nan, 1.0, 2.0)]).is_empty());
+    }
+}
@@ -0,0 +1,185 @@
+/// Default dash pattern for [GapPolicy::Dashed] segments.
+pub const GAP_DASH: [f64; 2] = [4.0, 4.0];
+
+/// How a line is drawn across gaps: missing (`f64::NAN`) values and gaps detected by [Line::gap_threshold](super::Line::gap_threshold).
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum GapPolicy {
+    /// Break the line at a gap.
+    #[default]
+    Break,
+    /// Connect the points either side of a gap as if it wasn't there.
+    Connect,
+    /// Break the line and draw a dashed segment across the gap. See [GAP_DASH].
+    Dashed,
+    /// Draw the gap as a flat run at zero. Zero is included in the Y range.
+    Zero,
+}
+
+/// Positions of the line and of dashed segments across gaps.
+pub(super) type Gapped<P> = (P, P);
+
+impl GapPolicy {
+    /// Applies the policy to positions. Returns positions of the line and of dashed segments (separated by `NaN`). Consecutive X positions further apart than `threshold` have missing values inserted at both edges of the gap. `zero_y` is the Y position of zero.
+    pub(super) fn apply(
+        self,
+        positions: &[(f64, f64)],
+        threshold: Option<f64>,
+        zero_y: f64,
+    ) -> Gapped<Vec<(f64, f64)>> {
+        let positions = insert_gaps(positions, threshold);
+        let is_missing = |&(x, y): &(f64, f64)| x.is_nan() || y.is_nan();
+        match self {
+            Self::Break => (positions, vec![]),
+            Self::Connect => {
+                let line = positions.into_iter().filter(|p| !is_missing(p)).collect();
+                (line, vec![])
+            }
+            Self::Zero => {
+                let line = (positions.into_iter())
+                    .map(|(x, y)| if y.is_nan() { (x, zero_y) } else { (x, y) })
+                    .collect();
+                (line, vec![])
+            }
+            Self::Dashed => {
+                // Bridge each run of missing values between two points
+                let mut bridges = vec![];
+                let mut last = None;
+                let mut in_gap = false;
+                for &point in &positions {
+                    if is_missing(&point) {
+                        in_gap = true;
+                        continue;
+                    }
+                    if let (Some(last), true) = (last, in_gap) {
+                        bridges.extend([last, point, (f64::NAN, f64::NAN)]);
+                    }
+                    last = Some(point);
+                    in_gap = false;
+                }
+                (positions, bridges)
+            }
+        }
+    }
+}
+
+/// Inserts missing values at both edges of consecutive X positions further apart than the threshold. With [GapPolicy::Zero] the gap becomes a flat run at zero.
+fn insert_gaps(positions: &[(f64, f64)], threshold: Option<f64>) -> Vec<(f64, f64)> {
+    let Some(threshold) = threshold.filter(|t| t.is_finite()) else {
+        return positions.to_vec();
+    };
+    let mut gapped = Vec::with_capacity(positions.len());
+    let mut prev_x: Option<f64> = None;
+    for &(x, y) in positions {
+        if let Some(prev_x) = prev_x.filter(|_| !x.is_nan()) {
+            if (x - prev_x).abs() > threshold {
+                gapped.extend([(prev_x, f64::NAN), (x, f64::NAN)]);
+            }
+        }
+        if !x.is_nan() {
+            prev_x = Some(x);
+        }
+        gapped.push((x, y));
+    }
+    gapped
+}
+
+impl std::str::FromStr for GapPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "break" => Ok(Self::Break),
+            "connect" => Ok(Self::Connect),
+            "dashed" => Ok(Self::Dashed),
+            "zero" => Ok(Self::Zero),
+            _ => Err(format!("unknown gap policy: `{}`", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for GapPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Break => write!(f, "break"),
+            Self::Connect => write!(f, "connect"),
+            Self::Dashed => write!(f, "dashed"),
+            Self::Zero => write!(f, "zero"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAN: f64 = f64::NAN;
+    const POSITIONS: &[(f64, f64)] = &[(0.0, 1.0), (1.0, NAN), (2.0, 3.0), (10.0, 4.0)];
+
+    // Compares positions with NaNs as equal
+    fn assert_positions(actual: &[(f64, f64)], expected: &[(f64, f64)]) {
+        let fmt = |p: &[(f64, f64)]| format!("{p:?}");
+        assert_eq!(fmt(actual), fmt(expected));
+    }
+
+    #[test]
+    fn test_apply() {
+        let (line, bridges) = GapPolicy::Break.apply(POSITIONS, None, 0.0);
+        assert_positions(&line, POSITIONS);
+        assert!(bridges.is_empty());
+        let (line, _) = GapPolicy::Connect.apply(POSITIONS, None, 0.0);
+        assert_positions(&line, &[(0.0, 1.0), (2.0, 3.0), (10.0, 4.0)]);
+        let (line, _) = GapPolicy::Zero.apply(POSITIONS, None, -5.0);
+        assert_positions(&line, &[(0.0, 1.0), (1.0, -5.0), (2.0, 3.0), (10.0, 4.0)]);
+        let (line, bridges) = GapPolicy::Dashed.apply(POSITIONS, None, 0.0);
+        assert_positions(&line, POSITIONS);
+        assert_positions(&bridges, &[(0.0, 1.0), (2.0, 3.0), (NAN, NAN)]);
+    }
+
+    #[test]
+    fn test_threshold() {
+        let (line, _) = GapPolicy::Break.apply(POSITIONS, Some(5.0), 0.0);
+        assert_positions(
+            &line,
+            &[
+                (0.0, 1.0),
+                (1.0, NAN),
+                (2.0, 3.0),
+                (2.0, NAN),
+                (10.0, NAN),
+                (10.0, 4.0),
+            ],
+        );
+        // Flat at zero across the gap
+        let (line, _) = GapPolicy::Zero.apply(POSITIONS, Some(5.0), -5.0);
+        assert_positions(
+            &line,
+            &[
+                (0.0, 1.0),
+                (1.0, -5.0),
+                (2.0, 3.0),
+                (2.0, -5.0),
+                (10.0, -5.0),
+                (10.0, 4.0),
+            ],
+        );
+        let (_, bridges) = GapPolicy::Dashed.apply(POSITIONS, Some(5.0), 0.0);
+        assert_positions(
+            &bridges,
+            &[
+                (0.0, 1.0),
+                (2.0, 3.0),
+                (NAN, NAN),
+                (2.0, 3.0),
+                (10.0, 4.0),
+                (NAN, NAN),
+            ],
+        );
+        // Missing X doesn't count as a gap
+        let positions = [(0.0, 1.0), (NAN, NAN), (1.0, 2.0)];
+        assert_positions(
+            &insert_gaps(&positions, Some(0.5)),
+            &[(0.0, 1.0), (NAN, NAN), (0.0, NAN), (1.0, NAN), (1.0, 2.0)],
+        );
+    }
+}
@@ -71,6 +71,16 @@ impl Interpolation {
             Self::Monotone => monotone(points),
         }
     }
+
+    /// Returns the path of `points[start..end]` as drawn by the path of all points. Continues from the point before `start`. Points either side are context that shape the path.
+    pub(super) fn path_within(self, points: &[(f64, f64)], start: usize, end: usize) -> String {
+        let from = start.saturating_sub(1);
+        match self {
+            Self::Linear => linear(&points[from..end]),
+            Self::Step(step) => step.path(&points[from..end]),
+            Self::Monotone => monotone_within(points, start, end),
+        }
+    }
 }
 
 fn linear(points: &[(f64, f64)]) -> String {
@@ -135,27 +145,64 @@ impl Step {
         y(i) = (s[i-1].signum() + s[i].signum()) * s[i-1].abs().min(0.5 * p[i].abs())
 */
 fn monotone(points: &[(f64, f64)]) -> String {
-    let mut path = String::with_capacity(points.len());
-    for i in 0..points.len() {
-        let (x_prev, y_prev) = get_or_nan(points, i.checked_sub(1));
+    monotone_within(points, 0, points.len())
+}
+
+/// A monotone path command at a point.
+enum Monotone {
+    // Inbetween segments
+    Gap,
+    // Start of a new segment
+    Move,
+    // End of a segment
+    Line,
+    // Curve with the control point before the point
+    Curve(f64, f64),
+}
+
+fn monotone_cmd(points: &[(f64, f64)], i: usize) -> Monotone {
+    let (x_prev, y_prev) = get_or_nan(points, i.checked_sub(1));
+    let (x, y) = points[i];
+    let (x_next, y_next) = get_or_nan(points, i.checked_add(1));
+    if x.is_nan() || y.is_nan() {
+        Monotone::Gap
+    } else if x_prev.is_nan() || y_prev.is_nan() {
+        Monotone::Move
+    } else if x_next.is_nan() || y_next.is_nan() {
+        Monotone::Line
+    } else {
+        let tangent = tangent(x_prev, x, x_next, y_prev, y, y_next);
+        let dx = (x - x_prev) / 3.0;
+        Monotone::Curve(x - dx, y - dx * tangent)
+    }
+}
+
+fn monotone_within(points: &[(f64, f64)], start: usize, end: usize) -> String {
+    let mut path = String::with_capacity(end - start);
+    // Continue from the previous point. A smooth curve reflects its control point
+    let mut reflect = None;
+    if let Some(prev) = start.checked_sub(1) {
+        let (x, y) = points[prev];
+        match monotone_cmd(points, prev) {
+            Monotone::Gap => {}
+            Monotone::Curve(x_c, y_c) => {
+                path.push_str(&format!("M {x},{y} "));
+                reflect = Some((2.0 * x - x_c, 2.0 * y - y_c));
+            }
+            Monotone::Move | Monotone::Line => path.push_str(&format!("M {x},{y} ")),
+        }
+    }
+    for i in start..end {
         let (x, y) = points[i];
-        let (x_next, y_next) = get_or_nan(points, i.checked_add(1));
         // Path command
-        let cmd = if x.is_nan() || y.is_nan() {
-            // Inbetween segments
-            "".to_string()
-        } else if x_prev.is_nan() || y_prev.is_nan() {
-            // Start of a new segment
-            format!("M {x},{y} ")
-        } else if x_next.is_nan() || y_next.is_nan() {
-            // End of a segment
-            format!("L {x},{y} ")
-        } else {
-            let tangent = tangent(x_prev, x, x_next, y_prev, y, y_next);
-            let dx = (x - x_prev) / 3.0;
-            let x_c = x - dx;
-            let y_c = y - dx * tangent;
-            format!("S {x_c},{y_c} {x},{y} ")
+        let cmd = match (monotone_cmd(points, i), reflect.take()) {
+            (Monotone::Gap, _) => "".to_string(),
+            (Monotone::Move, _) => format!("M {x},{y} "),
+            (Monotone::Line, _) => format!("L {x},{y} "),
+            (Monotone::Curve(x_c, y_c), Some((x_r, y_r))) => {
+                format!("C {x_r},{y_r} {x_c},{y_c} {x},{y} ")
+            }
+            (Monotone::Curve(x_c, y_c), None) => format!("S {x_c},{y_c} {x},{y} "),
         };
         path.push_str(&cmd);
     }
@@ -181,3 +228,29 @@ fn tangent(x_prev: f64, x: f64, x_next: f64, y_prev: f64, y: f64, y_next: f64) -
     // Tangent
     (slope_prev.signum() + slope.signum()) * slope_prev.abs().min(0.5 * para.abs())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POINTS: &[(f64, f64)] = &[(0.0, 0.0), (1.0, 2.0), (2.0, 1.0), (3.0, 3.0), (4.0, 2.0)];
+
+    #[test]
+    fn test_path_within() {
+        assert_eq!(
+            Interpolation::Linear.path_within(POINTS, 2, 4),
+            "M 1 2 L 2 1 L 3 3 "
+        );
+        assert_eq!(
+            Interpolation::Monotone.path_within(POINTS, 0, POINTS.len()),
+            Interpolation::Monotone.path(POINTS)
+        );
+        // Continues the curve before: reflects its control point
+        let Monotone::Curve(x_c, y_c) = monotone_cmd(POINTS, 1) else {
+            panic!("expected a curve");
+        };
+        let path = Interpolation::Monotone.path_within(POINTS, 2, 3);
+        let reflected = format!("M 1,2 C {},{} ", 2.0 - x_c, 4.0 - y_c);
+        assert!(path.starts_with(&reflected), "{path}");
+    }
+}
@@ -147,7 +147,7 @@ pub(super) fn LineMarkers(line: UseLine, positions: Signal<Vec<(f64, f64)>>) ->
 
 /// Renders the marker shape in a square. They should all be similar in size and not just extend to the edge e.g., square is a rotated diamond.
 #[component]
-fn MarkerShape(
+pub(in crate::series) fn MarkerShape(
     shape: MarkerShape,
     x: f64,
     y: f64,
@@ -1,16 +1,34 @@
+mod derived;
+mod error;
+mod gaps;
 mod interpolation;
 mod marker;
+mod segments;
+mod trend;
+pub(crate) use derived::DeriveState;
+pub(crate) use error::svg_errors;
+pub use error::{ErrorStyle, RenderLineError, ERROR_BAND_OPACITY};
+pub use gaps::{GapPolicy, GAP_DASH};
 pub use interpolation::{Interpolation, Step};
 pub use marker::{Marker, MarkerShape};
+pub(crate) use segments::{Segments, Streamed};
+pub(crate) use trend::Fit;
+pub use trend::{Regression, Trend};
 
-use super::{ApplyUseSeries, IntoUseLine, SeriesAcc, UseData, UseY};
+use super::{ApplyUseSeries, DataLabelPlacement, IntoUseLine, SeriesAcc, UseData, UseY};
 use crate::{
     colours::{Colour, DivergingGradient, LinearGradientSvg, SequentialGradient, BERLIN, LIPARI},
+    projection::Projection,
     series::GetYValue,
     ColourScheme, Tick,
 };
+use derived::{Derive, DerivedY};
+use error::{ErrorY, GetBound};
+use gaps::Gapped;
 use leptos::*;
+use segments::RenderSegments;
 use std::rc::Rc;
+use trend::TrendY;
 
 /// Suggested colour scheme for a linear gradient on a line. Uses darker colours for lower values and lighter colours for higher values. Assumes a light background.
 pub const LINEAR_GRADIENT: SequentialGradient = LIPARI;
@@ -42,7 +60,7 @@ pub const DIVERGING_GRADIENT: DivergingGradient = BERLIN;
 /// ```
 /// See this in action with the [legend example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#legend).
 pub struct Line<T, Y> {
-    get_y: Rc<dyn GetYValue<T, Y>>,
+    pub(super) get_y: Rc<dyn GetYValue<T, Y>>,
     /// Name of the line. Used in the legend.
     pub name: RwSignal<String>,
     /// Colour of the line. If not set, the next colour in the series will be used.
@@ -55,15 +73,31 @@ pub struct Line<T, Y> {
     pub interpolation: RwSignal<Interpolation>,
     /// Marker at each point on the line.
     pub marker: Marker,
+    /// Placement of data labels showing the Y value at each point. Default is none.
+    pub data_labels: RwSignal<DataLabelPlacement>,
+    // Lower and upper error bounds
+    pub(super) error: Option<(GetBound<T, Y>, GetBound<T, Y>)>,
+    // Values depend on other data points e.g., a moving average or trend
+    pub(super) derived: bool,
+    /// How error bounds are drawn (if set). See [Line::with_error]. Default is [ErrorStyle::Band].
+    pub error_style: RwSignal<ErrorStyle>,
+    /// How the line is drawn across gaps. Default is [GapPolicy::Break].
+    pub gaps: RwSignal<GapPolicy>,
+    /// Treat consecutive X positions further apart than this as a gap e.g., 300.0 for five minutes between timestamps. Default is `None` (no gaps detected).
+    pub gap_threshold: RwSignal<Option<f64>>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct UseLine {
-    colour: Signal<Colour>,
+    pub(super) colour: Signal<Colour>,
     gradient: RwSignal<Option<ColourScheme>>,
     width: RwSignal<f64>,
     interpolation: RwSignal<Interpolation>,
     marker: Marker,
+    pub(super) data_labels: RwSignal<DataLabelPlacement>,
+    error_style: RwSignal<ErrorStyle>,
+    pub(super) gaps: RwSignal<GapPolicy>,
+    pub(super) gap_threshold: RwSignal<Option<f64>>,
 }
 
 impl<T, Y> Line<T, Y> {
@@ -82,6 +116,12 @@ impl<T, Y> Line<T, Y> {
             width: 1.0.into(),
             interpolation: RwSignal::default(),
             marker: Marker::default(),
+            data_labels: RwSignal::default(),
+            error: None,
+            derived: false,
+            error_style: RwSignal::default(),
+            gaps: RwSignal::default(),
+            gap_threshold: RwSignal::default(),
         }
     }
 
@@ -122,6 +162,132 @@ impl<T, Y> Line<T, Y> {
         self.marker = marker.into();
         self
     }
+
+    /// Set the placement of data labels showing the Y value at each point.
+    pub fn with_data_labels(self, placement: impl Into<DataLabelPlacement>) -> Self {
+        self.data_labels.set(placement.into());
+        self
+    }
+
+    /// Set lower and upper error bounds e.g., p5 and p95 or a forecast interval. Drawn according to [error_style](Self::error_style). The Y range includes the bounds.
+    ///
+    /// Not supported on stacked lines: the bounds are dropped with a warning.
+    pub fn with_error(
+        mut self,
+        lower: impl Fn(&T) -> Y + 'static,
+        upper: impl Fn(&T) -> Y + 'static,
+    ) -> Self {
+        self.error = Some((Rc::new(lower), Rc::new(upper)));
+        self
+    }
+
+    /// Set how error bounds are drawn.
+    pub fn with_error_style(self, style: impl Into<ErrorStyle>) -> Self {
+        self.error_style.set(style.into());
+        self
+    }
+
+    /// Set how the line is drawn across gaps: missing values (`f64::NAN`) and gaps detected by [gap_threshold](Self::gap_threshold).
+    pub fn with_gaps(self, policy: impl Into<GapPolicy>) -> Self {
+        self.gaps.set(policy.into());
+        self
+    }
+
+    /// Set the X distance (in positions) between consecutive points that is treated as a gap. For timestamps this is in seconds.
+    pub fn with_gap_threshold(self, threshold: impl Into<Option<f64>>) -> Self {
+        self.gap_threshold.set(threshold.into());
+        self
+    }
+}
+
+impl<T: 'static> Line<T, f64> {
+    fn derive(mut self, derive: Derive) -> Self {
+        self.get_y = Rc::new(DerivedY {
+            get_y: self.get_y,
+            derive,
+        });
+        self.derived = true;
+        self
+    }
+
+    /// Draw the moving average of the last `window` values instead of each value. Fewer values are averaged at the start.
+    ///
+    /// Like the other derived lines, this draws a new line from the same getter. Add the original line separately to show both and give each a name. Missing values (`NaN`) are left out and stay missing.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use leptos_chartistry::*;
+    /// # struct Latency { at: f64, ms: f64 }
+    /// let series = Series::new(|l: &Latency| l.at)
+    ///     .line(Line::new(|l: &Latency| l.ms).with_name("latency"))
+    ///     .line(Line::new(|l: &Latency| l.ms).moving_average(10).with_name("average"));
+    /// ```
+    pub fn moving_average(self, window: usize) -> Self {
+        self.derive(Derive::MovingAverage(window))
+    }
+
+    /// Draw the exponential moving average (EMA) instead of each value. The smoothing factor `alpha` is clamped to 0.0 and 1.0: higher values follow the data more closely. See [moving_average](Self::moving_average).
+    pub fn ema(self, alpha: f64) -> Self {
+        self.derive(Derive::Ema(alpha))
+    }
+
+    /// Draw the running total instead of each value. See [moving_average](Self::moving_average).
+    pub fn cumulative(self) -> Self {
+        self.derive(Derive::Cumulative)
+    }
+
+    /// Draw the rate of change from the previous value per unit of X position e.g., per second for timestamps. The first value is missing. See [moving_average](Self::moving_average).
+    pub fn rate(self) -> Self {
+        self.derive(Derive::Rate)
+    }
+
+    /// Draw a trend fitted to all values instead of each value. See [Trend] for options such as a confidence band or showing the equation in the legend.
+    ///
+    /// Like derived lines, add the original line separately to show both.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use leptos_chartistry::*;
+    /// # struct Sales { week: f64, total: f64 }
+    /// let series = Series::new(|s: &Sales| s.week)
+    ///     .line(Line::new(|s: &Sales| s.total).with_name("sales"))
+    ///     .line(
+    ///         Line::new(|s: &Sales| s.total)
+    ///             .trend(Trend::linear().with_confidence(0.95).with_r_squared())
+    ///             .with_name("trend"),
+    ///     );
+    /// ```
+    pub fn trend(mut self, trend: impl Into<Trend>) -> Self {
+        self.get_y = Rc::new(TrendY {
+            get_y: self.get_y,
+            trend: trend.into(),
+        });
+        self.derived = true;
+        self
+    }
+}
+
+impl UseLine {
+    /// Applies the gap policy to SVG positions. Returns positions of the line and of dashed segments across gaps.
+    pub(super) fn apply_gaps(
+        &self,
+        positions: Signal<Vec<(f64, f64)>>,
+        projection: Signal<Projection>,
+    ) -> Gapped<Signal<Vec<(f64, f64)>>> {
+        let (gaps, threshold) = (self.gaps, self.gap_threshold);
+        let gapped = create_memo(move |_| {
+            // Projection is linear so the threshold and zero map straight to SVG
+            let proj = projection.get();
+            let (origin_x, zero_y) = proj.position_to_svg(0.0, 0.0);
+            let threshold = (threshold.get())
+                .map(|threshold| (proj.position_to_svg(threshold, 0.0).0 - origin_x).abs());
+            positions.with(|positions| gaps.get().apply(positions, threshold, zero_y))
+        });
+        (
+            Signal::derive(move || gapped.with(|(line, _)| line.clone())),
+            Signal::derive(move || gapped.with(|(_, bridges)| bridges.clone())),
+        )
+    }
 }
 
 impl<T, Y> Clone for Line<T, Y> {
@@ -134,6 +300,12 @@ impl<T, Y> Clone for Line<T, Y> {
             width: self.width,
             interpolation: self.interpolation,
             marker: self.marker.clone(),
+            data_labels: self.data_labels,
+            error: self.error.clone(),
+            derived: self.derived,
+            error_style: self.error_style,
+            gaps: self.gaps,
+            gap_threshold: self.gap_threshold,
         }
     }
 }
@@ -154,14 +326,14 @@ impl<T, Y: Tick, U: Fn(&T) -> Y> GetYValue<T, Y> for U {
     }
 }
 
-impl<T, Y> ApplyUseSeries<T, Y> for Line<T, Y> {
+impl<T: 'static, Y: 'static> ApplyUseSeries<T, Y> for Line<T, Y> {
     fn apply_use_series(self: Rc<Self>, series: &mut SeriesAcc<T, Y>) {
         let colour = series.next_colour();
         _ = series.push_line(colour, (*self).clone());
     }
 }
 
-impl<T, Y> IntoUseLine<T, Y> for Line<T, Y> {
+impl<T: 'static, Y: 'static> IntoUseLine<T, Y> for Line<T, Y> {
     fn into_use_line(self, id: usize, colour: Memo<Colour>) -> (UseY, Rc<dyn GetYValue<T, Y>>) {
         let override_colour = self.colour;
         let colour = Signal::derive(move || override_colour.get().unwrap_or(colour.get()));
@@ -174,9 +346,21 @@ impl<T, Y> IntoUseLine<T, Y> for Line<T, Y> {
                 width: self.width,
                 interpolation: self.interpolation,
                 marker: self.marker.clone(),
+                data_labels: self.data_labels,
+                error_style: self.error_style,
+                gaps: self.gaps,
+                gap_threshold: self.gap_threshold,
             },
         );
-        (line, self.get_y.clone())
+        let get_y = match self.error {
+            Some((lower, upper)) => Rc::new(ErrorY {
+                get_y: self.get_y,
+                lower,
+                upper,
+            }),
+            None => self.get_y,
+        };
+        (line, get_y)
     }
 }
 
@@ -187,8 +371,29 @@ pub fn RenderLine<X: 'static, Y: 'static>(
     data: UseData<X, Y>,
     positions: Signal<Vec<(f64, f64)>>,
     markers: Signal<Vec<(f64, f64)>>,
+    // Dashed segments across gaps separated by NaN
+    #[prop(optional)] bridges: Signal<Vec<(f64, f64)>>,
+    // Draws streamed data in segments
+    #[prop(optional_no_strip)] streamed: Option<Streamed>,
 ) -> impl IntoView {
     let path = move || positions.with(|positions| line.interpolation.get().path(positions));
+    // Segments are only drawn without gap handling or a gradient (each segment would get its own)
+    let (gaps, gap_threshold) = (line.gaps, line.gap_threshold);
+    let segmented = create_memo(move |_| {
+        gaps.get() == GapPolicy::Break
+            && gap_threshold.get().is_none()
+            && line.gradient.get().is_none()
+    });
+    let interpolation = line.interpolation;
+    let render_path = move || match streamed.clone().filter(|_| segmented.get()) {
+        Some(streamed) => view! {
+            <RenderSegments interpolation=interpolation streamed=streamed />
+        }
+        .into_view(),
+        None => view! { <path d=path fill="none" /> }.into_view(),
+    };
+    let bridge_path = move || bridges.with(|bridges| Interpolation::Linear.path(bridges));
+    let dash = GAP_DASH.map(|d| d.to_string()).join(" ");
 
     // Line colour
     let gradient_id = format!("line_{}_gradient", use_y.id);
@@ -226,7 +431,8 @@ pub fn RenderLine<X: 'static, Y: 'static>(
                         range_y=range_y />
                 </Show>
             </defs>
-            <path d=path fill="none" />
+            {render_path}
+            <path d=bridge_path fill="none" stroke-dasharray=dash />
             <marker::LineMarkers line=line positions=markers />
         </g>
     }
@@ -0,0 +1,227 @@
+use super::Interpolation;
+use crate::projection::Projection;
+use leptos::*;
+use std::{collections::HashMap, rc::Rc};
+
+/// Number of data points drawn by each path segment of a streamed line.
+const SEGMENT_LEN: usize = 256;
+
+/// Segments of a streamed line and the projection placing them.
+#[derive(Clone)]
+pub struct Streamed {
+    pub segments: Memo<Segments>,
+    pub projection: Signal<Projection>,
+}
+
+/// A streamed line split into segments. Kept between updates so only changed segments are rebuilt.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Segments {
+    // Generation of the data split with the stream indexes of its first and after its last positions. None if not streamed
+    source: Option<(usize, usize, usize)>,
+    segments: Vec<Rc<Segment>>,
+}
+
+/// Part of a line drawn by its own path.
+#[derive(Clone, Debug)]
+struct Segment {
+    // Stream index of the first point drawn. Stable as data is pushed and dropped
+    key: usize,
+    // Points are relative to this position e.g., the first point drawn
+    origin: (f64, f64),
+    // Includes up to two points before and one after for context
+    points: Vec<(f64, f64)>,
+    // Range of points drawn
+    start: usize,
+    end: usize,
+}
+
+impl PartialEq for Segment {
+    // Compares positions with NaNs as equal
+    fn eq(&self, other: &Self) -> bool {
+        let bits = |&(x, y): &(f64, f64)| (x.to_bits(), y.to_bits());
+        (self.key, self.start, self.end) == (other.key, other.start, other.end)
+            && bits(&self.origin) == bits(&other.origin)
+            && self
+                .points
+                .iter()
+                .map(bits)
+                .eq(other.points.iter().map(bits))
+    }
+}
+
+// Lets shared segments compare by pointer first
+impl Eq for Segment {}
+
+impl Segments {
+    /// Splits positions into segments of [SEGMENT_LEN] points aligned to stream indexes. `stream` is the (generation, stream index of the first position) of the data. Segments of `previous` from the same generation are kept if their points are unchanged: pushing data changes only the last segments and dropping data only the first.
+    pub fn split(
+        previous: Option<&Self>,
+        positions: &[(f64, f64)],
+        stream: Option<(usize, usize)>,
+    ) -> Self {
+        let (generation, offset) = stream.unzip();
+        let offset = offset.unwrap_or_default();
+        let end = offset + positions.len();
+        // Stream indexes of the points a segment depends on
+        let window = |key: usize, offset: usize, end: usize| {
+            (
+                key.saturating_sub(2).max(offset),
+                (key + SEGMENT_LEN + 1).min(end),
+            )
+        };
+        let kept = previous
+            .and_then(|previous| {
+                let (prev_generation, prev_offset, prev_end) = previous.source?;
+                (Some(prev_generation) == generation).then(|| {
+                    (previous.segments.iter())
+                        .filter(|s| {
+                            window(s.key, prev_offset, prev_end) == window(s.key, offset, end)
+                        })
+                        .map(|s| (s.key, s.clone()))
+                        .collect::<HashMap<_, _>>()
+                })
+            })
+            .unwrap_or_default();
+
+        let first = offset - offset % SEGMENT_LEN;
+        let segments = if positions.is_empty() {
+            vec![]
+        } else {
+            (first..end)
+                .step_by(SEGMENT_LEN)
+                .map(|key| {
+                    (kept.get(&key).cloned())
+                        .unwrap_or_else(|| Rc::new(Segment::new(positions, offset, key)))
+                })
+                .collect()
+        };
+        Self {
+            source: generation.map(|generation| (generation, offset, end)),
+            segments,
+        }
+    }
+}
+
+impl Segment {
+    /// Builds the segment starting at stream index `key` from positions starting at stream index `offset`.
+    fn new(positions: &[(f64, f64)], offset: usize, key: usize) -> Self {
+        let end = offset + positions.len();
+        // Indexes of positions drawn
+        let start = key.max(offset) - offset;
+        let stop = (key + SEGMENT_LEN).min(end) - offset;
+        // With context
+        let from = start.saturating_sub(2);
+        let to = (stop + 1).min(positions.len());
+        let points = &positions[from..to];
+        let origin = (points[start - from..].iter())
+            .find(|(x, y)| x.is_finite() && y.is_finite())
+            .copied()
+            .unwrap_or_default();
+        Segment {
+            key,
+            origin,
+            points: (points.iter())
+                .map(|&(x, y)| (x - origin.0, y - origin.1))
+                .collect(),
+            start: start - from,
+            end: stop - from,
+        }
+    }
+}
+
+/// Draws a streamed line in segments. Each segment is drawn in data positions and placed with a transform so its path is only redrawn when its points change, not when the chart's range moves.
+#[component]
+pub(super) fn RenderSegments(
+    interpolation: RwSignal<Interpolation>,
+    streamed: Streamed,
+) -> impl IntoView {
+    let Streamed {
+        segments,
+        projection,
+    } = streamed;
+    let keys = create_memo(move |_| {
+        segments.with(|segments| {
+            (segments.segments.iter())
+                .map(|s| s.key)
+                .collect::<Vec<_>>()
+        })
+    });
+    view! {
+        <For
+            each=move || keys.get()
+            key=|&key| key
+            children=move |key| {
+                let segment = create_memo(move |_| {
+                    segments.with(|segments| (segments.segments.iter()).find(|s| s.key == key).cloned())
+                });
+                let path = move || {
+                    segment.with(|segment| {
+                        segment.as_ref()
+                            .map(|s| interpolation.get().path_within(&s.points, s.start, s.end))
+                            .unwrap_or_default()
+                    })
+                };
+                let transform = move || {
+                    let origin = segment.with(|s| s.as_ref().map(|s| s.origin).unwrap_or_default());
+                    let proj = projection.get();
+                    let (scale_x, scale_y) = proj.scale();
+                    let (x, y) = proj.position_to_svg(origin.0, origin.1);
+                    format!("matrix({scale_x} 0 0 {scale_y} {x} {y})")
+                };
+                view! {
+                    <path d=path transform=transform vector-effect="non-scaling-stroke" fill="none" />
+                }
+            }
+        />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split() {
+        let positions = (0..600)
+            .map(|i| (i as f64, i as f64 * 2.0))
+            .collect::<Vec<_>>();
+        let split = Segments::split(None, &positions, Some((0, 0)));
+        let segments = &split.segments;
+        let keys = segments.iter().map(|s| s.key).collect::<Vec<_>>();
+        assert_eq!(keys, [0, 256, 512]);
+        // Context either side
+        let middle = &segments[1];
+        assert_eq!(
+            (middle.start, middle.end, middle.points.len()),
+            (2, 258, 259)
+        );
+        assert_eq!(middle.origin, (256.0, 512.0));
+        assert_eq!(middle.points[middle.start], (0.0, 0.0));
+
+        // Dropping from the front keeps later segments
+        let dropped = Segments::split(Some(&split), &positions[10..], Some((0, 10)));
+        assert_eq!(dropped.segments[0].key, 0);
+        assert_ne!(dropped.segments[0], segments[0]);
+        assert!(Rc::ptr_eq(&dropped.segments[1], &segments[1]));
+        assert!(Rc::ptr_eq(&dropped.segments[2], &segments[2]));
+        // Pushing changes only the last
+        let pushed_positions = [positions.as_slice(), &[(600.0, 0.0)]].concat();
+        let pushed = Segments::split(Some(&split), &pushed_positions, Some((0, 0)));
+        assert!(Rc::ptr_eq(&pushed.segments[0], &segments[0]));
+        assert!(Rc::ptr_eq(&pushed.segments[1], &segments[1]));
+        assert_ne!(pushed.segments[2], segments[2]);
+        // Same as splitting from scratch
+        assert_eq!(
+            pushed,
+            Segments::split(None, &pushed_positions, Some((0, 0)))
+        );
+
+        // Rebuilt data or data that isn't streamed is split again
+        let rebuilt = Segments::split(Some(&split), &positions, Some((1, 0)));
+        assert!(!Rc::ptr_eq(&rebuilt.segments[1], &segments[1]));
+        let unstreamed = Segments::split(None, &positions, None);
+        let again = Segments::split(Some(&unstreamed), &positions, None);
+        assert!(!Rc::ptr_eq(&again.segments[1], &unstreamed.segments[1]));
+        assert!(Segments::split(None, &[], Some((0, 0))).segments.is_empty());
+    }
+}
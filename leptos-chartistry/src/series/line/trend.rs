@@ -0,0 +1,554 @@
+use crate::series::{GetY, GetYValue};
+use leptos::*;
+
+/// Regression used to fit a [Trend].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum Regression {
+    /// Linear least squares: a straight line.
+    #[default]
+    Linear,
+    /// Least squares polynomial of the given degree. Limited by the number of values.
+    Polynomial(usize),
+    /// Locally weighted (LOESS) linear regression. Each point is fitted to the given fraction of the data nearest to it. Clamped to 0.0 and 1.0. Has no equation or confidence band.
+    Loess(f64),
+}
+
+/// Describes a trend line fitted to a line's values. See [Line::trend](super::Line::trend).
+///
+/// Fitted to the position of each X (see [Tick::position](crate::Tick::position)) so timestamps are fitted to seconds. Missing values (`NaN`) are left out.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Trend {
+    /// Regression used to fit the trend. Default is [Regression::Linear].
+    pub regression: RwSignal<Regression>,
+    /// Confidence level of a band around the trend e.g., 0.95 for 95%. Drawn as an error band. Default is none.
+    pub confidence: RwSignal<Option<f64>>,
+    /// Whether the legend shows the fitted equation. Default is false.
+    pub show_equation: RwSignal<bool>,
+    /// Whether the legend shows the coefficient of determination (R²). Default is false.
+    pub show_r_squared: RwSignal<bool>,
+}
+
+impl Trend {
+    /// Create a new trend using the given regression.
+    pub fn new(regression: impl Into<Regression>) -> Self {
+        Self {
+            regression: create_rw_signal(regression.into()),
+            confidence: RwSignal::default(),
+            show_equation: RwSignal::default(),
+            show_r_squared: RwSignal::default(),
+        }
+    }
+
+    /// Create a new linear least squares trend.
+    pub fn linear() -> Self {
+        Self::new(Regression::Linear)
+    }
+
+    /// Create a new least squares polynomial trend of the given degree.
+    pub fn polynomial(degree: usize) -> Self {
+        Self::new(Regression::Polynomial(degree))
+    }
+
+    /// Create a new LOESS trend using the given fraction of data around each point.
+    pub fn loess(span: f64) -> Self {
+        Self::new(Regression::Loess(span))
+    }
+
+    /// Set the confidence level of a band around the trend e.g., 0.95 for 95%.
+    pub fn with_confidence(self, level: impl Into<Option<f64>>) -> Self {
+        self.confidence.set(level.into());
+        self
+    }
+
+    /// Show the fitted equation in the legend.
+    pub fn with_equation(self) -> Self {
+        self.show_equation.set(true);
+        self
+    }
+
+    /// Show the coefficient of determination (R²) in the legend.
+    pub fn with_r_squared(self) -> Self {
+        self.show_r_squared.set(true);
+        self
+    }
+}
+
+impl Default for Trend {
+    fn default() -> Self {
+        Self::linear()
+    }
+}
+
+impl From<Regression> for Trend {
+    fn from(regression: Regression) -> Self {
+        Self::new(regression)
+    }
+}
+
+/// A series fitted across all of its data e.g., a trend line.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Fit<Y> {
+    /// Fitted value at each datum.
+    pub values: Vec<Y>,
+    /// Lower and upper bounds of each value (if any).
+    pub bands: Option<Vec<(Y, Y)>>,
+    /// Description of the fit for the legend e.g., its equation.
+    pub summary: String,
+}
+
+/// Fitted values, bands (if any), and equation (if any).
+type Fitted = (Vec<f64>, Option<Vec<(f64, f64)>>, Option<String>);
+
+/// Y getter fitting a trend to the line's values.
+pub(super) struct TrendY<T> {
+    pub get_y: GetY<T, f64>,
+    pub trend: Trend,
+}
+
+impl<T> GetYValue<T, f64> for TrendY<T> {
+    fn value(&self, t: &T) -> f64 {
+        self.get_y.value(t)
+    }
+
+    fn cumulative_value(&self, t: &T) -> f64 {
+        self.get_y.cumulative_value(t)
+    }
+
+    fn fit(&self, data: &[T], xs: &[f64]) -> Option<Fit<f64>> {
+        let (at, points): (Vec<_>, Vec<_>) = (xs.iter().zip(data))
+            .map(|(&x, t)| (x, self.get_y.value(t)))
+            .enumerate()
+            .filter(|(_, (x, y))| !x.is_nan() && !y.is_nan())
+            .unzip();
+        Some(self.trend.fit(&points, &at, xs))
+    }
+}
+
+impl Trend {
+    /// Fits the points then evaluates at each X. Each point was taken from the X at its index in `at`.
+    fn fit(&self, points: &[(f64, f64)], at: &[usize], xs: &[f64]) -> Fit<f64> {
+        let (values, bands, equation) = match self.regression.get() {
+            Regression::Linear => self.fit_polynomial(points, xs, 1),
+            Regression::Polynomial(degree) => self.fit_polynomial(points, xs, degree),
+            Regression::Loess(span) => (loess(points, span, xs), None, None),
+        };
+
+        // Describe the fit
+        let mut summary = vec![];
+        if let Some(equation) = equation.filter(|_| self.show_equation.get()) {
+            summary.push(equation);
+        }
+        if self.show_r_squared.get() {
+            // Reuse values fitted at each point's X rather than fitting again
+            let fitted = at.iter().map(|&index| values[index]).collect::<Vec<_>>();
+            summary.push(format!("R² = {:.2}", r_squared(points, &fitted)));
+        }
+        Fit {
+            values,
+            bands,
+            summary: summary.join(", "),
+        }
+    }
+
+    /// Fits a polynomial of the given degree and evaluates at each X.
+    fn fit_polynomial(&self, points: &[(f64, f64)], xs: &[f64], degree: usize) -> Fitted {
+        let Some(fit) = PolyFit::new(points, degree) else {
+            return (vec![f64::NAN; xs.len()], None, None);
+        };
+        let values = xs.iter().map(|&x| fit.value(x)).collect();
+        let bands = self.confidence.get().map(|level| {
+            let z = probit(0.5 + level.clamp(0.0, 1.0) / 2.0);
+            (xs.iter())
+                .map(|&x| {
+                    let (value, error) = (fit.value(x), z * fit.std_error(x));
+                    (value - error, value + error)
+                })
+                .collect()
+        });
+        (values, bands, Some(format_equation(&fit.coefficients())))
+    }
+}
+
+/// Least squares polynomial fit. Fitted to X scaled to -1 to 1 around its mean to keep large positions (e.g., timestamps) stable.
+#[derive(Clone, Debug, PartialEq)]
+struct PolyFit {
+    mean: f64,
+    scale: f64,
+    // Coefficients of scaled X in ascending powers
+    coefficients: Vec<f64>,
+    // Inverse of XᵀX
+    inverse: Vec<Vec<f64>>,
+    // Residual variance
+    variance: f64,
+}
+
+impl PolyFit {
+    fn new(points: &[(f64, f64)], degree: usize) -> Option<Self> {
+        let n = points.len();
+        if n < 2 {
+            return None;
+        }
+        // At most one coefficient per point
+        let terms = degree.clamp(1, n - 1) + 1;
+        let mean = points.iter().map(|&(x, _)| x).sum::<f64>() / n as f64;
+        let scale = (points.iter())
+            .map(|&(x, _)| (x - mean).abs())
+            .fold(0.0, f64::max);
+        let scale = if scale > 0.0 { scale } else { 1.0 };
+
+        // Normal equations: XᵀX and Xᵀy
+        let powers = |x: f64| {
+            let u = (x - mean) / scale;
+            (0..terms).map(move |k| u.powi(k as i32))
+        };
+        let mut xtx = vec![vec![0.0; terms]; terms];
+        let mut xty = vec![0.0; terms];
+        for &(x, y) in points {
+            let row = powers(x).collect::<Vec<_>>();
+            for j in 0..terms {
+                xty[j] += row[j] * y;
+                for k in 0..terms {
+                    xtx[j][k] += row[j] * row[k];
+                }
+            }
+        }
+        let inverse = invert(xtx)?;
+        let coefficients = (inverse.iter())
+            .map(|row| row.iter().zip(&xty).map(|(a, b)| a * b).sum())
+            .collect::<Vec<f64>>();
+
+        let mut fit = Self {
+            mean,
+            scale,
+            coefficients,
+            inverse,
+            variance: 0.0,
+        };
+        let residuals = (points.iter())
+            .map(|&(x, y)| (y - fit.value(x)).powi(2))
+            .sum::<f64>();
+        if n > terms {
+            fit.variance = residuals / (n - terms) as f64;
+        }
+        Some(fit)
+    }
+
+    fn powers(&self, x: f64) -> Vec<f64> {
+        let u = (x - self.mean) / self.scale;
+        (0..self.coefficients.len())
+            .map(|k| u.powi(k as i32))
+            .collect()
+    }
+
+    fn value(&self, x: f64) -> f64 {
+        (self.powers(x).iter())
+            .zip(&self.coefficients)
+            .map(|(p, c)| p * c)
+            .sum()
+    }
+
+    /// Standard error of the fitted value at X.
+    fn std_error(&self, x: f64) -> f64 {
+        let v = self.powers(x);
+        let quad = (self.inverse.iter().zip(&v))
+            .map(|(row, vj)| vj * row.iter().zip(&v).map(|(a, vk)| a * vk).sum::<f64>())
+            .sum::<f64>();
+        (self.variance * quad).max(0.0).sqrt()
+    }
+
+    /// Coefficients of unscaled X in ascending powers.
+    fn coefficients(&self) -> Vec<f64> {
+        // Expand c_k * ((x - mean) / scale)^k
+        let terms = self.coefficients.len();
+        let mut expanded = vec![0.0; terms];
+        for (k, c) in self.coefficients.iter().enumerate() {
+            let c = c / self.scale.powi(k as i32);
+            let mut binomial = 1.0;
+            for (j, term) in expanded.iter_mut().enumerate().take(k + 1) {
+                *term += c * binomial * (-self.mean).powi((k - j) as i32);
+                binomial = binomial * (k - j) as f64 / (j + 1) as f64;
+            }
+        }
+        expanded
+    }
+}
+
+/// Inverts a square matrix using Gauss-Jordan elimination. Returns None if singular.
+fn invert(mut m: Vec<Vec<f64>>) -> Option<Vec<Vec<f64>>> {
+    let n = m.len();
+    let mut inv = (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect::<Vec<Vec<f64>>>();
+    for col in 0..n {
+        // Partial pivot on the largest value
+        let pivot = (col..n).max_by(|&a, &b| m[a][col].abs().total_cmp(&m[b][col].abs()))?;
+        if m[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        m.swap(col, pivot);
+        inv.swap(col, pivot);
+        let p = m[col][col];
+        for j in 0..n {
+            m[col][j] /= p;
+            inv[col][j] /= p;
+        }
+        for row in 0..n {
+            let factor = m[row][col];
+            if row != col && factor != 0.0 {
+                for j in 0..n {
+                    m[row][j] -= factor * m[col][j];
+                    inv[row][j] -= factor * inv[col][j];
+                }
+            }
+        }
+    }
+    Some(inv)
+}
+
+/// Locally weighted linear regression evaluated at each X. Uses tricube weights over the nearest `span` fraction of points.
+fn loess(points: &[(f64, f64)], span: f64, xs: &[f64]) -> Vec<f64> {
+    let n = points.len();
+    if n == 0 {
+        return vec![f64::NAN; xs.len()];
+    }
+    let nearest = ((span.clamp(0.0, 1.0) * n as f64).ceil() as usize).clamp(2.min(n), n);
+    let mut distances = vec![0.0; n];
+    (xs.iter())
+        .map(|&x0| {
+            // Distance to the furthest of the nearest points. Selected in linear time
+            for (distance, &(x, _)) in distances.iter_mut().zip(points) {
+                *distance = (x - x0).abs();
+            }
+            let (_, &mut max, _) = distances.select_nth_unstable_by(nearest - 1, f64::total_cmp);
+            // Weighted sums relative to x0
+            let (mut sw, mut swx, mut swy, mut swxx, mut swxy) = (0.0, 0.0, 0.0, 0.0, 0.0);
+            for &(x, y) in points {
+                let dx = x - x0;
+                let w = if max > 0.0 {
+                    (1.0 - (dx.abs() / max).powi(3)).max(0.0).powi(3)
+                } else if dx == 0.0 {
+                    1.0
+                } else {
+                    0.0
+                };
+                sw += w;
+                swx += w * dx;
+                swy += w * y;
+                swxx += w * dx * dx;
+                swxy += w * dx * y;
+            }
+            if sw == 0.0 {
+                return f64::NAN;
+            }
+            // Intercept at x0 of the weighted line
+            let denom = sw * swxx - swx * swx;
+            if denom.abs() <= f64::EPSILON * sw * swxx {
+                return swy / sw;
+            }
+            let slope = (sw * swxy - swx * swy) / denom;
+            (swy - slope * swx) / sw
+        })
+        .collect()
+}
+
+/// Coefficient of determination of fitted values.
+fn r_squared(points: &[(f64, f64)], fitted: &[f64]) -> f64 {
+    let mean = points.iter().map(|&(_, y)| y).sum::<f64>() / points.len() as f64;
+    let total = points.iter().map(|&(_, y)| (y - mean).powi(2)).sum::<f64>();
+    let residual = (points.iter().zip(fitted))
+        .map(|(&(_, y), f)| (y - f).powi(2))
+        .sum::<f64>();
+    1.0 - residual / total
+}
+
+/// Inverse of the standard normal CDF. Uses Acklam's rational approximation.
+fn probit(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const LOW: f64 = 0.02425;
+    if p <= 0.0 {
+        return f64::NEG_INFINITY;
+    } else if p >= 1.0 {
+        return f64::INFINITY;
+    }
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    if p < LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p > 1.0 - LOW {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
+
+/// Formats a number to three significant figures. Very large or small numbers use scientific notation.
+fn format_number(value: f64) -> String {
+    let abs = value.abs();
+    if abs == 0.0 {
+        return "0".to_string();
+    }
+    if !(1e-3..1e6).contains(&abs) {
+        return format!("{value:.2e}");
+    }
+    let digits = (2 - abs.log10().floor() as i32).max(0) as usize;
+    let value = format!("{value:.digits$}");
+    if value.contains('.') {
+        value
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    } else {
+        value
+    }
+}
+
+/// Formats coefficients (ascending powers) as an equation e.g., "y = 2x + 1".
+fn format_equation(coefficients: &[f64]) -> String {
+    let mut equation = "y =".to_string();
+    let terms = (coefficients.iter().enumerate().rev()).filter(|(_, c)| **c != 0.0);
+    for (i, (power, &c)) in terms.enumerate() {
+        let sign = match (i, c < 0.0) {
+            (0, true) => " -",
+            (0, false) => " ",
+            (_, true) => " - ",
+            (_, false) => " + ",
+        };
+        let x = match power {
+            0 => String::new(),
+            1 => "x".to_string(),
+            2 => "x²".to_string(),
+            3 => "x³".to_string(),
+            _ => format!("x^{power}"),
+        };
+        equation.push_str(&format!("{sign}{}{x}", format_number(c.abs())));
+    }
+    if equation == "y =" {
+        equation.push_str(" 0");
+    }
+    equation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{a} != {b}");
+    }
+
+    #[test]
+    fn test_polynomial() {
+        // y = 2x + 1
+        let points = [(0.0, 1.0), (1.0, 3.0), (2.0, 5.0), (3.0, 7.0)];
+        let fit = PolyFit::new(&points, 1).unwrap();
+        assert_close(fit.value(10.0), 21.0);
+        let coefficients = fit.coefficients();
+        assert_close(coefficients[0], 1.0);
+        assert_close(coefficients[1], 2.0);
+        assert_close(fit.std_error(1.0), 0.0);
+        // y = x² - 1 around timestamps
+        let points = (0..5)
+            .map(|i| {
+                let x = 1.7e9 + i as f64;
+                (x, (i as f64).powi(2) - 1.0)
+            })
+            .collect::<Vec<_>>();
+        let fit = PolyFit::new(&points, 2).unwrap();
+        assert_close(fit.value(1.7e9 + 6.0), 35.0);
+        // Too few points
+        assert!(PolyFit::new(&points[..1], 1).is_none());
+    }
+
+    #[test]
+    fn test_std_error() {
+        let points = [(0.0, 1.0), (1.0, 2.0), (2.0, 5.0), (3.0, 6.0)];
+        let fit = PolyFit::new(&points, 1).unwrap();
+        // Narrowest at the mean
+        assert!(fit.std_error(1.5) < fit.std_error(0.0));
+        assert_close(fit.std_error(0.0), fit.std_error(3.0));
+    }
+
+    #[test]
+    fn test_loess() {
+        // Linear data is fitted exactly
+        let points = [(0.0, 1.0), (1.0, 3.0), (2.0, 5.0), (3.0, 7.0), (4.0, 9.0)];
+        let fitted = loess(&points, 0.6, &[0.0, 2.0, 4.0]);
+        for (f, e) in fitted.into_iter().zip([1.0, 5.0, 9.0]) {
+            assert_close(f, e);
+        }
+        assert!(loess(&[], 0.5, &[1.0])[0].is_nan());
+    }
+
+    #[test]
+    fn test_fit_r_squared() {
+        // Missing values are skipped: R² uses values fitted at the remaining points
+        let xs = [0.0, 1.0, 2.0, 3.0];
+        let points = [(0.0, 1.0), (2.0, 5.0), (3.0, 7.0)];
+        let fit = Trend::linear()
+            .with_r_squared()
+            .fit(&points, &[0, 2, 3], &xs);
+        assert_eq!(fit.summary, "R² = 1.00");
+        assert_close(fit.values[1], 3.0);
+    }
+
+    #[test]
+    fn test_r_squared() {
+        let points = [(0.0, 1.0), (1.0, 3.0), (2.0, 2.0)];
+        assert_close(r_squared(&points, &[1.0, 3.0, 2.0]), 1.0);
+        assert_close(r_squared(&points, &[2.0, 2.0, 2.0]), 0.0);
+    }
+
+    #[test]
+    fn test_probit() {
+        assert_close(probit(0.5), 0.0);
+        assert!((probit(0.975) - 1.959_964).abs() < 1e-5);
+        assert!((probit(0.005) + 2.575_829).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_format_equation() {
+        assert_eq!(format_equation(&[1.0, 2.0]), "y = 2x + 1");
+        assert_eq!(format_equation(&[-3.0, 0.0, -0.5]), "y = -0.5x² - 3");
+        assert_eq!(format_equation(&[0.0, 0.000_012_3]), "y = 1.23e-5x");
+        assert_eq!(
+            format_equation(&[1234.5678, 0.0, 0.0, 0.0, 1.0]),
+            "y = 1x^4 + 1235"
+        );
+        assert_eq!(format_equation(&[0.0]), "y = 0");
+    }
+}
@@ -1,20 +1,47 @@
 mod bar;
+mod box_plot;
+mod buckets;
+mod candlestick;
+mod data_labels;
+mod extra;
+mod heatmap;
+mod histogram;
 mod line;
+mod range_bar;
 mod stack;
 mod use_data;
 mod use_y;
+mod waterfall;
 
 pub use bar::{Bar, BarPlacement, BAR_GAP, BAR_GAP_INNER};
+pub use box_plot::{BoxPlot, BoxSummary, BOX_PLOT_OPACITY};
+pub use buckets::{Aggregate, Bucket, Buckets, MAX_BUCKETS};
+pub use candlestick::{Candlestick, CANDLESTICK_DOWN_COLOUR, CANDLESTICK_UP_COLOUR};
+pub use data_labels::DataLabelPlacement;
+pub(crate) use extra::{Extra, Extras};
+pub use heatmap::{Heatmap, HEATMAP_GRADIENT};
+pub use histogram::{Binning, Histogram, HistogramBin};
 pub use line::{
-    Interpolation, Line, Marker, MarkerShape, Step, DIVERGING_GRADIENT, LINEAR_GRADIENT,
+    ErrorStyle, GapPolicy, Interpolation, Line, Marker, MarkerShape, Regression, Step, Trend,
+    DIVERGING_GRADIENT, ERROR_BAND_OPACITY, GAP_DASH, LINEAR_GRADIENT,
 };
-pub use stack::{Stack, STACK_COLOUR_SCHEME};
-pub use use_data::{RenderData, UseData};
+pub use range_bar::{RangeBar, RANGE_BAR_GAP};
+pub use stack::{Stack, StackOffset, STACK_COLOUR_SCHEME};
+pub use use_data::{Range, RenderData, UseData};
 pub use use_y::{Snippet, UseY};
+pub use waterfall::{
+    Waterfall, WATERFALL_DECREASE_COLOUR, WATERFALL_INCREASE_COLOUR, WATERFALL_SUBTOTAL_COLOUR,
+};
 
-use crate::colours::{Colour, ColourScheme};
+use crate::{
+    colours::{Colour, ColourScheme},
+    Tick,
+};
 use leptos::signal_prelude::*;
-use std::rc::Rc;
+use line::{DeriveState, Fit};
+use std::{collections::HashMap, rc::Rc};
+use use_data::Data;
+use waterfall::WaterfallStep;
 
 /// Arbitrary colours for a brighter palette than BATLOW
 pub const SERIES_COLOUR_SCHEME: [Colour; 10] = [
@@ -36,6 +63,22 @@ type GetY<T, Y> = Rc<dyn GetYValue<T, Y>>;
 trait GetYValue<T, Y> {
     fn value(&self, t: &T) -> Y;
     fn cumulative_value(&self, t: &T) -> Y;
+    /// Values beyond the Y value drawn by the series (if any) e.g., error bounds.
+    fn extra(&self, _t: &T) -> Option<Extra<Y>> {
+        None
+    }
+    /// Value derived from the data before it at X position (if any) e.g., a moving average.
+    fn derive(&self, _t: &T, _x: f64, _state: &mut DeriveState) -> Option<Y> {
+        None
+    }
+    /// Values fitted across all data at each X position (if any) e.g., a trend line.
+    fn fit(&self, _data: &[T], _xs: &[f64]) -> Option<Fit<Y>> {
+        None
+    }
+    /// Waterfall bar given the running total of the previous data (if any).
+    fn running_total(&self, _t: &T, _previous: Option<&Y>) -> Option<WaterfallStep<Y>> {
+        None
+    }
 }
 
 /// Describes how to render a series of data. A series is a collection of lines, bars, etc. that share the same X and Y axes.
@@ -103,13 +146,42 @@ trait GetYValue<T, Y> {
 ///
 /// This would render the lines on top of each other to show the total traffic. Check this out on the [stacked line chart example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#stacked-line-chart).
 ///
+/// ### Unsorted data and other sources
+///
+/// Data is expected in X order. If it isn't, set [sort_by_x](Self::sort_by_x) to sort by X before drawing.
+///
+/// Series sampled at different X values (e.g., two metrics with different intervals) can be drawn together without joining them. [Attach](Self::attach) a series with its own data and X getter:
+///
+/// ```rust
+/// # use chrono::prelude::*;
+/// # use leptos::*;
+/// # use leptos_chartistry::*;
+/// # struct Rate { interval: DateTime<Utc>, in_octets: f64 }
+/// # struct Ping { at: DateTime<Utc>, millis: f64 }
+/// # let pings: Signal<Vec<Ping>> = Signal::default();
+/// let series = Series::new(|r: &Rate| r.interval)
+///     .line(Line::new(|r: &Rate| r.in_octets).with_name("Rx"))
+///     .attach(
+///         pings,
+///         Series::new(|p: &Ping| p.at).line(Line::new(|p: &Ping| p.millis).with_name("ping")),
+///     );
+/// ```
+///
+/// The tooltip then shows each series' value nearest to the cursor on its own X values.
+///
 /// ## Other options
 ///
 /// Finally, like most other components, you can control aspects such as the colour scheme and data ranges of X and Y.
 #[derive(Clone)]
 pub struct Series<T: 'static, X: 'static, Y: 'static> {
     get_x: GetX<T, X>,
+    // Extra X values covered by data e.g., the end of range bars
+    get_x_ends: Vec<GetX<T, X>>,
     series: Vec<Rc<dyn ApplyUseSeries<T, Y>>>,
+    // Series from other data sources
+    attached: Vec<Rc<dyn AttachSeries<T, X, Y>>>,
+    /// Sort data by X before drawing. Use when data isn't in X order. Default is false.
+    pub sort_by_x: RwSignal<bool>,
     /// Optional minimum X value. Extends the lower bound of the X axis if set.
     pub min_x: RwSignal<Option<X>>,
     /// Optional maximum X value. Extends the upper bound of the X axis if set.
@@ -126,6 +198,23 @@ trait ApplyUseSeries<T, Y> {
     fn apply_use_series(self: Rc<Self>, _: &mut SeriesAcc<T, Y>);
 }
 
+trait AttachSeries<T, X, Y> {
+    /// Adds lines continuing IDs and colours from `acc`. Returns them with their data on its own X grid, followed by series attached to it.
+    fn attach(&self, acc: &mut SeriesAcc<T, Y>) -> Vec<AttachedData<X, Y>>;
+}
+
+/// Lines of an attached series with their data.
+type AttachedData<X, Y> = (Vec<UseY>, Memo<Data<X, Y>>);
+
+/// Lines with their Y getters, and attached series.
+type UseLines<T, X, Y> = (Vec<(UseY, GetY<T, Y>)>, Vec<AttachedData<X, Y>>);
+
+/// A series with its own data source.
+struct Attached<U: 'static, X: 'static, Y: 'static> {
+    data: Signal<Vec<U>>,
+    series: Series<U, X, Y>,
+}
+
 trait IntoUseLine<T, Y> {
     fn into_use_line(self, id: usize, colour: Memo<Colour>) -> (UseY, GetY<T, Y>);
 }
@@ -134,6 +223,18 @@ trait IntoUseBar<T, Y> {
     fn into_use_bar(self, id: usize, group_id: usize, colour: Memo<Colour>) -> (UseY, GetY<T, Y>);
 }
 
+trait IntoUseRangeBar<T, Y> {
+    fn into_use_range_bar(self, id: usize, colour: Memo<Colour>) -> (UseY, GetY<T, Y>);
+}
+
+trait IntoUseCandlestick<T, Y> {
+    fn into_use_candlestick(self, id: usize) -> (UseY, GetY<T, Y>);
+}
+
+trait IntoUseHeatmap<T, Y> {
+    fn into_use_heatmap(self, id: usize) -> (UseY, GetY<T, Y>);
+}
+
 struct SeriesAcc<T, Y> {
     colour_id: usize,
     colours: RwSignal<ColourScheme>,
@@ -151,6 +252,9 @@ impl<T, X, Y> Series<T, X, Y> {
     pub fn new(get_x: impl Fn(&T) -> X + 'static) -> Self {
         Self {
             get_x: Rc::new(get_x),
+            get_x_ends: Vec::new(),
+            attached: Vec::new(),
+            sort_by_x: RwSignal::default(),
             min_x: RwSignal::default(),
             max_x: RwSignal::default(),
             min_y: RwSignal::default(),
@@ -166,6 +270,12 @@ impl<T, X, Y> Series<T, X, Y> {
         self
     }
 
+    /// Set whether data is sorted by X before drawing. Use when data isn't in X order.
+    pub fn with_sort_by_x(self, sort_by_x: bool) -> Self {
+        self.sort_by_x.set(sort_by_x);
+        self
+    }
+
     /// Set the minimum X value. Extends the lower bound of the X axis if set.
     pub fn with_min_x(self, max_x: impl Into<Option<X>>) -> Self {
         self.min_x.set(max_x.into());
@@ -228,6 +338,53 @@ impl<T, X, Y> Series<T, X, Y> {
         self
     }
 
+    /// Adds a box plot to the series. See [BoxPlot] for more details.
+    pub fn box_plot(mut self, box_plot: BoxPlot<T, Y>) -> Self {
+        self.series.push(Rc::new(box_plot));
+        self
+    }
+
+    /// Adds a heatmap to the series. See [Heatmap] for more details.
+    pub fn heatmap(mut self, heatmap: Heatmap<T, Y>) -> Self {
+        self.series.push(Rc::new(heatmap));
+        self
+    }
+
+    /// Adds a candlestick (OHLC) series. See [Candlestick] for more details.
+    pub fn candlestick(mut self, candlestick: Candlestick<T, Y>) -> Self {
+        self.series.push(Rc::new(candlestick));
+        self
+    }
+
+    /// Adds range bars (horizontal spans from X to an end X) to the series. See [RangeBar] for more details.
+    pub fn range_bar(mut self, range_bar: RangeBar<T, X, Y>) -> Self
+    where
+        X: Tick,
+    {
+        self.get_x_ends.push(range_bar.get_end.clone());
+        self.series.push(Rc::new(range_bar));
+        self
+    }
+
+    /// Attaches a series drawn from another data source with its own X values e.g., a metric sampled at a different interval. Its lines share the axes, colour scheme, and legend of this series but its X range, Y range, and colour scheme are ignored.
+    ///
+    /// Series attached to the attached series are drawn too, each on its own X values.
+    ///
+    /// Keyboard navigation steps through this series' data only.
+    pub fn attach<U: 'static>(
+        mut self,
+        data: impl Into<Signal<Vec<U>>>,
+        series: Series<U, X, Y>,
+    ) -> Self
+    where
+        X: Tick,
+        Y: Tick,
+    {
+        let data = data.into();
+        self.attached.push(Rc::new(Attached { data, series }));
+        self
+    }
+
     /// Gets the current size of the series (number of lines and stacks).
     pub fn len(&self) -> usize {
         self.series.len()
@@ -238,21 +395,36 @@ impl<T, X, Y> Series<T, X, Y> {
         self.series.is_empty()
     }
 
-    fn to_use_lines(&self) -> Vec<(UseY, GetY<T, Y>)> {
+    fn to_use_lines(&self) -> UseLines<T, X, Y> {
         let mut series = SeriesAcc::new(self.colours);
         for seq in self.series.clone() {
             seq.apply_use_series(&mut series);
         }
-        series.lines
+        let attached = (self.attached.iter())
+            .flat_map(|attached| attached.attach(&mut series))
+            .collect();
+        (series.lines, attached)
     }
 }
 
 impl<T, X, Y: std::ops::Add<Output = Y>> Series<T, X, Y> {
     /// Adds a stack to the series. See [Stack] for more details.
-    pub fn stack(mut self, stack: impl Into<Stack<T, Y>>) -> Self {
+    pub fn stack(mut self, stack: impl Into<Stack<T, Y>>) -> Self
+    where
+        Y: From<f64> + Tick,
+    {
         self.series.push(Rc::new(stack.into()));
         self
     }
+
+    /// Adds a waterfall to the series. See [Waterfall] for more details.
+    pub fn waterfall(mut self, waterfall: Waterfall<T, Y>) -> Self
+    where
+        Y: Clone,
+    {
+        self.series.push(Rc::new(waterfall));
+        self
+    }
 }
 
 impl<T, Y> SeriesAcc<T, Y> {
@@ -266,6 +438,24 @@ impl<T, Y> SeriesAcc<T, Y> {
         }
     }
 
+    /// Creates an empty accumulator for another data type continuing our IDs and colours.
+    fn hand_over<U>(&self) -> SeriesAcc<U, Y> {
+        SeriesAcc {
+            colour_id: self.colour_id,
+            colours: self.colours,
+            next_id: self.next_id,
+            next_group_id: self.next_group_id,
+            lines: Vec::new(),
+        }
+    }
+
+    /// Continues IDs and colours from a handed over accumulator.
+    fn take_back<U>(&mut self, other: &SeriesAcc<U, Y>) {
+        self.colour_id = other.colour_id;
+        self.next_id = other.next_id;
+        self.next_group_id = other.next_group_id;
+    }
+
     fn next_colour(&mut self) -> Memo<Colour> {
         let id = self.colour_id;
         self.colour_id += 1;
@@ -294,4 +484,64 @@ impl<T, Y> SeriesAcc<T, Y> {
         self.lines.push((bar, get_y.clone()));
         get_y
     }
+
+    fn push_range_bar(
+        &mut self,
+        colour: Memo<Colour>,
+        range_bar: impl IntoUseRangeBar<T, Y>,
+    ) -> GetY<T, Y> {
+        let id = self.next_id;
+        self.next_id += 1;
+        let (range_bar, get_y) = range_bar.into_use_range_bar(id, colour);
+        self.lines.push((range_bar, get_y.clone()));
+        get_y
+    }
+
+    fn push_candlestick(&mut self, candlestick: impl IntoUseCandlestick<T, Y>) -> GetY<T, Y> {
+        let id = self.next_id;
+        self.next_id += 1;
+        let (candlestick, get_y) = candlestick.into_use_candlestick(id);
+        self.lines.push((candlestick, get_y.clone()));
+        get_y
+    }
+
+    fn push_heatmap(&mut self, heatmap: impl IntoUseHeatmap<T, Y>) -> GetY<T, Y> {
+        let id = self.next_id;
+        self.next_id += 1;
+        let (heatmap, get_y) = heatmap.into_use_heatmap(id);
+        self.lines.push((heatmap, get_y.clone()));
+        get_y
+    }
+}
+
+impl<T, U, X: Tick, Y: Tick> AttachSeries<T, X, Y> for Attached<U, X, Y> {
+    fn attach(&self, acc: &mut SeriesAcc<T, Y>) -> Vec<AttachedData<X, Y>> {
+        let mut other = acc.hand_over::<U>();
+        for seq in self.series.series.clone() {
+            seq.apply_use_series(&mut other);
+        }
+        // Series attached to this one are attached alongside it
+        let nested = (self.series.attached.iter())
+            .flat_map(|attached| attached.attach(&mut other))
+            .collect::<Vec<_>>();
+        acc.take_back(&other);
+
+        let (use_ys, get_ys): (Vec<_>, Vec<_>) = other.lines.into_iter().unzip();
+        let get_ys = (use_ys.iter().zip(get_ys))
+            .map(|(use_y, get_y)| (use_y.id, get_y))
+            .collect::<HashMap<_, _>>();
+        let (get_x, get_x_ends) = (self.series.get_x.clone(), self.series.get_x_ends.clone());
+        let (data, sort_by_x) = (self.data, self.series.sort_by_x);
+        let data = create_memo(move |_| {
+            data.with(|data| {
+                let mut built = Data::new(get_x.clone(), get_ys.clone(), data, sort_by_x.get());
+                for get_x_end in &get_x_ends {
+                    data.iter()
+                        .for_each(|datum| built.include_x(&get_x_end(datum)));
+                }
+                built
+            })
+        });
+        std::iter::once((use_ys, data)).chain(nested).collect()
+    }
 }
@@ -0,0 +1,280 @@
+use super::{ApplyUseSeries, Extra, Extras, GetYValue, IntoUseRangeBar, SeriesAcc, UseY};
+use crate::{bounds::Bounds, state::State, Colour, Tick};
+use leptos::*;
+use std::rc::Rc;
+
+/// Default gap ratio between range bars in neighbouring lanes.
+pub const RANGE_BAR_GAP: f64 = 0.2;
+
+type GetLabel<T> = Rc<dyn Fn(&T) -> String>;
+
+/// Draws range bars: horizontal spans from a start to an end X on a lane (Y). Also known as a Gantt chart.
+///
+/// Each datum (`T`) is one span. It starts at the series' X getter, ends at `get_end`, and sits on the lane given by `get_lane`. Lanes are drawn as tall as the smallest gap between them. Use whole numbers for lanes and name them on an axis with [TickLabels::lanes](crate::TickLabels::lanes). Spans may have a label (see [with_label](Self::with_label)) which is drawn inside the span when it fits and shown on hover.
+///
+/// Suits job schedules, CI pipeline timelines, on-call rotations, and so on. Like the rest of the series, data is expected in X (start) order.
+///
+/// # Example
+/// ```rust
+/// # use chrono::prelude::*;
+/// # use leptos_chartistry::*;
+/// # struct Job { start: DateTime<Utc>, end: DateTime<Utc>, runner: f64, name: String }
+/// let series = Series::new(|j: &Job| j.start)
+///     .range_bar(
+///         RangeBar::new(|j: &Job| j.end, |j: &Job| j.runner)
+///             .with_label(|j: &Job| j.name.clone())
+///             .with_name("jobs"),
+///     );
+/// let lanes = TickLabels::lanes(["runner-1", "runner-2"]);
+/// ```
+#[non_exhaustive]
+pub struct RangeBar<T, X, Y> {
+    pub(super) get_end: Rc<dyn Fn(&T) -> X>,
+    get_lane: Rc<dyn Fn(&T) -> Y>,
+    get_label: Option<GetLabel<T>>,
+    /// Name of the range bars. Used in the legend and tooltip.
+    pub name: RwSignal<String>,
+    /// Colour of the range bars. If not set, the next colour in the series will be used. Default is `None`.
+    pub colour: RwSignal<Option<Colour>>,
+    /// Gap between bars in neighbouring lanes. Clamped to 0.0 and 1.0. Defaults to [RANGE_BAR_GAP].
+    ///
+    /// The gap is the ratio of the lane height. For example a lane 20px tall with a gap of 0.2 (20%) leaves a 16px bar with 2px above and below.
+    pub gap: RwSignal<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseRangeBar {
+    pub(super) colour: Signal<Colour>,
+    gap: RwSignal<f64>,
+}
+
+impl<T, X, Y> RangeBar<T, X, Y> {
+    /// Create a new range bar. `get_end` returns the end X of a span and `get_lane` its lane.
+    pub fn new(get_end: impl Fn(&T) -> X + 'static, get_lane: impl Fn(&T) -> Y + 'static) -> Self
+    where
+        X: Tick,
+        Y: Tick,
+    {
+        Self {
+            get_end: Rc::new(get_end),
+            get_lane: Rc::new(get_lane),
+            get_label: None,
+            name: RwSignal::default(),
+            colour: RwSignal::default(),
+            gap: create_rw_signal(RANGE_BAR_GAP),
+        }
+    }
+
+    /// Set a label for each span. Drawn inside the span when it fits.
+    pub fn with_label(mut self, get_label: impl Fn(&T) -> String + 'static) -> Self {
+        self.get_label = Some(Rc::new(get_label));
+        self
+    }
+
+    /// Set the name of the range bars. Used in the legend.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.name.set(name.into());
+        self
+    }
+
+    /// Set the colour of the range bars. If not set, the next colour in the series will be used.
+    pub fn with_colour(self, colour: impl Into<Option<Colour>>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+
+    /// Set the gap between bars in neighbouring lanes. Clamped to 0.0 and 1.0. Defaults to [RANGE_BAR_GAP].
+    pub fn with_gap(self, gap: f64) -> Self {
+        self.gap.set(gap);
+        self
+    }
+}
+
+impl<T, X, Y> Clone for RangeBar<T, X, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            get_end: self.get_end.clone(),
+            get_lane: self.get_lane.clone(),
+            get_label: self.get_label.clone(),
+            name: self.name,
+            colour: self.colour,
+            gap: self.gap,
+        }
+    }
+}
+
+/// Y getter using the lane with the span's end and label alongside.
+struct RangeY<T, X, Y> {
+    get_end: Rc<dyn Fn(&T) -> X>,
+    get_lane: Rc<dyn Fn(&T) -> Y>,
+    get_label: Option<GetLabel<T>>,
+}
+
+impl<T, X: Tick, Y> GetYValue<T, Y> for RangeY<T, X, Y> {
+    fn value(&self, t: &T) -> Y {
+        (self.get_lane)(t)
+    }
+
+    fn cumulative_value(&self, t: &T) -> Y {
+        (self.get_lane)(t)
+    }
+
+    fn extra(&self, t: &T) -> Option<Extra<Y>> {
+        let label = (self.get_label.as_ref()).map_or_else(String::new, |get_label| get_label(t));
+        Some(Extra::Span((self.get_end)(t).position(), label))
+    }
+}
+
+impl<T: 'static, X: Tick, Y: 'static> ApplyUseSeries<T, Y> for RangeBar<T, X, Y> {
+    fn apply_use_series(self: Rc<Self>, series: &mut SeriesAcc<T, Y>) {
+        let colour = series.next_colour();
+        _ = series.push_range_bar(colour, (*self).clone());
+    }
+}
+
+impl<T: 'static, X: Tick, Y: 'static> IntoUseRangeBar<T, Y> for RangeBar<T, X, Y> {
+    fn into_use_range_bar(
+        self,
+        id: usize,
+        colour: Memo<Colour>,
+    ) -> (UseY, Rc<dyn GetYValue<T, Y>>) {
+        let override_colour = self.colour;
+        let colour = Signal::derive(move || override_colour.get().unwrap_or(colour.get()));
+        let range_bar = UseY::new_range_bar(
+            id,
+            self.name,
+            UseRangeBar {
+                colour,
+                gap: self.gap,
+            },
+        );
+        let get_y = Rc::new(RangeY {
+            get_end: self.get_end,
+            get_lane: self.get_lane,
+            get_label: self.get_label,
+        });
+        (range_bar, get_y)
+    }
+}
+
+/// Shrinks a lane to the bar inside it by the gap ratio.
+fn span_rect(lane: Bounds, gap: f64) -> Bounds {
+    let inset = lane.height() * gap.clamp(0.0, 1.0) / 2.0;
+    lane.shrink(inset, 0.0, inset, 0.0)
+}
+
+/// Whether a label fits inside a bar with half a character of padding either side.
+fn label_fits(label: &str, bar: Bounds, font_height: f64, font_width: f64) -> bool {
+    let width = (label.chars().count() as f64 + 1.0) * font_width;
+    !label.is_empty() && width <= bar.width() && font_height <= bar.height()
+}
+
+/// SVG bounds of each span's lane with its label.
+pub(super) fn svg_spans<X, Y>(
+    state: &State<X, Y>,
+    extras: Signal<Extras>,
+) -> Signal<Vec<(Bounds, String)>> {
+    let (projection, lane_height) = (state.projection, state.pre.data.lane_height);
+    Signal::derive(move || {
+        let proj = projection.get();
+        let half = lane_height.get().unwrap_or_default() / 2.0;
+        extras.with(|extras| {
+            (extras.iter())
+                .filter_map(|&((start, y), ref extra)| match *extra {
+                    Extra::Span(end, ref label) => Some((start, end, y, label)),
+                    _ => None,
+                })
+                .filter(|&(start, end, y, _)| [start, end, y].iter().all(|v| v.is_finite()))
+                .map(|(start, end, y, label)| {
+                    let (start, top) = proj.position_to_svg(start, y + half);
+                    let (end, bottom) = proj.position_to_svg(end, y - half);
+                    let lane = Bounds::from_points(start.min(end), top, start.max(end), bottom);
+                    (lane, label.clone())
+                })
+                .collect::<Vec<_>>()
+        })
+    })
+}
+
+#[component]
+pub fn RenderRangeBar<X: 'static, Y: 'static>(
+    range_bar: UseRangeBar,
+    state: State<X, Y>,
+    // SVG bounds of each span's lane with its label
+    spans: Signal<Vec<(Bounds, String)>>,
+) -> impl IntoView {
+    let font_height = state.pre.font_height;
+    let font_width = state.pre.font_width;
+    let gap = range_bar.gap;
+    let spans = move || {
+        let (font_height, font_width) = (font_height.get(), font_width.get());
+        let gap = gap.get();
+        spans.with(|spans| {
+            spans
+                .iter()
+                .map(|(lane, label)| {
+                    let bar = span_rect(*lane, gap);
+                    let text = label_fits(label, bar, font_height, font_width).then(|| {
+                        view! {
+                            <text
+                                x=bar.left_x() + font_width / 2.0
+                                y=bar.centre_y()
+                                fill="white"
+                                dominant-baseline="middle">
+                                {label.clone()}
+                            </text>
+                        }
+                    });
+                    view! {
+                        <rect
+                            x=bar.left_x()
+                            y=bar.top_y()
+                            width=bar.width()
+                            height=bar.height()>
+                            <title>{label.clone()}</title>
+                        </rect>
+                        {text}
+                    }
+                })
+                .collect_view()
+        })
+    };
+    view! {
+        <g
+            class="_chartistry_range_bar"
+            fill=move || range_bar.colour.get().to_string()
+            font-family="monospace"
+            font-size=move || format!("{}px", font_height.get())>
+            {spans}
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_span_rect() {
+        let lane = Bounds::from_points(10.0, 20.0, 50.0, 40.0);
+        assert_eq!(
+            span_rect(lane, 0.2),
+            Bounds::from_points(10.0, 22.0, 50.0, 38.0)
+        );
+        assert_eq!(span_rect(lane, 0.0), lane);
+        // Clamped
+        assert_eq!(
+            span_rect(lane, 3.0),
+            Bounds::from_points(10.0, 30.0, 50.0, 30.0)
+        );
+    }
+
+    #[test]
+    fn test_label_fits() {
+        let bar = Bounds::from_points(0.0, 0.0, 40.0, 12.0);
+        assert!(label_fits("lint", bar, 10.0, 8.0));
+        assert!(!label_fits("build", bar, 10.0, 8.0));
+        assert!(!label_fits("lint", bar, 14.0, 8.0));
+        assert!(!label_fits("", bar, 10.0, 8.0));
+    }
+}
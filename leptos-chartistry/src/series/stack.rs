@@ -1,9 +1,10 @@
-use super::{ApplyUseSeries, GetYValue, IntoUseLine, SeriesAcc, UseY};
+use super::{ApplyUseSeries, Extra, GetYValue, IntoUseLine, SeriesAcc, UseY};
 use crate::{
     colours::{Colour, ColourScheme, BATLOW},
-    Line,
+    Line, Tick,
 };
 use leptos::signal_prelude::*;
+use std::cell::RefCell;
 use std::ops::Add;
 use std::rc::Rc;
 
@@ -12,6 +13,8 @@ pub const STACK_COLOUR_SCHEME: [Colour; 10] = BATLOW;
 
 /// Draws a stack of lines on top of each other.
 ///
+/// By default lines are stacked from zero with negative values subtracting from the running total. Use [with_offset](Self::with_offset) to instead stack negative values below zero, normalise each X to 100%, or centre the stack for a streamgraph.
+///
 /// # Example
 /// ```rust
 /// # use leptos_chartistry::*;
@@ -26,6 +29,25 @@ pub struct Stack<T, Y> {
     lines: Vec<Line<T, Y>>,
     /// Colour scheme for the stack. Interpolates colours across the whole scheme.
     pub colours: RwSignal<ColourScheme>,
+    /// Where the stack starts at each X. Default is [StackOffset::Zero].
+    pub offset: RwSignal<StackOffset>,
+}
+
+/// How a [Stack] is offset at each X.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum StackOffset {
+    /// Lines are stacked from zero.
+    #[default]
+    Zero,
+    /// Lines are stacked from zero and normalised so the top line is 100. The tooltip shows each line's value with its share of the total. Also known as a 100% stack.
+    Percent,
+    /// Positive values are stacked upward from zero and negative values downward. Useful when values can be negative e.g., cash flows. Only applies to stacked lines: bars aren't stacked.
+    Diverging,
+    /// The stack is centred on zero. Also known as a silhouette or ThemeRiver.
+    Silhouette,
+    /// The stack is offset to reduce the change in slope (wiggle) of the lines. Used by streamgraphs. See Byron and Wattenberg's "Stacked Graphs – Geometry & Aesthetics".
+    Wiggle,
 }
 
 impl<T, Y> Stack<T, Y> {
@@ -35,8 +57,24 @@ impl<T, Y> Stack<T, Y> {
     }
 
     /// Adds a line to the stack.
+    ///
+    /// Lines are stacked by their values so derived lines (e.g., [moving averages](Line::moving_average)) and [trends](Line::trend) can't be stacked. They're left out with a warning. [Error bounds](Line::with_error) are dropped with a warning.
     pub fn line(mut self, line: impl Into<Line<T, Y>>) -> Self {
-        self.lines.push(line.into());
+        let mut line = line.into();
+        if line.derived {
+            log::warn!(
+                "stacks can't include derived lines: leaving out {:?}",
+                line.name.get_untracked()
+            );
+            return self;
+        }
+        if line.error.take().is_some() {
+            log::warn!(
+                "stacks can't include error bounds: dropping them from {:?}",
+                line.name.get_untracked()
+            );
+        }
+        self.lines.push(line);
         self
     }
 
@@ -50,6 +88,12 @@ impl<T, Y> Stack<T, Y> {
         self.lines.is_empty()
     }
 
+    /// Sets where the stack starts at each X. See [StackOffset] for details.
+    pub fn with_offset(self, offset: impl Into<StackOffset>) -> Self {
+        self.offset.set(offset.into());
+        self
+    }
+
     /// Sets the colour scheme for the stack.
     pub fn with_colours<Opt>(self, colours: impl Into<ColourScheme>) -> Self {
         self.colours.set(colours.into());
@@ -62,6 +106,7 @@ impl<T, Y> Default for Stack<T, Y> {
         Self {
             lines: Vec::new(),
             colours: ColourScheme::from(STACK_COLOUR_SCHEME).invert().into(),
+            offset: RwSignal::default(),
         }
     }
 }
@@ -76,17 +121,21 @@ impl<T, Y, I: IntoIterator<Item = Line<T, Y>>> From<I> for Stack<T, Y> {
     }
 }
 
-impl<T: 'static, Y: std::ops::Add<Output = Y> + 'static> ApplyUseSeries<T, Y> for Stack<T, Y> {
+impl<T: 'static, Y> ApplyUseSeries<T, Y> for Stack<T, Y>
+where
+    Y: Add<Output = Y> + From<f64> + Tick,
+{
     fn apply_use_series(self: Rc<Self>, series: &mut SeriesAcc<T, Y>) {
         let colours = self.colours;
-        let mut previous = None;
         let total_lines = self.lines.len();
+        let offsets = Rc::new(StackOffsets::new(
+            self.lines.iter().map(|line| line.get_y.clone()).collect(),
+            self.offset,
+        ));
         for (id, line) in self.lines.clone().into_iter().enumerate() {
             let colour = create_memo(move |_| colours.get().interpolate(id, total_lines));
-            let line = StackedLine::new(line, previous.clone());
-            let get_y = series.push_line(colour, line);
-            // Sum next line with this one
-            previous = Some(get_y);
+            let line = StackedLine::new(line, id, offsets.clone());
+            _ = series.push_line(colour, line);
         }
     }
 }
@@ -94,41 +143,246 @@ impl<T: 'static, Y: std::ops::Add<Output = Y> + 'static> ApplyUseSeries<T, Y> fo
 #[derive(Clone)]
 struct StackedLine<T, Y> {
     line: Line<T, Y>,
-    previous: Option<Rc<dyn GetYValue<T, Y>>>,
+    index: usize,
+    offsets: Rc<StackOffsets<T, Y>>,
 }
 
 #[derive(Clone)]
 struct UseStackLine<T, Y> {
     current: Rc<dyn GetYValue<T, Y>>,
-    previous: Option<Rc<dyn GetYValue<T, Y>>>,
+    index: usize,
+    offsets: Rc<StackOffsets<T, Y>>,
+}
+
+/// Every line in a stack. Used to find the baseline and total at each X.
+struct StackOffsets<T, Y> {
+    lines: Vec<Rc<dyn GetYValue<T, Y>>>,
+    offset: RwSignal<StackOffset>,
+    // Values of the last datum read, shared by each line rather than each line reading every other line
+    read: RefCell<ReadValues>,
+}
+
+/// Values of each line for one datum.
+#[derive(Default)]
+struct ReadValues {
+    // Address of the datum
+    datum: usize,
+    // Missing values are treated as zero
+    values: Rc<[f64]>,
+    missing: Vec<bool>,
+    // Which lines have read the values (by line, then position or share). Every line reads once per datum so a second read means a new datum.
+    by: Vec<[bool; 2]>,
 }
 
 impl<T, Y> StackedLine<T, Y> {
-    pub fn new(line: Line<T, Y>, previous: Option<Rc<dyn GetYValue<T, Y>>>) -> Self {
-        Self { line, previous }
+    pub fn new(line: Line<T, Y>, index: usize, offsets: Rc<StackOffsets<T, Y>>) -> Self {
+        Self {
+            line,
+            index,
+            offsets,
+        }
     }
 }
 
-impl<T: 'static, Y: Add<Output = Y> + 'static> IntoUseLine<T, Y> for StackedLine<T, Y> {
+impl<T: 'static, Y> IntoUseLine<T, Y> for StackedLine<T, Y>
+where
+    Y: Add<Output = Y> + From<f64> + Tick,
+{
     fn into_use_line(self, id: usize, colour: Memo<Colour>) -> (UseY, Rc<dyn GetYValue<T, Y>>) {
         let (line, get_y) = self.line.into_use_line(id, colour);
         let get_y = Rc::new(UseStackLine {
             current: get_y,
-            previous: self.previous.clone(),
+            index: self.index,
+            offsets: self.offsets,
         });
         (line, get_y)
     }
 }
 
-impl<T, Y: Add<Output = Y>> GetYValue<T, Y> for UseStackLine<T, Y> {
+impl<T, Y: Tick> StackOffsets<T, Y> {
+    fn new(lines: Vec<Rc<dyn GetYValue<T, Y>>>, offset: RwSignal<StackOffset>) -> Self {
+        let read = ReadValues {
+            by: vec![[false; 2]; lines.len()],
+            ..ReadValues::default()
+        };
+        Self {
+            lines,
+            offset,
+            read: RefCell::new(read),
+        }
+    }
+
+    /// Values of each line and whether the line at `index` is missing, read by that line for a position (0) or share (1). Each datum's values are computed once. Missing values are treated as zero.
+    fn values(&self, t: &T, index: usize, by: usize) -> (Rc<[f64]>, bool) {
+        let mut read = self.read.borrow_mut();
+        let datum = t as *const T as usize;
+        if read.datum != datum || read.by[index][by] {
+            let values = (self.lines.iter())
+                .map(|line| line.value(t).position())
+                .collect::<Vec<_>>();
+            read.datum = datum;
+            read.missing = values.iter().map(|value| value.is_nan()).collect();
+            read.values = (values.into_iter())
+                .map(|value| if value.is_nan() { 0.0 } else { value })
+                .collect();
+            read.by.iter_mut().for_each(|by| *by = [false; 2]);
+        }
+        read.by[index][by] = true;
+        (read.values.clone(), read.missing[index])
+    }
+
+    /// Position of the top of the line at `index` after applying the offset. None if the line's value is missing. Other missing values are treated as zero.
+    fn position(&self, t: &T, index: usize) -> Option<f64> {
+        let (values, missing) = self.values(t, index, 0);
+        if missing {
+            return None;
+        }
+        let sum = values[..=index].iter().sum::<f64>();
+        let total = values.iter().sum::<f64>();
+        let n = values.len() as f64;
+        let position = match self.offset.get() {
+            StackOffset::Zero => sum,
+            StackOffset::Percent => sum / total * 100.0,
+            StackOffset::Diverging => {
+                // Only sum values on the same side of zero
+                let negative = values[index] < 0.0;
+                (values[..=index].iter())
+                    .filter(|&&value| (value < 0.0) == negative)
+                    .sum()
+            }
+            StackOffset::Silhouette => sum - total / 2.0,
+            StackOffset::Wiggle => {
+                let weighted = (values.iter().enumerate())
+                    .map(|(i, value)| (n - i as f64) * value)
+                    .sum::<f64>();
+                sum - weighted / (n + 1.0)
+            }
+        };
+        Some(position)
+    }
+
+    /// Share of the total of the line at `index`.
+    fn share(&self, t: &T, index: usize) -> f64 {
+        let (values, _) = self.values(t, index, 1);
+        values[index] / values.iter().sum::<f64>()
+    }
+}
+
+impl<T, Y> GetYValue<T, Y> for UseStackLine<T, Y>
+where
+    Y: Add<Output = Y> + From<f64> + Tick,
+{
     fn value(&self, t: &T) -> Y {
         self.current.value(t)
     }
 
     fn cumulative_value(&self, t: &T) -> Y {
-        self.previous.as_ref().map_or_else(
-            || self.current.cumulative_value(t),
-            |prev| self.current.cumulative_value(t) + prev.cumulative_value(t),
-        )
+        if self.offsets.offset.get() == StackOffset::Zero {
+            // Sum without converting so missing values carry up the stack
+            let lines = self.offsets.lines[..self.index].iter();
+            return lines.fold(self.current.cumulative_value(t), |sum, line| {
+                sum + line.cumulative_value(t)
+            });
+        }
+        match self.offsets.position(t, self.index) {
+            Some(position) => Y::from(position),
+            // Missing values stay missing
+            None => self.current.value(t),
+        }
+    }
+
+    fn extra(&self, t: &T) -> Option<Extra<Y>> {
+        (self.offsets.offset.get() == StackOffset::Percent)
+            .then(|| Extra::Share(self.offsets.share(t, self.index)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Trend;
+    use std::cell::Cell;
+
+    fn offsets(offset: StackOffset) -> StackOffsets<[f64; 3], f64> {
+        let lines: Vec<Rc<dyn GetYValue<[f64; 3], f64>>> = vec![
+            Rc::new(|d: &[f64; 3]| d[0]),
+            Rc::new(|d: &[f64; 3]| d[1]),
+            Rc::new(|d: &[f64; 3]| d[2]),
+        ];
+        StackOffsets::new(lines, create_rw_signal(offset))
+    }
+
+    #[test]
+    fn test_derived_lines() {
+        let stack = Stack::new()
+            .line(Line::new(|d: &[f64; 3]| d[0]))
+            .line(Line::new(|d: &[f64; 3]| d[1]).moving_average(2))
+            .line(Line::new(|d: &[f64; 3]| d[2]).trend(Trend::linear()));
+        assert_eq!(stack.len(), 1);
+    }
+
+    #[test]
+    fn test_error_bounds() {
+        let line = Line::new(|d: &[f64; 3]| d[1]).with_error(|d| d[0], |d| d[2]);
+        let stack = Stack::new().line(line);
+        // Stacked lines keep their values but not their bounds
+        assert_eq!(stack.len(), 1);
+        assert!(stack.lines[0].error.is_none());
+    }
+
+    fn positions(offset: StackOffset, datum: [f64; 3]) -> Vec<f64> {
+        let offsets = offsets(offset);
+        (0..3).filter_map(|i| offsets.position(&datum, i)).collect()
+    }
+
+    #[test]
+    fn test_offsets() {
+        let datum = [1.0, 3.0, 4.0];
+        assert_eq!(positions(StackOffset::Zero, datum), [1.0, 4.0, 8.0]);
+        assert_eq!(positions(StackOffset::Percent, datum), [12.5, 50.0, 100.0]);
+        assert_eq!(positions(StackOffset::Silhouette, datum), [-3.0, 0.0, 4.0]);
+        // Baseline of -(3 * 1 + 2 * 3 + 1 * 4) / 4
+        assert_eq!(positions(StackOffset::Wiggle, datum), [-2.25, 0.75, 4.75]);
+        // Negative values stack downward
+        let datum = [2.0, -3.0, -1.0];
+        assert_eq!(positions(StackOffset::Diverging, datum), [2.0, -3.0, -4.0]);
+        assert_eq!(positions(StackOffset::Zero, datum), [2.0, -1.0, -2.0]);
+        // Missing values are zero for other lines and stay missing
+        let datum = [1.0, f64::NAN, 3.0];
+        assert_eq!(positions(StackOffset::Percent, datum), [25.0, 100.0]);
+    }
+
+    #[test]
+    fn test_values_once() {
+        let reads = Rc::new(Cell::new(0));
+        let lines = (0..3)
+            .map(|i| {
+                let reads = reads.clone();
+                Rc::new(move |d: &[f64; 3]| {
+                    reads.set(reads.get() + 1);
+                    d[i]
+                }) as Rc<dyn GetYValue<[f64; 3], f64>>
+            })
+            .collect();
+        let offsets = StackOffsets::new(lines, create_rw_signal(StackOffset::Percent));
+        let data = [[1.0, 3.0, 4.0], [2.0, 2.0, 4.0]];
+        for datum in &data {
+            for i in 0..3 {
+                offsets.position(datum, i);
+                offsets.share(datum, i);
+            }
+        }
+        // Each line is read once per datum
+        assert_eq!(reads.get(), 6);
+        // Reading again recomputes the values e.g., when the data is rebuilt
+        assert_eq!(offsets.position(&data[1], 0), Some(25.0));
+        assert_eq!(reads.get(), 9);
+    }
+
+    #[test]
+    fn test_share() {
+        let offsets = offsets(StackOffset::Percent);
+        assert_eq!(offsets.share(&[1.0, 3.0, 4.0], 1), 0.375);
+        assert!(offsets.share(&[0.0, 0.0, 0.0], 0).is_nan());
     }
 }
@@ -1,70 +1,287 @@
-use super::Range;
+use super::{rows::Rows, Range};
 use crate::{
-    series::{GetX, GetY},
+    series::{
+        line::{DeriveState, Fit},
+        Extra, GetX, GetY,
+    },
+    stream::Revision,
     Tick,
 };
 use std::collections::HashMap;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Data<X, Y> {
-    data_x: Vec<X>,
-    data_y: Vec<HashMap<usize, Y>>,
+    data_x: Rows<X>,
+    data_y: Rows<HashMap<usize, Y>>,
 
     // Data index: X position to data
-    x_to_data: Vec<f64>,
+    x_to_data: Rows<f64>,
     // Rendering data
-    coords: HashMap<usize, Vec<(f64, f64)>>,
+    coords: HashMap<usize, Rows<(f64, f64)>>,
+    // Extra values of series that have them e.g., error bounds
+    extras: HashMap<usize, Rows<Extra<Y>>>,
+    // Description of fitted series e.g., a trend's equation
+    summaries: HashMap<usize, String>,
 
     range_x: Range<X>,
     range_y: Range<Y>,
+    range_heat: Range<f64>,
+
+    // Streaming: Y range of each data point to recalculate the range when dropped
+    rows_y: Rows<Range<Y>>,
+    // Running totals of waterfall series
+    running: HashMap<usize, Y>,
+    // History of derived series
+    derived: HashMap<usize, DeriveState>,
+    // Whether any series is fitted to all data
+    fitted: bool,
+    // Whether any series carries on from the data before it e.g., moving averages and waterfalls
+    carried: bool,
+    // Stream revision the data was built at
+    revision: Option<Revision>,
+    // Times streamed data was rebuilt rather than updated in place
+    generation: usize,
 }
 
 impl<X: Tick, Y: Tick> Data<X, Y> {
-    pub fn new<T>(get_x: GetX<T, X>, get_ys: HashMap<usize, GetY<T, Y>>, data: &[T]) -> Self {
+    /// Builds data in X order if `sort_by_x` is set. Otherwise data is expected in X order.
+    pub fn new<T>(
+        get_x: GetX<T, X>,
+        get_ys: HashMap<usize, GetY<T, Y>>,
+        data: &[T],
+        sort_by_x: bool,
+    ) -> Self {
         let cap = data.len();
-        let y_cap = get_ys.len();
 
         // Empty positions
         let mut built = Self {
-            data_x: Vec::with_capacity(cap),
-            data_y: Vec::with_capacity(cap),
-            x_to_data: Vec::with_capacity(cap * y_cap),
+            data_x: Rows::with_capacity(cap),
+            data_y: Rows::with_capacity(cap),
+            x_to_data: Rows::with_capacity(cap * get_ys.len()),
             coords: HashMap::with_capacity(cap),
+            extras: HashMap::new(),
+            summaries: HashMap::new(),
             range_x: Range::default(),
             range_y: Range::default(),
+            range_heat: Range::default(),
+            rows_y: Rows::with_capacity(cap),
+            running: HashMap::new(),
+            derived: HashMap::new(),
+            fitted: false,
+            carried: false,
+            revision: None,
+            generation: 0,
         };
 
-        for datum in data {
-            // X
-            let x = (get_x)(datum);
-            let x_position = x.position();
-            built.range_x.update(&x);
-            built.x_to_data.push(x_position);
-
-            // Y
-            let mut y_data = HashMap::with_capacity(y_cap);
-            for (&id, get_y) in &get_ys {
-                let y = get_y.value(datum);
+        // Fitted series e.g., trends need all data up front
+        let xs = (data.iter())
+            .map(|datum| (get_x)(datum).position())
+            .collect::<Vec<_>>();
+        let fits = (get_ys.iter())
+            .filter_map(|(&id, get_y)| Some((id, get_y.fit(data, &xs)?)))
+            .collect::<HashMap<usize, Fit<Y>>>();
+        built.fitted = !fits.is_empty();
+        for (&id, fit) in &fits {
+            if !fit.summary.is_empty() {
+                built.summaries.insert(id, fit.summary.clone());
+            }
+        }
+
+        // Visit data in X order. Fits are by data index
+        let mut order = (0..data.len()).collect::<Vec<_>>();
+        if sort_by_x {
+            order.sort_by(|&a, &b| xs[a].total_cmp(&xs[b]));
+        }
+        for index in order {
+            built.push(&get_x, &get_ys, &data[index], cap, |id| {
+                let fit = fits.get(&id)?;
+                let value = fit.values.get(index)?.clone();
+                let band = (fit.bands.as_ref()).and_then(|bands| bands.get(index).cloned());
+                Some((value, band))
+            });
+        }
+
+        built
+    }
+
+    /// Updates streamed data in place: drops `dropped` data points from the front then appends `appended`. Returns false if the data needs rebuilding instead: fitted series need all data and sorted data can't be appended out of X order. Sorted data can't drop either: the oldest data isn't necessarily first in X order. Nor can series that carry on from the data before them (e.g., moving averages): they restart from the first data point as if built from scratch.
+    pub fn update<T>(
+        &mut self,
+        get_x: GetX<T, X>,
+        get_ys: HashMap<usize, GetY<T, Y>>,
+        appended: &[T],
+        dropped: usize,
+        sort_by_x: bool,
+    ) -> bool {
+        if self.fitted || (dropped > 0 && (sort_by_x || self.carried)) {
+            return false;
+        }
+        let mut order = (0..appended.len()).collect::<Vec<_>>();
+        if sort_by_x {
+            let xs = (appended.iter())
+                .map(|datum| (get_x)(datum).position())
+                .collect::<Vec<_>>();
+            order.sort_by(|&a, &b| xs[a].total_cmp(&xs[b]));
+            let last_x = self.x_to_data.last().copied();
+            let first_x = order.first().map(|&index| xs[index]);
+            if let (Some(last_x), Some(first_x)) = (last_x, first_x) {
+                if first_x < last_x {
+                    return false;
+                }
+            }
+        }
+
+        self.truncate_front(dropped);
+        let cap = self.len() + appended.len();
+        for index in order {
+            self.push(&get_x, &get_ys, &appended[index], cap, |_| None);
+        }
+        true
+    }
+
+    /// Adds a data point. `fit` returns the fitted value and band (if any) of a series by ID.
+    fn push<T>(
+        &mut self,
+        get_x: &GetX<T, X>,
+        get_ys: &HashMap<usize, GetY<T, Y>>,
+        datum: &T,
+        cap: usize,
+        fit: impl Fn(usize) -> Option<(Y, Option<(Y, Y)>)>,
+    ) {
+        // X
+        let x = (get_x)(datum);
+        let x_position = x.position();
+        self.range_x.update(&x);
+        self.x_to_data.push(x_position);
+
+        // Y
+        let mut y_data = HashMap::with_capacity(get_ys.len());
+        let mut range_y = Range::default();
+        for (&id, get_y) in get_ys {
+            let extra = get_y.extra(datum);
+            let (mut y, mut y_cumulative) = match &extra {
+                // Box plots draw their median: reuse the summary rather than computing it again
+                Some(Extra::BoxPlot(summary)) => (summary.median.clone(), summary.median.clone()),
                 // Note: cumulative can differ from Y when stacked
-                let y_cumulative = get_y.cumulative_value(datum);
-                built.range_y.update(&y_cumulative);
-                // Insert
-                y_data.insert(id, y);
-                built
-                    .coords
+                _ => (get_y.value(datum), get_y.cumulative_value(datum)),
+            };
+            let state = self.derived.entry(id).or_default();
+            if let Some(value) = get_y.derive(datum, x_position, state) {
+                self.carried = true;
+                y = value.clone();
+                y_cumulative = value;
+            }
+            let fit = fit(id);
+            if let Some((value, _)) = &fit {
+                y = value.clone();
+                y_cumulative = value.clone();
+            }
+            let mut step_extra = None;
+            if let Some(step) = get_y.running_total(datum, self.running.get(&id)) {
+                self.carried = true;
+                // Subtotals show the total rather than a change
+                if step.subtotal {
+                    y = step.end.clone();
+                }
+                // Skip missing values
+                if !step.end.position().is_nan() {
+                    self.running.insert(id, step.end.clone());
+                }
+                step_extra = Some(Extra::Step(step.start, step.subtotal));
+                y_cumulative = step.end;
+            }
+            range_y.update(&y_cumulative);
+            // Fitted bands replace error bounds
+            let extra = match fit {
+                Some((_, band)) => band.map(|(lower, upper)| Extra::Error(lower, upper)),
+                None => step_extra.or(extra),
+            };
+            if let Some(extra) = extra {
+                extra.extend_range(&mut range_y);
+                if let Extra::Heat(value) = extra {
+                    self.range_heat.update(&value);
+                }
+                self.extras
                     .entry(id)
-                    .or_insert_with(|| Vec::with_capacity(cap))
-                    .push((x_position, y_cumulative.position()));
+                    .or_insert_with(|| Rows::with_capacity(cap))
+                    .push(extra);
             }
-
             // Insert
-            built.data_x.push(x);
-            built.data_y.push(y_data);
+            y_data.insert(id, y);
+            self.coords
+                .entry(id)
+                .or_insert_with(|| Rows::with_capacity(cap))
+                .push((x_position, y_cumulative.position()));
         }
 
-        built
+        // Insert
+        self.range_y = self.range_y.clone().union(&range_y);
+        self.rows_y.push(range_y);
+        self.data_x.push(x);
+        self.data_y.push(y_data);
     }
 
+    /// Drops data points from the front. Ranges are only recalculated if the dropped data was at their edge.
+    fn truncate_front(&mut self, n: usize) {
+        let n = n.min(self.len());
+        if n == 0 {
+            return;
+        }
+
+        // Ranges
+        let dropped_x = Range::default().extend(&self.data_x[..n]);
+        if !self.range_x.encloses(&dropped_x) {
+            self.range_x = Range::default().extend(&self.data_x[n..]);
+        }
+        let dropped_y = (self.rows_y[..n].iter()).fold(Range::default(), |acc, row| acc.union(row));
+        if !self.range_y.encloses(&dropped_y) {
+            self.range_y =
+                (self.rows_y[n..].iter()).fold(Range::default(), |acc, row| acc.union(row));
+        }
+        let dropped_heat = (self.extras.values()).fold(Range::default(), |acc, extras| {
+            acc.union(&heat_range(&extras[..n.min(extras.len())]))
+        });
+        if !self.range_heat.encloses(&dropped_heat) {
+            self.range_heat = (self.extras.values()).fold(Range::default(), |acc, extras| {
+                acc.union(&heat_range(&extras[n.min(extras.len())..]))
+            });
+        }
+
+        // Data
+        self.data_x.truncate_front(n);
+        self.data_y.truncate_front(n);
+        self.x_to_data.truncate_front(n);
+        self.rows_y.truncate_front(n);
+        (self.coords.values_mut()).for_each(|v| v.truncate_front(n));
+        (self.extras.values_mut()).for_each(|v| v.truncate_front(n));
+    }
+
+    /// Returns the stream revision the data was built at. None if not streamed.
+    pub fn revision(&self) -> Option<Revision> {
+        self.revision
+    }
+
+    /// Sets the stream revision the data was built at.
+    pub fn set_revision(&mut self, revision: Option<Revision>) {
+        self.revision = revision;
+    }
+
+    /// Returns the number of times streamed data was rebuilt. Unchanged by updates in place.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Sets the number of times streamed data was rebuilt.
+    pub fn set_generation(&mut self, generation: usize) {
+        self.generation = generation;
+    }
+
+    /// Extends the X range e.g., to the end of range bars.
+    pub fn include_x(&mut self, x: &X) {
+        self.range_x.update(x);
+    }
+
+    /// Number of data points.
     pub fn len(&self) -> usize {
         self.data_x.len()
     }
@@ -77,6 +294,42 @@ impl<X: Tick, Y: Tick> Data<X, Y> {
         self.range_y.clone()
     }
 
+    /// Returns the range of heatmap values. Empty if there are no heatmaps.
+    pub fn range_heat(&self) -> Range<f64> {
+        self.range_heat.clone()
+    }
+
+    /// Returns the positions of series with extra values of a kind e.g., heatmap cells. None if there are none.
+    fn kind_coords(&self, kind: fn(&Extra<Y>) -> bool) -> Option<Vec<(f64, f64)>> {
+        let ids = (self.extras.iter())
+            .filter(|(_, extras)| extras.first().is_some_and(kind))
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>();
+        if ids.is_empty() {
+            return None;
+        }
+        let coords = ids.into_iter().filter_map(|id| self.coords.get(id));
+        Some(coords.flatten().copied().collect())
+    }
+
+    /// Returns the (width, height) in positions of a heatmap cell: the smallest gap between X and Y bins. Defaults to 1.0 if there's only one bin. None if there are no heatmaps.
+    pub fn heat_cell(&self) -> Option<(f64, f64)> {
+        let coords = self.kind_coords(|extra| matches!(extra, Extra::Heat(_)))?;
+        let (xs, ys): (Vec<_>, Vec<_>) = coords.into_iter().unzip();
+        Some((smallest_gap(xs), smallest_gap(ys)))
+    }
+
+    /// Returns the height in positions of a range bar lane: the smallest gap between lanes. Defaults to 1.0 if there's only one lane. None if there are no range bars.
+    pub fn lane_height(&self) -> Option<f64> {
+        let coords = self.kind_coords(|extra| matches!(extra, Extra::Span(..)))?;
+        Some(smallest_gap(coords.into_iter().map(|(_, y)| y).collect()))
+    }
+
+    /// Returns the description of each fitted series (by series ID) e.g., a trend's equation.
+    pub fn summaries(&self) -> HashMap<usize, String> {
+        self.summaries.clone()
+    }
+
     /// Finds the index of the _nearest_ position to the given X. Returns None if no data.
     fn nearest_index(&self, pos_x: f64) -> Option<usize> {
         // No values
@@ -114,20 +367,98 @@ impl<X: Tick, Y: Tick> Data<X, Y> {
             .unwrap_or_default()
     }
 
+    /// Returns the extra values (by series ID) nearest to the given X e.g., a candlestick's open, high, low, and close. Only includes series with extra values.
+    pub fn nearest_extras(&self, pos_x: f64) -> HashMap<usize, Extra<Y>> {
+        self.nearest_index(pos_x)
+            .map(|index| {
+                (self.extras.iter())
+                    .filter_map(|(&id, extras)| Some((id, extras.get(index)?.clone())))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Given an arbitrary (unaligned to data) X position, find the nearest X position aligned to data. Returns `f64::NAN` if no data. Note a position covers a range dependent on the chart width.
     pub fn nearest_position_x(&self, pos_x: f64) -> Option<f64> {
         self.nearest_index(pos_x).map(|index| self.x_to_data[index])
     }
 
+    /// Returns each X value with its Y values (by series ID). In data order.
+    pub fn rows(&self) -> impl Iterator<Item = (&X, &HashMap<usize, Y>)> {
+        self.data_x.iter().zip(self.data_y.iter())
+    }
+
+    /// Returns the X position of the data at the given index. Returns None if out of bounds.
+    pub fn position_x_at(&self, index: usize) -> Option<f64> {
+        self.x_to_data.get(index).copied()
+    }
+
+    /// Returns the Y values of a series. In data order.
+    pub fn series_values(&self, id: usize) -> Vec<Option<Y>> {
+        self.data_y.iter().map(|ys| ys.get(&id).cloned()).collect()
+    }
+
     pub fn series_positions(&self, id: usize) -> Vec<(f64, f64)> {
-        self.coords.get(&id).cloned().unwrap_or_default()
+        self.series_coords(id).to_vec()
+    }
+
+    /// Returns the positions of a series without copying them. In data order.
+    pub fn series_coords(&self, id: usize) -> &[(f64, f64)] {
+        self.coords
+            .get(&id)
+            .map(|coords| &coords[..])
+            .unwrap_or_default()
     }
+
+    /// Returns the (generation, stream index of the first data point) of the data. None if it isn't streamed.
+    pub fn stream(&self) -> Option<(usize, usize)> {
+        (self.revision).map(|revision| (self.generation, revision.offset()))
+    }
+
+    /// Returns the (X, Y) position and extra values (in positions) of each data point of a series. Empty if the series has no extra values.
+    pub fn series_extras(&self, id: usize) -> Vec<((f64, f64), Extra<f64>)> {
+        let extras = self.extras.get(&id).into_iter().flatten();
+        (self.series_coords(id).iter())
+            .zip(extras)
+            .map(|(&position, extra)| (position, extra.positions()))
+            .collect()
+    }
+}
+
+/// Range of heatmap cell values.
+fn heat_range<Y>(extras: &[Extra<Y>]) -> Range<f64> {
+    extras.iter().fold(Range::default(), |mut range, extra| {
+        if let Extra::Heat(value) = extra {
+            range.update(value);
+        }
+        range
+    })
+}
+
+/// Finds the smallest gap between distinct positions. Defaults to 1.0.
+fn smallest_gap(mut positions: Vec<f64>) -> f64 {
+    positions.retain(|pos| !pos.is_nan());
+    positions.sort_by(f64::total_cmp);
+    positions
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .filter(|&gap| gap > 0.0)
+        .reduce(f64::min)
+        .unwrap_or(1.0)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::rc::Rc;
+    use crate::{
+        series::{
+            ApplyUseSeries, BoxPlot, GetYValue, IntoUseBar, IntoUseLine, IntoUseRangeBar, Line,
+            RangeBar, Series, SeriesAcc, Stack, StackOffset, Trend, Waterfall, STACK_COLOUR_SCHEME,
+        },
+        Colour,
+    };
+    use leptos::{create_memo, create_rw_signal, Signal, SignalWith};
+    use std::{cell::Cell, rc::Rc};
 
     #[derive(Clone, Debug, PartialEq)]
     struct MyData {
@@ -153,31 +484,26 @@ mod tests {
         get_ys.insert(66, Rc::new(|d: &MyData| d.y1));
         get_ys.insert(5, Rc::new(|d: &MyData| d.y2));
 
-        Data::new(Rc::new(|d: &MyData| d.x), get_ys, data)
+        Data::new(Rc::new(|d: &MyData| d.x), get_ys, data, false)
     }
 
     #[test]
     fn test_data_new() {
         let data = test_data(DATA);
         // Data
-        assert_eq!(data.data_x, vec![1.0, 4.0, 7.0]);
+        assert_eq!(*data.data_x, [1.0, 4.0, 7.0]);
         assert_eq!(
-            data.data_y,
-            vec![
+            *data.data_y,
+            [
                 HashMap::from([(66, 2.0), (5, 3.0)]),
                 HashMap::from([(66, 5.0), (5, 6.0)]),
                 HashMap::from([(66, 8.0), (5, 9.0)]),
             ]
         );
         // Positions
-        assert_eq!(data.x_to_data, vec![1.0, 4.0, 7.0]);
-        assert_eq!(
-            data.coords,
-            HashMap::from([
-                (66, vec![(1.0, 2.0), (4.0, 5.0), (7.0, 8.0)]),
-                (5, vec![(1.0, 3.0), (4.0, 6.0), (7.0, 9.0)]),
-            ])
-        );
+        assert_eq!(*data.x_to_data, [1.0, 4.0, 7.0]);
+        assert_eq!(data.series_coords(66), [(1.0, 2.0), (4.0, 5.0), (7.0, 8.0)]);
+        assert_eq!(data.series_coords(5), [(1.0, 3.0), (4.0, 6.0), (7.0, 9.0)]);
         // Ranges
         assert_eq!(data.range_x.range(), Some((&1.0, &7.0)));
         assert_eq!(data.range_x.positions(), Some((1.0, 7.0)));
@@ -185,6 +511,85 @@ mod tests {
         assert_eq!(data.range_y.positions(), Some((2.0, 9.0)));
     }
 
+    #[test]
+    fn test_sort_by_x() {
+        let unsorted = [DATA[2].clone(), DATA[0].clone(), DATA[1].clone()];
+        let mut get_ys = HashMap::<usize, GetY<_, _>>::new();
+        let (_, cumulative) = Line::new(|d: &MyData| d.y1)
+            .cumulative()
+            .into_use_line(0, create_memo(|_| Colour::from_rgb(0, 0, 0)));
+        get_ys.insert(0, cumulative);
+        let data = Data::new(Rc::new(|d: &MyData| d.x), get_ys, &unsorted, true);
+        assert_eq!(*data.x_to_data, [1.0, 4.0, 7.0]);
+        // Derived in X order
+        assert_eq!(
+            data.series_positions(0),
+            vec![(1.0, 2.0), (4.0, 7.0), (7.0, 15.0)]
+        );
+        assert_eq!(data.nearest_data_x(3.0), Some(4.0));
+    }
+
+    #[test]
+    fn test_update() {
+        let get_ys = || {
+            let mut get_ys = HashMap::<usize, GetY<_, _>>::new();
+            get_ys.insert(66, Rc::new(|d: &MyData| d.y1));
+            get_ys.insert(5, Rc::new(|d: &MyData| d.y2));
+            get_ys
+        };
+        let get_x: GetX<MyData, f64> = Rc::new(|d: &MyData| d.x);
+        // Same as building from scratch
+        let mut data = test_data(&DATA[..2]);
+        assert!(data.update(get_x.clone(), get_ys(), &DATA[2..], 1, false));
+        assert_eq!(data, test_data(&DATA[1..]));
+        assert_eq!(data.range_x.positions(), Some((4.0, 7.0)));
+        assert_eq!(data.range_y.positions(), Some((5.0, 9.0)));
+        // Dropping alone updates in place too
+        assert!(data.update(get_x.clone(), get_ys(), &[], 1, false));
+        assert_eq!(data, test_data(&DATA[2..]));
+        // Sorted data can't be appended out of order
+        let before = MyData::new(2.0, 0.0, 0.0);
+        assert!(!data.update(get_x.clone(), get_ys(), &[before], 0, true));
+        // Sorted data drops by stream order, not X order e.g., [3, 1, 2] then push 4 with capacity 3
+        let unsorted = [DATA[2].clone(), DATA[0].clone(), DATA[1].clone()];
+        let mut data = Data::new(get_x.clone(), get_ys(), &unsorted, true);
+        let after = [MyData::new(10.0, 0.0, 0.0)];
+        assert!(!data.update(get_x.clone(), get_ys(), &after, 1, true));
+        assert!(data.update(get_x.clone(), get_ys(), &after, 0, true));
+        // Fits need all data
+        let mut get_ys = HashMap::<usize, GetY<_, _>>::new();
+        let (_, trend) = Line::new(|d: &MyData| d.y1)
+            .trend(Trend::linear())
+            .into_use_line(0, create_memo(|_| Colour::from_rgb(0, 0, 0)));
+        get_ys.insert(0, trend);
+        let mut data = Data::new(get_x.clone(), get_ys.clone(), &DATA[..2], false);
+        assert!(!data.update(get_x, get_ys, &DATA[2..], 0, false));
+    }
+
+    #[test]
+    fn test_attach_nested() {
+        let points = |points: Vec<(f64, f64)>| Signal::from(create_rw_signal(points));
+        let pair =
+            || Series::new(|&(x, _): &(f64, f64)| x).line(Line::new(|&(_, y): &(f64, f64)| y));
+        let inner = pair();
+        let middle = pair().attach(points(vec![(2.0, 20.0), (3.0, 30.0)]), inner);
+        let series = Series::new(|d: &MyData| d.x)
+            .line(Line::new(|d: &MyData| d.y1))
+            .attach(points(vec![(1.5, 15.0)]), middle);
+        let (lines, attached) = series.to_use_lines();
+        assert_eq!(lines.len(), 1);
+        // Series attached to attached series are attached too, continuing IDs
+        let ids = (attached.iter())
+            .map(|(use_ys, data)| {
+                (
+                    use_ys.iter().map(|use_y| use_y.id).collect(),
+                    data.with(Data::len),
+                )
+            })
+            .collect::<Vec<(Vec<_>, _)>>();
+        assert_eq!(ids, [(vec![1], 1), (vec![2], 2)]);
+    }
+
     #[test]
     fn test_nearest_index() {
         let data = test_data(DATA);
@@ -223,4 +628,163 @@ mod tests {
         assert_eq!(data.nearest_position_x(3.0), Some(4.0));
         assert_eq!(data.nearest_position_x(4.0), Some(4.0));
     }
+
+    #[test]
+    fn test_ohlc() {
+        struct Ohlc;
+        impl GetYValue<MyData, f64> for Ohlc {
+            fn value(&self, d: &MyData) -> f64 {
+                d.y1
+            }
+            fn cumulative_value(&self, d: &MyData) -> f64 {
+                d.y1
+            }
+            fn extra(&self, d: &MyData) -> Option<Extra<f64>> {
+                Some(Extra::Ohlc([d.y2, d.y1 + 10.0, d.y1 - 10.0, d.y1]))
+            }
+        }
+        let mut get_ys = HashMap::<usize, GetY<_, _>>::new();
+        get_ys.insert(3, Rc::new(Ohlc));
+        let data = Data::new(Rc::new(|d: &MyData| d.x), get_ys, DATA, false);
+        // Range includes the high and low
+        assert_eq!(data.range_y.range(), Some((&-8.0, &18.0)));
+        assert_eq!(
+            data.nearest_extras(4.0),
+            HashMap::from([(3, Extra::Ohlc([6.0, 15.0, -5.0, 5.0]))])
+        );
+        assert_eq!(
+            data.series_extras(3)[2],
+            ((7.0, 8.0), Extra::Ohlc([9.0, 18.0, -2.0, 8.0]))
+        );
+        assert!(data.series_extras(4).is_empty());
+    }
+
+    #[test]
+    fn test_waterfall() {
+        let mut get_ys = HashMap::<usize, GetY<_, _>>::new();
+        let (_, waterfall) = Waterfall::new(|d: &MyData| d.y1 - d.y2)
+            .with_subtotals(|d: &MyData| d.x == 7.0)
+            .into_use_bar(1, 0, create_memo(|_| Colour::from_rgb(0, 0, 0)));
+        get_ys.insert(1, waterfall);
+        let data = Data::new(Rc::new(|d: &MyData| d.x), get_ys, DATA, false);
+        // Running totals: -1, -2, then a subtotal of -2
+        assert_eq!(
+            data.series_positions(1),
+            vec![(1.0, -1.0), (4.0, -2.0), (7.0, -2.0)]
+        );
+        assert_eq!(
+            data.series_extras(1),
+            vec![
+                ((1.0, -1.0), Extra::Step(None, false)),
+                ((4.0, -2.0), Extra::Step(Some(-1.0), false)),
+                ((7.0, -2.0), Extra::Step(None, true)),
+            ]
+        );
+        // Tooltip shows the change or the subtotal
+        assert_eq!(
+            data.series_values(1),
+            vec![Some(-1.0), Some(-1.0), Some(-2.0)]
+        );
+    }
+
+    #[test]
+    fn test_box_plot() {
+        let summaries = Rc::new(Cell::new(0));
+        let mut get_ys = HashMap::<usize, GetY<_, _>>::new();
+        let (_, box_plot) = BoxPlot::from_samples({
+            let summaries = summaries.clone();
+            move |d: &MyData| {
+                summaries.set(summaries.get() + 1);
+                vec![d.y1, d.y2]
+            }
+        })
+        .into_use_bar(1, 0, create_memo(|_| Colour::from_rgb(0, 0, 0)));
+        get_ys.insert(1, box_plot);
+        let data = Data::new(Rc::new(|d: &MyData| d.x), get_ys, DATA, false);
+        // Median is drawn from the summary computed once per data point
+        assert_eq!(summaries.get(), DATA.len());
+        assert_eq!(data.series_values(1), vec![Some(2.5), Some(5.5), Some(8.5)]);
+    }
+
+    #[test]
+    fn test_percent_stack() {
+        let stack = Stack::new()
+            .line(|d: &MyData| d.y1)
+            .line(|d: &MyData| d.y2)
+            .with_offset(StackOffset::Percent);
+        let mut series = SeriesAcc::new(create_rw_signal(STACK_COLOUR_SCHEME.into()));
+        Rc::new(stack).apply_use_series(&mut series);
+        let get_ys = (series.lines.into_iter())
+            .map(|(use_y, get_y)| (use_y.id, get_y))
+            .collect();
+        let data = Data::new(Rc::new(|d: &MyData| d.x), get_ys, DATA, false);
+        // Top of the stack is always 100%
+        assert_eq!(data.series_positions(1)[0], (1.0, 100.0));
+        assert_eq!(data.series_positions(0)[0], (1.0, 40.0));
+        // Tooltip shows the value and its share
+        assert_eq!(data.series_values(0)[0], Some(2.0));
+        assert_eq!(
+            data.nearest_extras(1.0),
+            HashMap::from([(0, Extra::Share(0.4)), (1, Extra::Share(0.6))])
+        );
+        assert_eq!(data.range_y.positions(), Some((40.0, 100.0)));
+    }
+
+    #[test]
+    fn test_range_bar() {
+        let mut get_ys = HashMap::<usize, GetY<_, _>>::new();
+        let (_, range_bar) = RangeBar::new(|d: &MyData| d.y1, |d: &MyData| d.x - 1.0)
+            .with_label(|d: &MyData| format!("job {}", d.x))
+            .into_use_range_bar(2, create_memo(|_| Colour::from_rgb(0, 0, 0)));
+        get_ys.insert(2, range_bar);
+        let data = Data::new(Rc::new(|d: &MyData| d.x), get_ys, DATA, false);
+        assert_eq!(
+            data.series_extras(2),
+            vec![
+                ((1.0, 0.0), Extra::Span(2.0, "job 1".to_string())),
+                ((4.0, 3.0), Extra::Span(5.0, "job 4".to_string())),
+                ((7.0, 6.0), Extra::Span(8.0, "job 7".to_string())),
+            ]
+        );
+        assert_eq!(data.lane_height(), Some(3.0));
+        assert!(data.series_extras(3).is_empty());
+        assert_eq!(test_data(DATA).lane_height(), None);
+    }
+
+    #[test]
+    fn test_trend() {
+        let line = Line::new(|d: &MyData| d.y1).trend(
+            Trend::linear()
+                .with_confidence(0.95)
+                .with_equation()
+                .with_r_squared(),
+        );
+        let mut series = SeriesAcc::new(create_rw_signal(STACK_COLOUR_SCHEME.into()));
+        Rc::new(line).apply_use_series(&mut series);
+        let get_ys = (series.lines.into_iter())
+            .map(|(use_y, get_y)| (use_y.id, get_y))
+            .collect();
+        let data = Data::new(Rc::new(|d: &MyData| d.x), get_ys, DATA, false);
+        // Y is one more than X so the fit is exact
+        assert_eq!(
+            data.series_positions(0),
+            vec![(1.0, 2.0), (4.0, 5.0), (7.0, 8.0)]
+        );
+        assert_eq!(
+            data.series_extras(0)[1],
+            ((4.0, 5.0), Extra::Error(5.0, 5.0))
+        );
+        assert_eq!(
+            data.summaries(),
+            HashMap::from([(0, "y = 1x + 1, R² = 1.00".to_string())])
+        );
+        assert!(test_data(DATA).summaries().is_empty());
+    }
+
+    #[test]
+    fn test_smallest_gap() {
+        assert_eq!(smallest_gap(vec![3.0, 1.0, 1.0, 2.5, f64::NAN]), 0.5);
+        assert_eq!(smallest_gap(vec![4.0, 4.0]), 1.0);
+        assert_eq!(smallest_gap(vec![]), 1.0);
+    }
 }
@@ -1,99 +1,255 @@
 mod data;
 mod range;
+mod rows;
 
 pub use range::Range;
 
 use crate::{
-    series::{use_y::RenderUseY, UseY},
+    group::UseChartGroup,
+    series::{
+        line::{Segments, Streamed},
+        use_y::RenderUseY,
+        Extra, UseY,
+    },
     state::State,
-    Series, Tick,
+    ChartData, Series, Tick, TickLabels,
 };
-use data::Data;
+pub(super) use data::Data;
 use leptos::*;
+use std::{collections::HashMap, rc::Rc};
 
 #[derive(Clone)]
 pub struct UseData<X: 'static, Y: 'static> {
     data: Memo<Data<X, Y>>,
+    // Our own data followed by data of attached series. Each has its own X grid
+    grids: Rc<[Memo<Data<X, Y>>]>,
+    // Data of attached series (by series ID)
+    owners: StoredValue<HashMap<usize, Memo<Data<X, Y>>>>,
     pub len: Memo<usize>,
     pub series: Memo<Vec<UseY>>,
     pub includes_bars: Memo<bool>,
+    /// (width, height) of a heatmap cell in positions. None if there are no heatmaps.
+    pub heat_cell: Memo<Option<(f64, f64)>>,
+    /// (min, max) of heatmap values. None if there are no heatmaps.
+    pub heat_range: Memo<Option<(f64, f64)>>,
+    /// Height of a range bar lane in positions. None if there are no range bars.
+    pub lane_height: Memo<Option<f64>>,
+    /// Description of fitted series (by series ID) e.g., a trend's equation.
+    pub summaries: Memo<HashMap<usize, String>>,
     pub range_x: Memo<Range<X>>,
     pub range_y: Memo<Range<Y>>,
+    /// Whether the data is a stream. Streamed lines are drawn in segments.
+    pub is_stream: bool,
 }
 
 impl<X: Tick, Y: Tick> UseData<X, Y> {
-    pub fn new<T: 'static>(series: Series<T, X, Y>, data: Signal<Vec<T>>) -> UseData<X, Y> {
-        let lines = series.to_use_lines();
+    pub fn new<T: 'static>(
+        series: Series<T, X, Y>,
+        data: ChartData<T>,
+        extents: Signal<(Vec<X>, Vec<Y>)>,
+        group: Option<&UseChartGroup<X>>,
+    ) -> UseData<X, Y> {
+        let (lines, attached) = series.to_use_lines();
 
-        // Data values
-        let data = {
-            let lines = lines.clone();
+        // Range bars extend the X range to their ends
+        let range_x_ends = {
+            let get_x_ends = series.get_x_ends.clone();
             create_memo(move |_| {
-                let get_x = series.get_x.clone();
+                let mut range = Range::default();
                 data.with(|data| {
-                    Data::new(
-                        get_x,
-                        lines
-                            .clone()
-                            .into_iter()
-                            .map(|(use_y, get_y)| (use_y.id, get_y))
-                            .collect(),
-                        data,
-                    )
-                })
+                    for get_x_end in &get_x_ends {
+                        data.iter()
+                            .for_each(|datum| range.update(&get_x_end(datum)));
+                    }
+                });
+                range
+            })
+        };
+
+        // Data values. Streams update the last data in place
+        let own = {
+            let lines = lines.clone();
+            create_owning_memo(move |previous: Option<Data<X, Y>>| {
+                let get_x = series.get_x.clone();
+                let get_ys = || {
+                    (lines.iter())
+                        .map(|(use_y, get_y)| (use_y.id, get_y.clone()))
+                        .collect()
+                };
+                let sort_by_x = series.sort_by_x.get();
+                let generation =
+                    (previous.as_ref()).map_or(0, |previous| previous.generation() + 1);
+                let built = data.with_revision(|data, revision| {
+                    // Only pushing and dropping data updates in place. Anything else (e.g., a series changing) rebuilds
+                    let change = (previous.as_ref())
+                        .and_then(|previous| revision?.since(previous.revision()?))
+                        .filter(|&(dropped, appended)| {
+                            (dropped > 0 || appended > 0) && appended <= data.len()
+                        });
+                    if let (Some(mut previous), Some((dropped, appended))) = (previous, change) {
+                        let appended = &data[data.len() - appended..];
+                        if previous.update(get_x.clone(), get_ys(), appended, dropped, sort_by_x) {
+                            // Dropping alone reads no series. Read them on the last data point so changes to their signals still rebuild
+                            if let (true, Some(last)) = (appended.is_empty(), data.last()) {
+                                (get_x)(last);
+                                for get_y in get_ys().values() {
+                                    get_y.value(last);
+                                    get_y.cumulative_value(last);
+                                    get_y.extra(last);
+                                }
+                            }
+                            previous.set_revision(revision);
+                            return previous;
+                        }
+                    }
+                    let mut built = Data::new(get_x, get_ys(), data, sort_by_x);
+                    built.set_revision(revision);
+                    built.set_generation(generation);
+                    built
+                });
+                (built, true)
             })
         };
+        let is_stream = data.is_stream();
+        // Attached data is kept apart from our own and looked up on its own X grid
+        let grids = std::iter::once(own)
+            .chain(attached.iter().map(|(_, data)| *data))
+            .collect::<Rc<[_]>>();
+        let owners = (attached.iter())
+            .flat_map(|(use_ys, data)| use_ys.iter().map(|use_y| (use_y.id, *data)))
+            .collect::<HashMap<_, _>>();
 
         // Range signals
-        let range_x: Memo<Range<X>> = create_memo(move |_| {
-            data.with(|data| data.range_x())
-                .maybe_update(vec![series.min_x.get(), series.max_x.get()])
+        let range_x: Memo<Range<X>> = create_memo({
+            let grids = grids.clone();
+            move |_| {
+                let range_x = union(&grids, Data::range_x)
+                    .union(&range_x_ends.get())
+                    .maybe_update(vec![series.min_x.get(), series.max_x.get()]);
+                extents.with(|(xs, _)| range_x.extend(xs))
+            }
         });
-        let range_y: Memo<Range<Y>> = create_memo(move |_| {
-            data.with(|data| data.range_y())
-                .maybe_update(vec![series.min_y.get(), series.max_y.get()])
+        // Share X range with other charts
+        let range_x = group.map_or(range_x, |group| group.range_x(range_x));
+        // Gaps drawn at zero need zero in range
+        let gaps_at_zero = {
+            let lines = lines
+                .iter()
+                .map(|(use_y, _)| use_y.clone())
+                .collect::<Vec<_>>();
+            create_memo(move |_| lines.iter().any(UseY::gaps_at_zero))
+        };
+        let range_y: Memo<Range<Y>> = create_memo({
+            let grids = grids.clone();
+            move |_| {
+                let zero = gaps_at_zero.get().then(Y::zero).flatten();
+                let range_y = union(&grids, Data::range_y).maybe_update(vec![
+                    series.min_y.get(),
+                    series.max_y.get(),
+                    zero,
+                ]);
+                extents.with(|(_, ys)| range_y.extend(ys))
+            }
         });
 
         // Sort series by name
         let series = {
-            let (lines, _): (Vec<_>, Vec<_>) = lines.into_iter().unzip();
+            let (mut lines, _): (Vec<_>, Vec<_>) = lines.into_iter().unzip();
+            lines.extend(attached.into_iter().flat_map(|(use_ys, _)| use_ys));
             create_memo(move |_| {
                 let mut lines = lines.clone();
                 lines.sort_by_key(|line| line.name.get());
                 lines
             })
         };
-        let includes_bars =
-            create_memo(move |_| series.get().iter().any(|use_y| use_y.bar().is_some()));
+        let includes_bars = create_memo(move |_| series.get().iter().any(UseY::takes_x_width));
+
+        let heat_cell = {
+            let grids = grids.clone();
+            create_memo(move |_| grids.iter().find_map(|grid| grid.with(Data::heat_cell)))
+        };
+        let lane_height = {
+            let grids = grids.clone();
+            create_memo(move |_| grids.iter().find_map(|grid| grid.with(Data::lane_height)))
+        };
+        let heat_range = {
+            let grids = grids.clone();
+            create_memo(move |_| union(&grids, Data::range_heat).positions())
+        };
+        let summaries = {
+            let grids = grids.clone();
+            create_memo(move |_| {
+                grids
+                    .iter()
+                    .flat_map(|grid| grid.with(Data::summaries))
+                    .collect()
+            })
+        };
 
         UseData {
-            data,
-            len: create_memo(move |_| with!(|data| data.len())),
+            data: own,
+            grids,
+            owners: store_value(owners),
+            len: create_memo(move |_| own.with(Data::len)),
             series,
             includes_bars,
+            heat_cell,
+            heat_range,
+            lane_height,
+            summaries,
             range_x,
             range_y,
+            is_stream,
         }
     }
 }
 
 impl<X: Tick, Y: Tick> UseData<X, Y> {
     pub fn nearest_data_x(&self, pos_x: Memo<f64>) -> Memo<Option<X>> {
-        let data = self.data;
-        create_memo(move |_| data.with(|data| data.nearest_data_x(pos_x.get())))
+        let grids = self.grids.clone();
+        create_memo(move |_| {
+            let pos_x = pos_x.get();
+            nearest_grid(&grids, pos_x)?.with(|data| data.nearest_data_x(pos_x))
+        })
     }
 
     pub fn nearest_position_x(&self, pos_x: Memo<f64>) -> Memo<Option<f64>> {
+        let grids = self.grids.clone();
+        create_memo(move |_| {
+            let pos_x = pos_x.get();
+            nearest_grid(&grids, pos_x)?.with(|data| data.nearest_position_x(pos_x))
+        })
+    }
+
+    pub fn rows(&self) -> Memo<Vec<(X, HashMap<usize, Y>)>> {
+        let data = self.data;
+        create_memo(move |_| {
+            data.with(|data| data.rows().map(|(x, ys)| (x.clone(), ys.clone())).collect())
+        })
+    }
+
+    /// Extra values (by series ID) nearest to the given X e.g., a candlestick's open, high, low, and close. Only includes series with extra values.
+    pub(crate) fn nearest_extras(&self, pos_x: Memo<f64>) -> Memo<HashMap<usize, Extra<Y>>> {
+        let grids = self.grids.clone();
+        create_memo(move |_| by_grid(&grids, |data| data.nearest_extras(pos_x.get())))
+    }
+
+    pub fn position_x_at(&self, index: Signal<Option<usize>>) -> Memo<Option<f64>> {
         let data = self.data;
-        create_memo(move |_| data.with(|data| data.nearest_position_x(pos_x.get())))
+        create_memo(move |_| {
+            index
+                .get()
+                .and_then(|index| data.with(|data| data.position_x_at(index)))
+        })
     }
 
     // TODO: this can never be None
+    /// Y values nearest to the given X. Each series uses the nearest X of its own data.
     pub fn nearest_data_y(&self, pos_x: Memo<f64>) -> Memo<Vec<(UseY, Option<Y>)>> {
         let series = self.series;
-        let data = self.data;
+        let grids = self.grids.clone();
         create_memo(move |_| {
-            let y_values = data.with(|data| data.nearest_data_y(pos_x.get()));
+            let y_values = by_grid(&grids, |data| data.nearest_data_y(pos_x.get()));
             series
                 .get()
                 .into_iter()
@@ -106,13 +262,70 @@ impl<X: Tick, Y: Tick> UseData<X, Y> {
     }
 }
 
+/// Joins the ranges of each grid.
+fn union<X, Y, T: Tick>(
+    grids: &[Memo<Data<X, Y>>],
+    range: impl Fn(&Data<X, Y>) -> Range<T>,
+) -> Range<T> {
+    (grids.iter()).fold(Range::default(), |acc, grid| acc.union(&grid.with(&range)))
+}
+
+/// Collects values (by series ID) from each grid.
+fn by_grid<X, Y, V>(
+    grids: &[Memo<Data<X, Y>>],
+    values: impl Fn(&Data<X, Y>) -> HashMap<usize, V>,
+) -> HashMap<usize, V> {
+    (grids.iter()).flat_map(|grid| grid.with(&values)).collect()
+}
+
+/// Returns the grid with the nearest X position. Our own data wins ties.
+fn nearest_grid<X: Tick, Y: Tick>(
+    grids: &[Memo<Data<X, Y>>],
+    pos_x: f64,
+) -> Option<Memo<Data<X, Y>>> {
+    let distance = |grid: &Memo<Data<X, Y>>| {
+        grid.with(|data| data.nearest_position_x(pos_x))
+            .map(|nearest| (nearest - pos_x).abs())
+    };
+    (grids.iter())
+        .filter_map(|grid| Some((*grid, distance(grid)?)))
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(grid, _)| grid)
+}
+
 #[component]
-pub fn RenderData<X: Tick, Y: Tick>(state: State<X, Y>) -> impl IntoView {
+pub fn RenderData<X: Tick, Y: Tick>(state: State<X, Y>, y_ticks: TickLabels<Y>) -> impl IntoView {
     let data = state.pre.data.clone();
+    let format_y = y_ticks.format_y(&state);
+    // Data holding a series: attached data or our own
+    let (own, owners) = (data.data, data.owners);
+    let owner = move |id| {
+        owners
+            .with_value(|owners| owners.get(&id).copied())
+            .unwrap_or(own)
+    };
+    let mk_labels = move |id| {
+        let format_y = format_y.clone();
+        let data = owner(id);
+        Signal::derive(move || {
+            data.with(|data| {
+                data.series_values(id)
+                    .iter()
+                    .map(|y| y.as_ref().map(&format_y).unwrap_or_default())
+                    .collect::<Vec<_>>()
+            })
+        })
+    };
+    let mk_extras = move |id| {
+        let data = owner(id);
+        let extras = create_memo(move |_| data.with(|data| data.series_extras(id)));
+        Signal::from(extras)
+    };
     let mk_svg_coords = move |id| {
+        let data = owner(id);
         Signal::derive(move || {
             let proj = state.projection.get();
-            data.data.with(|data| {
+            data.with(|data| {
                 data.series_positions(id)
                     .into_iter()
                     .map(|(x, y)| proj.position_to_svg(x, y))
@@ -121,14 +334,108 @@ pub fn RenderData<X: Tick, Y: Tick>(state: State<X, Y>) -> impl IntoView {
         })
     };
 
+    // Streamed lines are drawn in segments of data positions
+    let mk_streamed = move |id| {
+        let owned = owner(id);
+        data.is_stream.then(|| Streamed {
+            segments: create_memo(move |previous| {
+                owned.with(|data| Segments::split(previous, data.series_coords(id), data.stream()))
+            }),
+            projection: state.projection,
+        })
+    };
+
     view! {
         <g class="_chartistry_series">
             <For
                 each=move || data.series.get()
                 key=|use_y| use_y.id
                 let:use_y>
-                <RenderUseY use_y=use_y.clone() state=state.clone() positions=mk_svg_coords(use_y.id) />
+                <RenderUseY
+                    use_y=use_y.clone()
+                    state=state.clone()
+                    positions=mk_svg_coords(use_y.id)
+                    labels=mk_labels(use_y.id)
+                    extras=mk_extras(use_y.id)
+                    streamed=mk_streamed(use_y.id) />
             </For>
         </g>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Line, Stream, Waterfall};
+
+    type Point = (f64, f64);
+
+    fn use_data(data: ChartData<Point>) -> UseData<f64, f64> {
+        let series = Series::new(|&(x, _): &Point| x)
+            .line(Line::new(|&(_, y): &Point| y).cumulative())
+            .waterfall(Waterfall::new(|&(_, y): &Point| y));
+        let extents = Signal::derive(|| (Vec::new(), Vec::new()));
+        UseData::new(series, data, extents, None)
+    }
+
+    fn positions(data: &UseData<f64, f64>) -> [Vec<Point>; 2] {
+        data.data
+            .with(|data| [data.series_positions(0), data.series_positions(1)])
+    }
+
+    #[test]
+    fn test_stream_carried() {
+        const POINTS: [Point; 3] = [(1.0, 2.0), (2.0, 3.0), (3.0, 4.0)];
+        let built = |points: &[Point]| use_data(create_rw_signal(points.to_vec()).into());
+        let stream = Stream::new();
+        let streamed = use_data(stream.into());
+        // Appending carries on from the data before
+        stream.extend(POINTS[..2].to_vec());
+        positions(&streamed);
+        stream.push(POINTS[2]);
+        assert_eq!(positions(&streamed), positions(&built(&POINTS)));
+        assert_eq!(
+            positions(&streamed)[1],
+            [(1.0, 2.0), (2.0, 5.0), (3.0, 9.0)]
+        );
+        // Dropping restarts from the first data point kept
+        stream.truncate_front(2);
+        assert_eq!(positions(&streamed), positions(&built(&POINTS[1..])));
+        assert_eq!(positions(&streamed)[1], [(2.0, 3.0), (3.0, 7.0)]);
+    }
+
+    #[test]
+    fn test_attach() {
+        let line = || Series::new(|&(x, _): &Point| x).line(Line::new(|&(_, y): &Point| y));
+        let attached = vec![(2.0, 20.0), (5.5, 55.0), (9.0, 90.0)];
+        let series = line().attach(create_rw_signal(attached), line());
+        let points = vec![(1.0, 1.0), (4.0, 4.0), (6.0, 6.0)];
+        let extents = Signal::derive(|| (Vec::new(), Vec::new()));
+        let data = UseData::new(series, create_rw_signal(points).into(), extents, None);
+        let nearest_y = |pos_x| {
+            let nearest = data.nearest_data_y(create_memo(move |_| pos_x)).get();
+            (nearest.into_iter())
+                .map(|(use_y, y)| (use_y.id, y))
+                .collect::<Vec<_>>()
+        };
+        // Each series is looked up on its own X grid
+        assert_eq!(nearest_y(5.0), [(0, Some(4.0)), (1, Some(55.0))]);
+        assert_eq!(nearest_y(8.0), [(0, Some(6.0)), (1, Some(90.0))]);
+        // Nearest X of any grid
+        let pos_x = |pos_x| create_memo(move |_| pos_x);
+        assert_eq!(data.nearest_data_x(pos_x(5.0)).get(), Some(5.5));
+        assert_eq!(data.nearest_position_x(pos_x(4.2)).get(), Some(4.0));
+        // Ranges cover all data
+        assert_eq!(data.range_x.get().positions(), Some((1.0, 9.0)));
+        assert_eq!(data.range_y.get().positions(), Some((1.0, 90.0)));
+        // Only our own data is counted
+        assert_eq!(data.len.get(), 3);
+        // Attached series are drawn from attached data
+        let attached = data.owners.with_value(|owners| owners[&1]);
+        assert_eq!(
+            attached.with(|data| data.series_positions(1)),
+            [(2.0, 20.0), (5.5, 55.0), (9.0, 90.0)]
+        );
+        assert!(data.data.with(|data| data.series_positions(1).is_empty()));
+    }
+}
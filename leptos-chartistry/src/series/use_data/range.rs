@@ -37,6 +37,37 @@ impl<T> Range<T> {
         self
     }
 
+    /// Extends the range to include the given values.
+    pub fn extend(mut self, ts: &[T]) -> Self
+    where
+        T: Tick,
+    {
+        ts.iter().for_each(|t| self.update(t));
+        self
+    }
+
+    /// Extends the range to include another range.
+    pub fn union(mut self, other: &Self) -> Self
+    where
+        T: Tick,
+    {
+        if let Some((min, max)) = other.range() {
+            self.update(min);
+            self.update(max);
+        }
+        self
+    }
+
+    /// Returns true if the other range lies strictly inside ours. Removing its values leaves our range unchanged.
+    pub fn encloses(&self, other: &Self) -> bool {
+        match (self.positions(), other.positions()) {
+            (Some((min, max)), Some((other_min, other_max))) => min < other_min && other_max < max,
+            // Nothing to remove
+            (_, None) => true,
+            (None, Some(_)) => false,
+        }
+    }
+
     // Returns the (min, max) of T if it exists
     pub fn range(&self) -> Option<(&T, &T)> {
         self.0.as_ref().map(|r| (&r.min.0, &r.max.0))
@@ -0,0 +1,97 @@
+use std::ops::Deref;
+
+/// Values by data index that can be cheaply dropped from the front. Dropped values are compacted away once they're half the buffer. Derefs to the kept values.
+#[derive(Clone)]
+pub struct Rows<V> {
+    values: Vec<V>,
+    // Index of the first kept value
+    start: usize,
+}
+
+impl<V> Rows<V> {
+    pub fn with_capacity(cap: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(cap),
+            start: 0,
+        }
+    }
+
+    pub fn push(&mut self, value: V) {
+        self.values.push(value);
+    }
+
+    /// Drops up to `n` values from the front. Amortised over the drops that lead to compacting.
+    pub fn truncate_front(&mut self, n: usize) {
+        self.start += n.min(self.len());
+        if self.start > 0 && self.start >= self.values.len() / 2 {
+            self.values.drain(..self.start);
+            self.start = 0;
+        }
+    }
+}
+
+impl<V> Default for Rows<V> {
+    fn default() -> Self {
+        Self::with_capacity(0)
+    }
+}
+
+impl<V> Deref for Rows<V> {
+    type Target = [V];
+
+    fn deref(&self) -> &[V] {
+        &self.values[self.start..]
+    }
+}
+
+impl<V: std::fmt::Debug> std::fmt::Debug for Rows<V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// Compares kept values only
+impl<V: PartialEq> PartialEq for Rows<V> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<'a, V> IntoIterator for &'a Rows<V> {
+    type Item = &'a V;
+    type IntoIter = std::slice::Iter<'a, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<V> FromIterator<V> for Rows<V> {
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+            start: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_front() {
+        let mut rows = (0..6).collect::<Rows<_>>();
+        rows.truncate_front(2);
+        assert_eq!(*rows, [2, 3, 4, 5]);
+        // Not yet compacted
+        assert_eq!(rows.values.len(), 6);
+        rows.truncate_front(1);
+        assert_eq!(*rows, [3, 4, 5]);
+        assert_eq!((rows.values.len(), rows.start), (3, 0));
+        rows.push(6);
+        assert_eq!(rows, [3, 4, 5, 6].into_iter().collect());
+        rows.truncate_front(10);
+        assert!(rows.is_empty());
+    }
+}
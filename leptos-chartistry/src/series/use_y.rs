@@ -1,8 +1,15 @@
 use super::{
     bar::{RenderBar, UseBar},
-    line::{RenderLine, UseLine},
+    box_plot::{svg_summaries, RenderBoxPlot, UseBoxPlot},
+    candlestick::{svg_ohlc, RenderCandlestick, UseCandlestick},
+    data_labels::{DataLabelAnchor, DataLabels},
+    heatmap::{svg_cells, RenderHeatmap, UseHeatmap},
+    line::{svg_errors, GapPolicy, RenderLine, RenderLineError, Streamed, UseLine},
+    range_bar::{svg_spans, RenderRangeBar, UseRangeBar},
+    waterfall::{svg_steps, RenderWaterfall, UseWaterfall},
+    Extras,
 };
-use crate::{bounds::Bounds, debug::DebugRect, state::State};
+use crate::{bounds::Bounds, colours::Colour, debug::DebugRect, state::State};
 use leptos::*;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -16,6 +23,11 @@ pub struct UseY {
 enum UseYDesc {
     Line(UseLine),
     Bar(UseBar),
+    Candlestick(UseCandlestick),
+    BoxPlot(UseBoxPlot),
+    Heatmap(UseHeatmap),
+    Waterfall(UseWaterfall),
+    RangeBar(UseRangeBar),
 }
 
 impl UseY {
@@ -29,13 +41,73 @@ impl UseY {
         Self { id, name, desc }
     }
 
-    pub(crate) fn bar(&self) -> Option<&UseBar> {
+    pub(super) fn new_candlestick(
+        id: usize,
+        name: RwSignal<String>,
+        candlestick: UseCandlestick,
+    ) -> Self {
+        let desc = UseYDesc::Candlestick(candlestick);
+        Self { id, name, desc }
+    }
+
+    pub(super) fn new_box_plot(id: usize, name: RwSignal<String>, box_plot: UseBoxPlot) -> Self {
+        let desc = UseYDesc::BoxPlot(box_plot);
+        Self { id, name, desc }
+    }
+
+    pub(super) fn new_heatmap(id: usize, name: RwSignal<String>, heatmap: UseHeatmap) -> Self {
+        let desc = UseYDesc::Heatmap(heatmap);
+        Self { id, name, desc }
+    }
+
+    pub(super) fn new_waterfall(
+        id: usize,
+        name: RwSignal<String>,
+        waterfall: UseWaterfall,
+    ) -> Self {
+        let desc = UseYDesc::Waterfall(waterfall);
+        Self { id, name, desc }
+    }
+
+    pub(super) fn new_range_bar(id: usize, name: RwSignal<String>, range_bar: UseRangeBar) -> Self {
+        let desc = UseYDesc::RangeBar(range_bar);
+        Self { id, name, desc }
+    }
+
+    pub(crate) fn heatmap(&self) -> Option<&UseHeatmap> {
         match &self.desc {
-            UseYDesc::Bar(bar) => Some(bar),
+            UseYDesc::Heatmap(heatmap) => Some(heatmap),
             _ => None,
         }
     }
 
+    /// Whether the series is drawn across the width of each X value e.g., bars.
+    pub(crate) fn takes_x_width(&self) -> bool {
+        matches!(
+            self.desc,
+            UseYDesc::Bar(_)
+                | UseYDesc::Candlestick(_)
+                | UseYDesc::BoxPlot(_)
+                | UseYDesc::Waterfall(_)
+        )
+    }
+
+    /// Whether the series draws gaps at zero. See [GapPolicy::Zero].
+    pub(crate) fn gaps_at_zero(&self) -> bool {
+        match &self.desc {
+            UseYDesc::Line(line) => line.gaps.get() == GapPolicy::Zero,
+            _ => false,
+        }
+    }
+
+    /// Whether the series shares the width of each X value with other grouped series e.g., bars side by side.
+    pub(crate) fn is_grouped(&self) -> bool {
+        matches!(
+            self.desc,
+            UseYDesc::Bar(_) | UseYDesc::BoxPlot(_) | UseYDesc::Waterfall(_)
+        )
+    }
+
     fn taster_bounds(font_height: Memo<f64>, font_width: Memo<f64>) -> Memo<Bounds> {
         create_memo(move |_| Bounds::new(font_width.get() * 2.5, font_height.get()))
     }
@@ -51,32 +123,135 @@ pub(super) fn RenderUseY<X: 'static, Y: 'static>(
     use_y: UseY,
     state: State<X, Y>,
     positions: Signal<Vec<(f64, f64)>>,
+    labels: Signal<Vec<String>>,
+    // Drawn by each kind of series e.g., error bounds
+    extras: Signal<Extras>,
+    streamed: Option<Streamed>,
 ) -> impl IntoView {
+    let (font_height, font_width) = (state.pre.font_height, state.pre.font_width);
     let desc = use_y.desc.clone();
     match desc {
-        UseYDesc::Line(line) => view! {
-            <RenderLine
-                use_y=use_y
-                line=line
-                data=state.pre.data
-                positions=positions
-                markers=positions />
-        },
-        UseYDesc::Bar(bar) => view! {
-            <RenderBar bar=bar state=state positions=positions />
-        },
+        UseYDesc::Line(line) => {
+            let anchors = Signal::derive(move || {
+                positions.with(|positions| {
+                    positions
+                        .iter()
+                        .map(|&(x, y)| DataLabelAnchor { x, y, base_y: y })
+                        .collect()
+                })
+            });
+            let (line_positions, bridges) = line.apply_gaps(positions, state.projection);
+            let errors = svg_errors(&state, extras);
+            view! {
+                <RenderLineError line=line.clone() errors=errors />
+                <RenderLine
+                    use_y=use_y
+                    line=line.clone()
+                    data=state.pre.data
+                    positions=line_positions
+                    markers=positions
+                    bridges=bridges
+                    streamed=streamed />
+                <DataLabels
+                    placement=line.data_labels
+                    anchors=anchors
+                    labels=labels
+                    colour=line.colour
+                    inside_colour=line.colour
+                    font_height=font_height
+                    font_width=font_width />
+            }
+            .into_view()
+        }
+        UseYDesc::Bar(bar) => {
+            let rects = bar.rects(&state, positions);
+            let anchors = Signal::derive(move || {
+                rects.with(|rects| {
+                    rects
+                        .iter()
+                        .map(|&(x, y, width, base_y)| DataLabelAnchor {
+                            x: x + width / 2.0,
+                            y,
+                            base_y,
+                        })
+                        .collect()
+                })
+            });
+            view! {
+                <RenderBar bar=bar.clone() rects=rects />
+                <DataLabels
+                    placement=bar.data_labels
+                    anchors=anchors
+                    labels=labels
+                    colour=bar.colour
+                    inside_colour=Signal::derive(|| Colour::from_rgb(0xFF, 0xFF, 0xFF))
+                    font_height=font_height
+                    font_width=font_width />
+            }
+            .into_view()
+        }
+        UseYDesc::Candlestick(candlestick) => {
+            let ohlc = svg_ohlc(&state, extras);
+            view! {
+                <RenderCandlestick candlestick=candlestick state=state ohlc=ohlc />
+            }
+            .into_view()
+        }
+        UseYDesc::BoxPlot(box_plot) => {
+            let summaries = svg_summaries(&state, extras);
+            view! {
+                <RenderBoxPlot box_plot=box_plot state=state summaries=summaries />
+            }
+            .into_view()
+        }
+        UseYDesc::Heatmap(heatmap) => {
+            let cells = svg_cells(&state, extras);
+            view! {
+                <RenderHeatmap heatmap=heatmap state=state cells=cells labels=labels />
+            }
+            .into_view()
+        }
+        UseYDesc::Waterfall(waterfall) => {
+            let steps = svg_steps(&state, extras);
+            view! {
+                <RenderWaterfall waterfall=waterfall state=state positions=positions steps=steps />
+            }
+            .into_view()
+        }
+        UseYDesc::RangeBar(range_bar) => {
+            let spans = svg_spans(&state, extras);
+            view! {
+                <RenderRangeBar range_bar=range_bar state=state spans=spans />
+            }
+            .into_view()
+        }
     }
 }
 
 #[component]
-pub fn Snippet<X: 'static, Y: 'static>(series: UseY, state: State<X, Y>) -> impl IntoView {
+pub fn Snippet<X: 'static, Y: 'static>(
+    series: UseY,
+    state: State<X, Y>,
+    /// Show the series' fit summary (if any) after its name e.g., a trend's equation.
+    #[prop(optional)]
+    summary: bool,
+) -> impl IntoView {
     let debug = state.pre.debug;
+    let id = series.id;
     let name = series.name;
+    let summaries = state.pre.data.summaries;
+    let label = move || {
+        let name = name.get();
+        summaries.with(|summaries| match summaries.get(&id).filter(|_| summary) {
+            Some(summary) => format!("{name} ({summary})"),
+            None => name,
+        })
+    };
     view! {
         <div class="_chartistry_snippet" style="white-space: nowrap;">
             <DebugRect label="snippet" debug=debug />
             <Taster series=series state=state />
-            {name}
+            {label}
         </div>
     }
 }
@@ -84,6 +259,7 @@ pub fn Snippet<X: 'static, Y: 'static>(series: UseY, state: State<X, Y>) -> impl
 #[component]
 fn Taster<X: 'static, Y: 'static>(series: UseY, state: State<X, Y>) -> impl IntoView {
     const Y_OFFSET: f64 = 2.0;
+    const TASTER_CELLS: usize = 3;
     let debug = state.pre.debug;
     let font_width = state.pre.font_width;
     let right_padding = Signal::derive(move || font_width.get() / 2.0);
@@ -111,9 +287,110 @@ fn Taster<X: 'static, Y: 'static>(series: UseY, state: State<X, Y>) -> impl Into
                     markers=markers />
             }
         }
-        UseYDesc::Bar(bar) => view! {
-            <RenderBar bar=bar.clone() state=state positions=positions />
-        },
+        UseYDesc::Bar(bar) => {
+            let rects = bar.rects(&state, positions);
+            view! {
+                <RenderBar bar=bar.clone() rects=rects />
+            }
+        }
+        UseYDesc::Candlestick(candlestick) => {
+            // One candle in the middle
+            let ohlc = Signal::derive(move || {
+                let bounds = bounds.get();
+                let (top, bottom) = (bounds.top_y(), bounds.bottom_y());
+                let body = bounds.height() / 4.0;
+                vec![(bounds.centre_x(), [bottom - body, top, bottom, top + body])]
+            });
+            view! {
+                <RenderCandlestick candlestick=candlestick.clone() state=state ohlc=ohlc />
+            }
+        }
+        UseYDesc::BoxPlot(box_plot) => {
+            // One box in the middle without outliers
+            let summaries = Signal::derive(move || {
+                let bounds = bounds.get();
+                let (top, bottom) = (bounds.top_y(), bounds.bottom_y());
+                let quarter = bounds.height() / 4.0;
+                let values = [
+                    bottom,
+                    bottom - quarter,
+                    bounds.centre_y(),
+                    top + quarter,
+                    top,
+                ];
+                vec![(bounds.centre_x(), values, vec![])]
+            });
+            view! {
+                <RenderBoxPlot box_plot=box_plot.clone() state=state summaries=summaries />
+            }
+        }
+        UseYDesc::Heatmap(heatmap) => {
+            // Row of cells across the colour scheme
+            let colours = heatmap.colours;
+            let cells = move || {
+                let bounds = bounds.get();
+                let width = bounds.width() / TASTER_CELLS as f64;
+                let colours = colours.get();
+                (0..TASTER_CELLS)
+                    .map(|i| {
+                        let fill = colours.by_value(i as f64, (0.0, TASTER_CELLS as f64 - 1.0));
+                        view! {
+                            <rect
+                                x=bounds.left_x() + width * i as f64
+                                y=bounds.top_y()
+                                width=width
+                                height=bounds.height()
+                                fill=fill.to_string() />
+                        }
+                    })
+                    .collect_view()
+            };
+            view!(<g class="_chartistry_heatmap">{cells}</g>).into_view()
+        }
+        UseYDesc::Waterfall(waterfall) => {
+            // Rising, falling, then a subtotal
+            let colours = [
+                waterfall.increase_colour,
+                waterfall.decrease_colour,
+                waterfall.subtotal_colour,
+            ];
+            let bars = move || {
+                let bounds = bounds.get();
+                let width = bounds.width() / colours.len() as f64;
+                let (top, half) = (bounds.top_y(), bounds.height() / 2.0);
+                [
+                    (top + half, half),
+                    (top + half / 2.0, half),
+                    (top, half * 2.0),
+                ]
+                .into_iter()
+                .zip(colours)
+                .enumerate()
+                .map(|(i, ((y, height), colour))| {
+                    view! {
+                        <rect
+                            x=bounds.left_x() + width * i as f64
+                            y=y
+                            width=width
+                            height=height
+                            fill=colour.get().to_string() />
+                    }
+                })
+                .collect_view()
+            };
+            view!(<g class="_chartistry_waterfall">{bars}</g>).into_view()
+        }
+        UseYDesc::RangeBar(range_bar) => {
+            // One span across the middle
+            let spans = Signal::derive(move || {
+                let bounds = bounds.get();
+                let quarter = bounds.height() / 4.0;
+                vec![(bounds.shrink(quarter, 0.0, quarter, 0.0), String::new())]
+            });
+            view! {
+                <RenderRangeBar range_bar=range_bar.clone() state=state spans=spans />
+            }
+        }
     };
 
     view! {
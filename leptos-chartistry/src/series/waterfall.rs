@@ -0,0 +1,378 @@
+use super::{
+    bar::group_rects, ApplyUseSeries, Extra, Extras, GetYValue, IntoUseBar, SeriesAcc, UseY,
+};
+use crate::{state::State, Colour, Tick, AXIS_MARKER_COLOUR};
+use leptos::*;
+use std::{ops::Add, rc::Rc};
+
+/// Default colour of a waterfall bar that increases the running total.
+pub const WATERFALL_INCREASE_COLOUR: Colour = Colour::from_rgb(0x26, 0xa6, 0x9a);
+/// Default colour of a waterfall bar that decreases the running total.
+pub const WATERFALL_DECREASE_COLOUR: Colour = Colour::from_rgb(0xef, 0x53, 0x50);
+/// Default colour of a waterfall subtotal bar.
+pub const WATERFALL_SUBTOTAL_COLOUR: Colour = Colour::from_rgb(0x60, 0x7d, 0x8b);
+
+type IsSubtotal<T> = Rc<dyn Fn(&T) -> bool>;
+
+/// Draws a waterfall: bars showing how each value changes a running total.
+///
+/// Each datum's value is added to the running total of the data before it. Its bar floats from the previous total to the new total and is coloured by whether it increases or decreases the total. Data marked as subtotals (see [with_subtotals](Self::with_subtotals)) ignore their value and instead show the running total as a bar from zero. The first bar starts from zero. Missing values (`NaN`) are skipped and the total carries on.
+///
+/// The tooltip shows each bar's change while the guide lines follow the running total. Like [Bar](crate::Bar), waterfalls are sized by [gap](Self::gap) and sit alongside other bars. Bars start from zero which is not included in the Y range: use [Series::with_y_range](crate::Series::with_y_range) to include it.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Budget { month: f64, change: f64, quarter_end: bool }
+/// let series = Series::new(|b: &Budget| b.month)
+///     .waterfall(
+///         Waterfall::new(|b: &Budget| b.change)
+///             .with_subtotals(|b: &Budget| b.quarter_end)
+///             .with_name("budget"),
+///     )
+///     .with_min_y(0.0);
+/// ```
+#[non_exhaustive]
+pub struct Waterfall<T, Y> {
+    get_y: Rc<dyn Fn(&T) -> Y>,
+    is_subtotal: Option<IsSubtotal<T>>,
+    /// Name of the waterfall. Used in the legend and tooltip.
+    pub name: RwSignal<String>,
+    /// Colour of bars that increase the total. Default is [WATERFALL_INCREASE_COLOUR].
+    pub increase_colour: RwSignal<Colour>,
+    /// Colour of bars that decrease the total. Default is [WATERFALL_DECREASE_COLOUR].
+    pub decrease_colour: RwSignal<Colour>,
+    /// Colour of subtotal bars. Default is [WATERFALL_SUBTOTAL_COLOUR].
+    pub subtotal_colour: RwSignal<Colour>,
+    /// Whether to draw lines joining the end of each bar to the start of the next. Default is true.
+    pub connectors: RwSignal<bool>,
+    /// Gap between bars. Clamped to 0.0 and 1.0. Defaults to [BAR_GAP](crate::BAR_GAP). See [Bar::gap](crate::Bar::gap).
+    pub gap: RwSignal<f64>,
+    /// Gap inside a group of bars. Clamped to 0.0 and 1.0. Defaults to [BAR_GAP_INNER](crate::BAR_GAP_INNER). See [Bar::group_gap](crate::Bar::group_gap).
+    pub group_gap: RwSignal<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseWaterfall {
+    group_id: usize,
+    pub(super) increase_colour: RwSignal<Colour>,
+    pub(super) decrease_colour: RwSignal<Colour>,
+    pub(super) subtotal_colour: RwSignal<Colour>,
+    connectors: RwSignal<bool>,
+    gap: RwSignal<f64>,
+    group_gap: RwSignal<f64>,
+}
+
+impl<T, Y> Waterfall<T, Y> {
+    /// Create a new waterfall. `get_y` returns the change in the running total.
+    pub fn new(get_y: impl Fn(&T) -> Y + 'static) -> Self
+    where
+        Y: Tick,
+    {
+        Self {
+            get_y: Rc::new(get_y),
+            is_subtotal: None,
+            name: RwSignal::default(),
+            increase_colour: create_rw_signal(WATERFALL_INCREASE_COLOUR),
+            decrease_colour: create_rw_signal(WATERFALL_DECREASE_COLOUR),
+            subtotal_colour: create_rw_signal(WATERFALL_SUBTOTAL_COLOUR),
+            connectors: create_rw_signal(true),
+            gap: create_rw_signal(super::BAR_GAP),
+            group_gap: create_rw_signal(super::BAR_GAP_INNER),
+        }
+    }
+
+    /// Marks data as subtotals. Subtotals show the running total instead of a change.
+    pub fn with_subtotals(mut self, is_subtotal: impl Fn(&T) -> bool + 'static) -> Self {
+        self.is_subtotal = Some(Rc::new(is_subtotal));
+        self
+    }
+
+    /// Set the name of the waterfall. Used in the legend.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.name.set(name.into());
+        self
+    }
+
+    /// Set the increase, decrease, and subtotal colours.
+    pub fn with_colours(
+        self,
+        increase: impl Into<Colour>,
+        decrease: impl Into<Colour>,
+        subtotal: impl Into<Colour>,
+    ) -> Self {
+        self.increase_colour.set(increase.into());
+        self.decrease_colour.set(decrease.into());
+        self.subtotal_colour.set(subtotal.into());
+        self
+    }
+
+    /// Set whether to draw connector lines between bars.
+    pub fn with_connectors(self, connectors: bool) -> Self {
+        self.connectors.set(connectors);
+        self
+    }
+
+    /// Set the gap between bars. Clamped to 0.0 and 1.0.
+    pub fn with_gap(self, gap: f64) -> Self {
+        self.gap.set(gap);
+        self
+    }
+}
+
+impl<T, Y> Clone for Waterfall<T, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            get_y: self.get_y.clone(),
+            is_subtotal: self.is_subtotal.clone(),
+            name: self.name,
+            increase_colour: self.increase_colour,
+            decrease_colour: self.decrease_colour,
+            subtotal_colour: self.subtotal_colour,
+            connectors: self.connectors,
+            gap: self.gap,
+            group_gap: self.group_gap,
+        }
+    }
+}
+
+/// A bar of a waterfall: where it starts and ends. A start of `None` is drawn from zero.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct WaterfallStep<Y> {
+    pub start: Option<Y>,
+    pub end: Y,
+    pub subtotal: bool,
+}
+
+/// Y getter using the change with the running total worked out as data is read.
+struct WaterfallY<T, Y> {
+    get_y: Rc<dyn Fn(&T) -> Y>,
+    is_subtotal: Option<IsSubtotal<T>>,
+}
+
+impl<T, Y: Clone + Add<Output = Y>> GetYValue<T, Y> for WaterfallY<T, Y> {
+    fn value(&self, t: &T) -> Y {
+        (self.get_y)(t)
+    }
+
+    fn cumulative_value(&self, t: &T) -> Y {
+        (self.get_y)(t)
+    }
+
+    fn running_total(&self, t: &T, previous: Option<&Y>) -> Option<WaterfallStep<Y>> {
+        let value = (self.get_y)(t);
+        let subtotal = self.is_subtotal.as_ref().is_some_and(|f| f(t));
+        let step = match (subtotal, previous) {
+            (true, previous) => WaterfallStep {
+                start: None,
+                end: previous.cloned().unwrap_or(value),
+                subtotal,
+            },
+            (false, Some(previous)) => WaterfallStep {
+                start: Some(previous.clone()),
+                end: previous.clone() + value,
+                subtotal,
+            },
+            (false, None) => WaterfallStep {
+                start: None,
+                end: value,
+                subtotal,
+            },
+        };
+        Some(step)
+    }
+}
+
+impl<T: 'static, Y: Clone + Add<Output = Y> + 'static> ApplyUseSeries<T, Y> for Waterfall<T, Y> {
+    fn apply_use_series(self: Rc<Self>, series: &mut SeriesAcc<T, Y>) {
+        // Waterfalls have their own colours: don't take one from the series
+        let subtotal_colour = self.subtotal_colour;
+        let colour = create_memo(move |_| subtotal_colour.get());
+        _ = series.push_bar(colour, (*self).clone());
+    }
+}
+
+impl<T: 'static, Y: Clone + Add<Output = Y> + 'static> IntoUseBar<T, Y> for Waterfall<T, Y> {
+    fn into_use_bar(
+        self,
+        id: usize,
+        group_id: usize,
+        _: Memo<Colour>,
+    ) -> (UseY, Rc<dyn GetYValue<T, Y>>) {
+        let waterfall = UseY::new_waterfall(
+            id,
+            self.name,
+            UseWaterfall {
+                group_id,
+                increase_colour: self.increase_colour,
+                decrease_colour: self.decrease_colour,
+                subtotal_colour: self.subtotal_colour,
+                connectors: self.connectors,
+                gap: self.gap,
+                group_gap: self.group_gap,
+            },
+        );
+        let get_y = Rc::new(WaterfallY {
+            get_y: self.get_y,
+            is_subtotal: self.is_subtotal,
+        });
+        (waterfall, get_y)
+    }
+}
+
+/// How a waterfall bar changes the running total.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum WaterfallKind {
+    Increase,
+    Decrease,
+    Subtotal,
+}
+
+/// Returns a bar's top (SVG Y), height, and kind from its base and end. SVG Y increases downwards.
+fn waterfall_rect(base: f64, end: f64, subtotal: bool) -> (f64, f64, WaterfallKind) {
+    let kind = if subtotal {
+        WaterfallKind::Subtotal
+    } else if end <= base {
+        WaterfallKind::Increase
+    } else {
+        WaterfallKind::Decrease
+    };
+    (base.min(end), (base - end).abs(), kind)
+}
+
+/// SVG start Y (None for zero) and whether each bar is a subtotal.
+pub(super) fn svg_steps<X, Y>(
+    state: &State<X, Y>,
+    extras: Signal<Extras>,
+) -> Signal<Vec<(Option<f64>, bool)>> {
+    let projection = state.projection;
+    Signal::derive(move || {
+        let proj = projection.get();
+        extras.with(|extras| {
+            (extras.iter())
+                .filter_map(|(_, extra)| match *extra {
+                    Extra::Step(start, subtotal) => {
+                        let start = start.map(|y| proj.position_to_svg(0.0, y).1);
+                        Some((start, subtotal))
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        })
+    })
+}
+
+#[component]
+pub fn RenderWaterfall<X: 'static, Y: 'static>(
+    waterfall: UseWaterfall,
+    state: State<X, Y>,
+    positions: Signal<Vec<(f64, f64)>>,
+    // SVG start Y (None for zero) and whether each bar is a subtotal
+    steps: Signal<Vec<(Option<f64>, bool)>>,
+) -> impl IntoView {
+    let UseWaterfall {
+        group_id,
+        increase_colour,
+        decrease_colour,
+        subtotal_colour,
+        connectors,
+        gap,
+        group_gap,
+    } = waterfall;
+    let svg_zero = state.svg_zero;
+    let rects = group_rects(&state, group_id, gap, group_gap, positions);
+    // Each bar's (left X, end Y, width, base Y, subtotal)
+    let bars = Signal::derive(move || {
+        let zero = svg_zero.get().1;
+        let steps = steps.get();
+        rects.with(|rects| {
+            (rects.iter().zip(steps))
+                .filter(|((_, end, _), _)| !end.is_nan())
+                .map(|(&(left, end, width), (start, subtotal))| {
+                    (left, end, width, start.unwrap_or(zero), subtotal)
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    let render_bars = move || {
+        let colours = (
+            increase_colour.get().to_string(),
+            decrease_colour.get().to_string(),
+            subtotal_colour.get().to_string(),
+        );
+        bars.with(|bars| {
+            bars.iter()
+                .map(|&(left, end, width, base, subtotal)| {
+                    let (top, height, kind) = waterfall_rect(base, end, subtotal);
+                    let fill = match kind {
+                        WaterfallKind::Increase => colours.0.clone(),
+                        WaterfallKind::Decrease => colours.1.clone(),
+                        WaterfallKind::Subtotal => colours.2.clone(),
+                    };
+                    // Keep unchanged bars visible
+                    view!(<rect x=left y=top width=width height=height.max(1.0) fill=fill />)
+                })
+                .collect_view()
+        })
+    };
+
+    let render_connectors = move || {
+        if !connectors.get() {
+            return None;
+        }
+        let lines = bars.with(|bars| {
+            bars.windows(2)
+                .map(|pair| {
+                    let (left, end, width, _, _) = pair[0];
+                    let (next_left, _, _, _, _) = pair[1];
+                    view!(<line x1=left + width y1=end x2=next_left y2=end />)
+                })
+                .collect_view()
+        });
+        Some(view! {
+            <g class="_chartistry_waterfall_connectors" stroke=AXIS_MARKER_COLOUR stroke-width=1>
+                {lines}
+            </g>
+        })
+    };
+
+    view! {
+        <g class="_chartistry_waterfall">
+            {render_bars}
+            {render_connectors}
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_running_total() {
+        let waterfall = WaterfallY {
+            get_y: Rc::new(|&(y, _): &(f64, bool)| y),
+            is_subtotal: Some(Rc::new(|&(_, subtotal): &(f64, bool)| subtotal)),
+        };
+        let step = |t, previous: Option<f64>| {
+            let step = waterfall.running_total(&t, previous.as_ref()).unwrap();
+            (step.start, step.end, step.subtotal)
+        };
+        // First bar starts from zero
+        assert_eq!(step((5.0, false), None), (None, 5.0, false));
+        // Floats from the previous total
+        assert_eq!(step((-2.0, false), Some(5.0)), (Some(5.0), 3.0, false));
+        // Subtotals show the total so far from zero
+        assert_eq!(step((99.0, true), Some(3.0)), (None, 3.0, true));
+    }
+
+    #[test]
+    fn test_waterfall_rect() {
+        use WaterfallKind::*;
+        // Up: the end is above the base (smaller SVG Y)
+        assert_eq!(waterfall_rect(10.0, 4.0, false), (4.0, 6.0, Increase));
+        assert_eq!(waterfall_rect(4.0, 10.0, false), (4.0, 6.0, Decrease));
+        assert_eq!(waterfall_rect(10.0, 4.0, true), (4.0, 6.0, Subtotal));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    layout::Layout, projection::Projection, series::UseData, use_watched_node::UseWatchedNode,
-    Padding, Tick,
+    group::UseChartGroup, layout::Layout, projection::Projection, series::UseData,
+    use_watched_node::UseWatchedNode, Padding, Tick,
 };
 use leptos::signal_prelude::*;
 
@@ -21,11 +21,11 @@ pub struct State<X: 'static, Y: 'static> {
 
     pub svg_zero: Memo<(f64, f64)>,
 
-    /// Mouse page position
+    /// Mouse page position. Follows the hover position when another chart in the group is hovered or the keyboard has focus.
     pub mouse_page: Signal<(f64, f64)>,
-    /// Mouse page position relative to chart
+    /// Mouse page position relative to chart. Y is `f64::NAN` when another chart in the group is hovered or the keyboard has focus.
     pub mouse_chart: Signal<(f64, f64)>,
-    /// Mouse over inner chart?
+    /// Mouse over inner chart (or over another chart in the group)?
     pub hover_inner: Signal<bool>,
     /// X mouse coord in data position space
     pub hover_position_x: Memo<f64>,
@@ -55,17 +55,60 @@ impl<X: Tick, Y: Tick> State<X, Y> {
         node: &UseWatchedNode,
         layout: Layout,
         proj: Signal<Projection>,
+        keyboard_x: Memo<Option<f64>>,
+        group: Option<&UseChartGroup<X>>,
     ) -> Self {
         // Mouse
-        let mouse_chart = node.mouse_chart;
-        let hover_inner = node.mouse_hover_inner(layout.inner);
+        let local_mouse_chart = node.mouse_chart;
+        let mouse_hover_inner = node.mouse_hover_inner(layout.inner);
 
         // Data
         let hover_position = create_memo(move |_| {
-            let (mouse_x, mouse_y) = mouse_chart.get();
+            let (mouse_x, mouse_y) = local_mouse_chart.get();
             proj.get().svg_to_position(mouse_x, mouse_y)
         });
-        let hover_position_x = create_memo(move |_| hover_position.get().0);
+
+        // Keyboard focus takes precedence over the mouse
+        let local_hover_inner =
+            Signal::derive(move || keyboard_x.get().is_some() || mouse_hover_inner.get());
+        let local_hover_position_x =
+            create_memo(move |_| keyboard_x.get().unwrap_or_else(|| hover_position.get().0));
+
+        // Hover from another chart in the group
+        let group_hover_x = group.map_or_else(
+            || create_memo(|_| None),
+            |group| group.hover_position_x(local_hover_inner, local_hover_position_x),
+        );
+        let hover_position_x = create_memo(move |_| {
+            group_hover_x
+                .get()
+                .unwrap_or_else(|| local_hover_position_x.get())
+        });
+        let hover_inner =
+            Signal::derive(move || local_hover_inner.get() || group_hover_x.get().is_some());
+
+        // Mouse positions follow the group hover or keyboard focus
+        let inner = layout.inner;
+        let hover_svg = create_memo(move |_| {
+            group_hover_x.get().or(keyboard_x.get()).map(|pos_x| {
+                let (x, _) = proj.get().position_to_svg(pos_x, 0.0);
+                (x, inner.get().centre_y())
+            })
+        });
+        let mouse_chart = Signal::derive(move || match hover_svg.get() {
+            Some((x, _)) => (x, f64::NAN),
+            None => local_mouse_chart.get(),
+        });
+        let mouse_page = {
+            let node = node.clone();
+            Signal::derive(move || match hover_svg.get() {
+                Some((x, y)) => {
+                    let (offset_x, offset_y) = node.page_offset();
+                    (offset_x + x, offset_y + y)
+                }
+                None => node.mouse_page.get(),
+            })
+        };
 
         Self {
             pre,
@@ -73,7 +116,7 @@ impl<X: Tick, Y: Tick> State<X, Y> {
             projection: proj,
             svg_zero: create_memo(move |_| proj.get().position_to_svg(0.0, 0.0)),
 
-            mouse_page: node.mouse_page,
+            mouse_page,
             mouse_chart,
             hover_inner,
             hover_position_x,
@@ -0,0 +1,257 @@
+use leptos::*;
+
+/// Data for a [Chart](crate::Chart): either a signal of all data or a [Stream] that's appended to.
+///
+/// Converts from any signal of data (e.g., `Signal<Vec<T>>`, `RwSignal<Vec<T>>`, or `Memo<Vec<T>>`) or a [Stream].
+pub struct ChartData<T: 'static>(Source<T>);
+
+enum Source<T: 'static> {
+    Signal(Signal<Vec<T>>),
+    Stream(Stream<T>),
+}
+
+/// A buffer of data for live charts e.g., a dashboard updated every second.
+///
+/// Use as a [Chart](crate::Chart)'s data. Changing a signal of data rebuilds everything the chart knows about the data. A stream instead tells the chart what changed: pushed data is added to the existing data, old data dropped from the front, and only the changed segments of lines are redrawn.
+///
+/// Like a ring buffer, pushing and dropping data is cheap. Set a [capacity](Self::capacity) to keep a sliding window of the latest data.
+///
+/// Data is rebuilt as usual when it's [replaced](Self::set), when series need all data e.g., [trend lines](crate::Line::trend), or when sorted data is pushed out of X order or dropped. Derived lines (e.g., moving averages) and waterfalls are rebuilt when data is dropped too: they restart from the first data point kept, the same as if the data had been set.
+///
+/// # Example
+/// ```rust
+/// # use leptos::*;
+/// # use leptos_chartistry::*;
+/// # struct Reading { at: f64, value: f64 }
+/// let stream = Stream::new().with_capacity(10_000);
+/// let series = Series::new(|r: &Reading| r.at)
+///     .line(Line::new(|r: &Reading| r.value).with_name("reading"));
+/// // Later e.g., every second
+/// stream.push(Reading { at: 1.0, value: 2.0 });
+/// ```
+pub struct Stream<T: 'static> {
+    buffer: RwSignal<Buffer<T>>,
+    /// Maximum number of data points kept. Pushing beyond this drops the oldest data. Applied on the next push. Default is unbounded.
+    pub capacity: RwSignal<Option<usize>>,
+}
+
+struct Buffer<T> {
+    data: Vec<T>,
+    // Index of the first kept datum. Dropped data is compacted away once it's half the buffer
+    start: usize,
+    revision: Revision,
+}
+
+/// Counts changes to a stream. Compared to an earlier revision, tells what changed.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub(crate) struct Revision {
+    // Times the data was replaced
+    resets: usize,
+    // Data points dropped and pushed since the data was replaced
+    dropped: usize,
+    pushed: usize,
+}
+
+impl<T: 'static> ChartData<T> {
+    /// Applies a function to the data. Tracks changes.
+    pub(crate) fn with<R>(&self, f: impl FnOnce(&[T]) -> R) -> R {
+        self.with_revision(|data, _| f(data))
+    }
+
+    /// Applies a function to the data and its revision. The revision is None unless the data is a stream. Tracks changes.
+    pub(crate) fn with_revision<R>(&self, f: impl FnOnce(&[T], Option<Revision>) -> R) -> R {
+        match &self.0 {
+            Source::Signal(data) => data.with(|data| f(data, None)),
+            Source::Stream(stream) => stream
+                .buffer
+                .with(|buffer| f(buffer.data(), Some(buffer.revision))),
+        }
+    }
+
+    /// Returns true if the data is a stream.
+    pub(crate) fn is_stream(&self) -> bool {
+        matches!(self.0, Source::Stream(_))
+    }
+}
+
+impl<T: 'static> Stream<T> {
+    /// Creates a new, empty stream.
+    pub fn new() -> Self {
+        Self {
+            buffer: create_rw_signal(Buffer {
+                data: Vec::new(),
+                start: 0,
+                revision: Revision::default(),
+            }),
+            capacity: RwSignal::default(),
+        }
+    }
+
+    /// Sets the maximum number of data points kept.
+    pub fn with_capacity(self, capacity: impl Into<Option<usize>>) -> Self {
+        self.capacity.set(capacity.into());
+        self
+    }
+
+    /// Appends a data point. It should come after existing data in X order.
+    pub fn push(&self, datum: T) {
+        self.extend(std::iter::once(datum));
+    }
+
+    /// Appends data points. They should come after existing data in X order.
+    pub fn extend(&self, data: impl IntoIterator<Item = T>) {
+        let capacity = self.capacity.get_untracked();
+        self.buffer.update(|buffer| {
+            let before = buffer.data.len();
+            buffer.data.extend(data);
+            buffer.revision.pushed += buffer.data.len() - before;
+            if let Some(capacity) = capacity {
+                buffer.truncate_front(capacity);
+            }
+        });
+    }
+
+    /// Shortens the stream, keeping the last `len` data points and dropping the rest.
+    pub fn truncate_front(&self, len: usize) {
+        self.buffer.update(|buffer| buffer.truncate_front(len));
+    }
+
+    /// Replaces all data. The chart rebuilds its data.
+    pub fn set(&self, data: Vec<T>) {
+        self.buffer.update(|buffer| {
+            buffer.revision = Revision {
+                resets: buffer.revision.resets + 1,
+                dropped: 0,
+                pushed: data.len(),
+            };
+            buffer.data = data;
+            buffer.start = 0;
+        });
+    }
+
+    /// Removes all data.
+    pub fn clear(&self) {
+        self.set(Vec::new());
+    }
+
+    /// Returns the number of data points. Tracks changes.
+    pub fn len(&self) -> usize {
+        self.buffer.with(|buffer| buffer.data().len())
+    }
+
+    /// Returns true if there's no data. Tracks changes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Applies a function to the data. Tracks changes.
+    pub fn with<R>(&self, f: impl FnOnce(&[T]) -> R) -> R {
+        self.buffer.with(|buffer| f(buffer.data()))
+    }
+}
+
+impl<T> Buffer<T> {
+    fn data(&self) -> &[T] {
+        &self.data[self.start..]
+    }
+
+    fn truncate_front(&mut self, len: usize) {
+        let drop = self.data().len().saturating_sub(len);
+        self.start += drop;
+        self.revision.dropped += drop;
+        // Compact dropped data. Amortised over the drops that led here
+        if self.start > 0 && self.start >= self.data.len() / 2 {
+            self.data.drain(..self.start);
+            self.start = 0;
+        }
+    }
+}
+
+impl Revision {
+    /// Returns the number of data points dropped from the front and appended since an earlier revision. None if the data was replaced.
+    pub(crate) fn since(self, earlier: Self) -> Option<(usize, usize)> {
+        let same = self.resets == earlier.resets
+            && self.dropped >= earlier.dropped
+            && self.pushed >= earlier.pushed;
+        same.then(|| (self.dropped - earlier.dropped, self.pushed - earlier.pushed))
+    }
+
+    /// Stream index of the first data point: the number dropped since the data was replaced.
+    pub(crate) fn offset(self) -> usize {
+        self.dropped
+    }
+}
+
+impl<T> Default for Stream<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for Stream<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Stream<T> {}
+
+impl<T> Clone for ChartData<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ChartData<T> {}
+
+impl<T> Clone for Source<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Source<T> {}
+
+impl<T, S: Into<Signal<Vec<T>>>> From<S> for ChartData<T> {
+    fn from(data: S) -> Self {
+        Self(Source::Signal(data.into()))
+    }
+}
+
+impl<T> From<Stream<T>> for ChartData<T> {
+    fn from(stream: Stream<T>) -> Self {
+        Self(Source::Stream(stream))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream() {
+        let stream = Stream::new().with_capacity(3);
+        let revision = || stream.buffer.with_untracked(|buffer| buffer.revision);
+        let start = revision();
+        stream.extend([1, 2]);
+        assert_eq!(stream.with(<[_]>::to_vec), [1, 2]);
+        assert_eq!(revision().since(start), Some((0, 2)));
+        // Capacity drops the oldest
+        let before = revision();
+        stream.extend([3, 4, 5]);
+        assert_eq!(stream.with(<[_]>::to_vec), [3, 4, 5]);
+        assert_eq!(revision().since(before), Some((2, 3)));
+        assert_eq!(revision().offset(), 2);
+        stream.truncate_front(1);
+        assert_eq!(stream.with(<[_]>::to_vec), [5]);
+        assert_eq!(revision().offset(), 4);
+        // Replaced
+        let before = revision();
+        stream.set(vec![6]);
+        assert_eq!(stream.with(<[_]>::to_vec), [6]);
+        assert_eq!(revision().since(before), None);
+        assert_eq!(revision().offset(), 0);
+        stream.clear();
+        assert!(stream.is_empty());
+    }
+}
@@ -0,0 +1,113 @@
+use super::{Format, GeneratedTicks, Generator, Span};
+use std::rc::Rc;
+
+/// Generates f64 ticks for named lanes e.g., the Y axis of a [RangeBar](crate::RangeBar).
+///
+/// Lanes sit on whole number positions starting from zero: the first name labels 0.0, the second 1.0, and so on. One tick is generated for each lane in range. If the labels don't fit then every other lane is skipped, and so on.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Lanes {
+    names: Rc<[String]>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct State {
+    names: Rc<[String]>,
+}
+
+impl Lanes {
+    /// Creates a new lane generator. The index of each name is its lane position.
+    pub fn new(names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            names: names.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Generator for Lanes {
+    type Tick = f64;
+
+    fn generate(
+        &self,
+        &first: &Self::Tick,
+        &last: &Self::Tick,
+        span: &dyn Span<Self::Tick>,
+    ) -> GeneratedTicks<Self::Tick> {
+        let state = State {
+            names: self.names.clone(),
+        };
+        if first.is_nan() || last.is_nan() {
+            return GeneratedTicks::new(state, vec![]);
+        }
+        // Lanes in range
+        let first = first.ceil().max(0.0) as usize;
+        let last = (last.floor().max(-1.0) + 1.0) as usize;
+        let lanes = (first..last.min(self.names.len()))
+            .map(|lane| lane as f64)
+            .collect::<Vec<_>>();
+        // Skip lanes until they fit
+        let mut ticks = lanes.clone();
+        let mut step = 1;
+        while ticks.len() > 1 && span.consumed(&state, &ticks) > span.length() {
+            step += 1;
+            ticks = lanes.iter().step_by(step).copied().collect();
+        }
+        GeneratedTicks::new(state, ticks)
+    }
+}
+
+impl Format for State {
+    type Tick = f64;
+
+    fn format(&self, value: &Self::Tick) -> String {
+        let lane = value.round();
+        if lane >= 0.0 {
+            if let Some(name) = self.names.get(lane as usize) {
+                return name.clone();
+            }
+        }
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ticks::VerticalSpan;
+
+    fn lanes(first: f64, last: f64, avail: f64) -> Vec<String> {
+        let gen = Lanes::new(["build", "test", "deploy", "verify"]);
+        let GeneratedTicks { state, ticks } =
+            gen.generate(&first, &last, &VerticalSpan::new(10.0, avail));
+        ticks.iter().map(|tick| state.format(tick)).collect()
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            lanes(0.0, 3.0, 100.0),
+            ["build", "test", "deploy", "verify"]
+        );
+        // Partial range
+        assert_eq!(lanes(0.5, 2.0, 100.0), ["test", "deploy"]);
+        // Outside of named lanes
+        assert_eq!(
+            lanes(-3.0, 9.0, 100.0),
+            ["build", "test", "deploy", "verify"]
+        );
+        assert!(lanes(5.0, 9.0, 100.0).is_empty());
+        assert!(lanes(f64::NAN, f64::NAN, 100.0).is_empty());
+        // Skip lanes that don't fit
+        assert_eq!(lanes(0.0, 3.0, 30.0), ["build", "deploy"]);
+        assert_eq!(lanes(0.0, 3.0, 5.0), ["build"]);
+    }
+
+    #[test]
+    fn test_format() {
+        let state = State {
+            names: Rc::from(["a".to_string(), "b".to_string()]),
+        };
+        assert_eq!(state.format(&1.0), "b");
+        assert_eq!(state.format(&2.0), "2");
+        assert_eq!(state.format(&-1.0), "-1");
+    }
+}
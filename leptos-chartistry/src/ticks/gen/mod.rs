@@ -1,8 +1,10 @@
 mod aligned_floats;
+mod lanes;
 mod span;
 mod timestamps;
 
 pub use aligned_floats::AlignedFloats;
+pub use lanes::Lanes;
 pub use span::{HorizontalSpan, TickFormatFn, VerticalSpan};
 pub use timestamps::{Period, Timestamps};
 
@@ -355,7 +355,7 @@ impl Period {
         }
     }
 
-    fn truncate_at<Tz: TimeZone>(self, at: DateTime<Tz>) -> Option<DateTime<Tz>> {
+    pub(crate) fn truncate_at<Tz: TimeZone>(self, at: DateTime<Tz>) -> Option<DateTime<Tz>> {
         let duration = match self {
             Period::Nanosecond => Duration::nanoseconds(1),
             Period::Microsecond => Duration::microseconds(1),
@@ -2,7 +2,7 @@ mod gen;
 
 pub use gen::{
     AlignedFloats, Format as TickFormat, GeneratedTicks, Generator as TickGen, HorizontalSpan,
-    Period, TickFormatFn, Timestamps, VerticalSpan,
+    Lanes, Period, TickFormatFn, Timestamps, VerticalSpan,
 };
 
 use chrono::prelude::*;
@@ -19,6 +19,11 @@ pub trait Tick: Clone + PartialEq + PartialOrd + std::fmt::Debug + 'static {
 
     /// Maps the tick to a position on the axis. Must be uniform. May return `f64::NAN` for missing data.
     fn position(&self) -> f64;
+
+    /// Returns the tick at position zero, if it has a meaningful one. Used to include zero in the range e.g., for [GapPolicy::Zero](crate::GapPolicy::Zero). Default is none.
+    fn zero() -> Option<Self> {
+        None
+    }
 }
 
 impl Tick for f64 {
@@ -29,6 +34,10 @@ impl Tick for f64 {
     fn position(&self) -> f64 {
         *self
     }
+
+    fn zero() -> Option<Self> {
+        Some(0.0)
+    }
 }
 
 impl<Tz> Tick for DateTime<Tz>
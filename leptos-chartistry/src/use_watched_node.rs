@@ -1,14 +1,41 @@
 use crate::bounds::Bounds;
-use leptos::{html::Div, *};
+use leptos::{
+    ev::{touchcancel, touchmove, touchstart},
+    html::Div,
+    *,
+};
 use leptos_use::{
-    use_element_hover, use_mouse_with_options, use_resize_observer_with_options, UseMouseCoordType,
-    UseMouseOptions, UseMouseSourceType, UseResizeObserverOptions,
+    use_element_hover, use_event_listener_with_options, use_mouse_with_options,
+    use_resize_observer_with_options, UseEventListenerOptions, UseMouseCoordType, UseMouseOptions,
+    UseMouseSourceType, UseResizeObserverOptions,
 };
-use std::convert::Infallible;
-use web_sys::ResizeObserverBoxOptions;
+use std::{cell::Cell, convert::Infallible, rc::Rc};
+use web_sys::{wasm_bindgen::JsCast, ResizeObserverBoxOptions, TouchEvent};
+
+// Distance (px) a touch must move before we decide between scrubbing and scrolling
+const TOUCH_SLOP: f64 = 8.0;
+
+/// How a single finger touch is being used.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TouchGesture {
+    /// Not moved far enough to tell. Treated as a tap.
+    Tap,
+    /// Horizontal drag: scrub along the X axis.
+    Scrub,
+    /// Vertical drag: the page is scrolling.
+    Scroll,
+}
+
+/// Touch position relative to the chart and the page.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct TouchAt {
+    chart: (f64, f64),
+    page: (f64, f64),
+}
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct UseWatchedNode {
+    node: NodeRef<Div>,
     pub bounds: Signal<Option<Bounds>>,
     pub mouse_page: Signal<(f64, f64)>,
     pub mouse_chart: Signal<(f64, f64)>,
@@ -33,20 +60,22 @@ pub fn use_watched_node(node: NodeRef<Div>) -> UseWatchedNode {
     );
     let bounds: Signal<Option<Bounds>> = bounds.into();
 
+    // Touch position. Handled separately from the mouse so that a tap leaves a sticky tooltip
+    let touch = use_touch(node);
+
     // Mouse position
     let mouse_page = use_mouse_with_options(
         UseMouseOptions::default()
             .target(node)
             .coord_type(UseMouseCoordType::<Infallible>::Page)
-            .reset_on_touch_ends(true),
+            .touch(false),
     );
 
     // Mouse absolute coords on page
     let mouse_page_type = mouse_page.source_type;
-    let mouse_page = Signal::derive(move || {
-        let x = mouse_page.x.get();
-        let y = mouse_page.y.get();
-        (x, y)
+    let mouse_page = Signal::derive(move || match touch.get() {
+        Some(touch) => touch.page,
+        None => (mouse_page.x.get(), mouse_page.y.get()),
     });
 
     // Mouse relative to SVG
@@ -54,9 +83,12 @@ pub fn use_watched_node(node: NodeRef<Div>) -> UseWatchedNode {
         UseMouseOptions::default()
             .target(node)
             .coord_type(UseMouseCoordType::<Infallible>::Client)
-            .reset_on_touch_ends(true),
+            .touch(false),
     );
     let mouse_chart: Signal<_> = create_memo(move |_| {
+        if let Some(touch) = touch.get() {
+            return touch.chart;
+        }
         let (left, top) = node
             .get()
             .map(|target| {
@@ -74,8 +106,8 @@ pub fn use_watched_node(node: NodeRef<Div>) -> UseWatchedNode {
     let el_hover = use_element_hover(node);
     let mouse_chart_hover = create_memo(move |_| {
         let (x, y) = mouse_chart.get();
-        mouse_page_type.get() != UseMouseSourceType::Unset
-            && el_hover.get()
+        (touch.with(Option::is_some)
+            || mouse_page_type.get() != UseMouseSourceType::Unset && el_hover.get())
             && bounds
                 .get()
                 .map(|bounds| bounds.contains(x, y))
@@ -84,6 +116,7 @@ pub fn use_watched_node(node: NodeRef<Div>) -> UseWatchedNode {
     .into();
 
     UseWatchedNode {
+        node,
         bounds,
         mouse_page,
         mouse_chart,
@@ -91,7 +124,130 @@ pub fn use_watched_node(node: NodeRef<Div>) -> UseWatchedNode {
     }
 }
 
+/// Tracks a single finger touch on the node:
+/// - A tap shows a sticky position that stays after the finger lifts. Tapping outside the node clears it.
+/// - A horizontal drag scrubs along the X axis.
+/// - A vertical drag scrolls the page as usual.
+/// - Multi-finger gestures (e.g., pinch) are reserved for zooming and ignored.
+fn use_touch(node: NodeRef<Div>) -> Signal<Option<TouchAt>> {
+    let (touch, set_touch) = create_signal::<Option<TouchAt>>(None);
+    // Where the current touch started and how it's being used
+    let gesture = Rc::new(Cell::new(None::<((f64, f64), TouchGesture)>));
+
+    let touch_at = move |ev: &TouchEvent| {
+        let touches = ev.touches();
+        if touches.length() != 1 {
+            return None;
+        }
+        let touch = touches.get(0)?;
+        let (left, top) = node
+            .get_untracked()
+            .map(|target| {
+                let rect = target.get_bounding_client_rect();
+                (rect.left(), rect.top())
+            })
+            .unwrap_or_default();
+        let client = (touch.client_x() as f64, touch.client_y() as f64);
+        let at = TouchAt {
+            chart: (client.0 - left, client.1 - top),
+            page: (touch.page_x() as f64, touch.page_y() as f64),
+        };
+        Some((client, at))
+    };
+
+    // Listeners are passive: we never block scrolling. The node's `touch-action` decides what the browser handles
+    let options = UseEventListenerOptions::default().passive(true);
+    let _ = use_event_listener_with_options(
+        node,
+        touchstart,
+        {
+            let gesture = gesture.clone();
+            move |ev| match touch_at(&ev) {
+                Some((client, at)) => {
+                    gesture.set(Some((client, TouchGesture::Tap)));
+                    set_touch.set(Some(at));
+                }
+                // Reserved for pinch zoom
+                None => gesture.set(None),
+            }
+        },
+        options,
+    );
+    let _ = use_event_listener_with_options(
+        node,
+        touchmove,
+        {
+            let gesture = gesture.clone();
+            move |ev| {
+                let (Some((client, at)), Some((start, current))) = (touch_at(&ev), gesture.get())
+                else {
+                    return;
+                };
+                let next = touch_gesture(current, start, client);
+                gesture.set(Some((start, next)));
+                match next {
+                    TouchGesture::Tap | TouchGesture::Scrub => set_touch.set(Some(at)),
+                    TouchGesture::Scroll => set_touch.set(None),
+                }
+            }
+        },
+        options,
+    );
+    // The browser took over e.g., to scroll the page
+    let _ =
+        use_event_listener_with_options(node, touchcancel, move |_| set_touch.set(None), options);
+    // Tapping outside the node clears the sticky position
+    let _ = use_event_listener_with_options(
+        document(),
+        touchstart,
+        move |ev| {
+            let inside = node.get_untracked().is_some_and(|node| {
+                let target = ev.target().and_then(|t| t.dyn_into::<web_sys::Node>().ok());
+                node.contains(target.as_ref())
+            });
+            if !inside && touch.get_untracked().is_some() {
+                set_touch.set(None);
+            }
+        },
+        options,
+    );
+
+    touch.into()
+}
+
+/// Decides how a touch is being used once it's moved from `start` to `now`. Once decided, the gesture doesn't change.
+fn touch_gesture(current: TouchGesture, start: (f64, f64), now: (f64, f64)) -> TouchGesture {
+    if current != TouchGesture::Tap {
+        return current;
+    }
+    let dx = (now.0 - start.0).abs();
+    let dy = (now.1 - start.1).abs();
+    if dx.max(dy) < TOUCH_SLOP {
+        TouchGesture::Tap
+    } else if dx >= dy {
+        TouchGesture::Scrub
+    } else {
+        TouchGesture::Scroll
+    }
+}
+
 impl UseWatchedNode {
+    // Position of the node's top left corner on the page. Not reactive.
+    pub fn page_offset(&self) -> (f64, f64) {
+        let (left, top) = self
+            .node
+            .get_untracked()
+            .map(|target| {
+                let rect = target.get_bounding_client_rect();
+                (rect.left(), rect.top())
+            })
+            .unwrap_or_default();
+        let window = window();
+        let scroll_x = window.scroll_x().unwrap_or_default();
+        let scroll_y = window.scroll_y().unwrap_or_default();
+        (left + scroll_x, top + scroll_y)
+    }
+
     // Mouse inside inner chart?
     pub fn mouse_hover_inner(&self, inner: Memo<Bounds>) -> Signal<bool> {
         let (mouse_rel, hover) = (self.mouse_chart, self.mouse_chart_hover);
@@ -102,3 +258,22 @@ impl UseWatchedNode {
         .into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_touch_gesture() {
+        use TouchGesture::*;
+        let start = (100.0, 100.0);
+        // Small movements are still a tap
+        assert_eq!(touch_gesture(Tap, start, (103.0, 104.0)), Tap);
+        // Horizontal drags scrub, vertical drags scroll
+        assert_eq!(touch_gesture(Tap, start, (120.0, 105.0)), Scrub);
+        assert_eq!(touch_gesture(Tap, start, (105.0, 60.0)), Scroll);
+        // Once decided, the gesture sticks
+        assert_eq!(touch_gesture(Scrub, start, (100.0, 200.0)), Scrub);
+        assert_eq!(touch_gesture(Scroll, start, (200.0, 100.0)), Scroll);
+    }
+}